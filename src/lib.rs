@@ -1,3 +1,33 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod prelude;
 pub mod wasm_simd;
 
-pub use wasm_simd::{Bx4, F32x4, I32x4};
+pub use wasm_simd::{
+    Bx16, Bx4, Bx8, F32x16, F32x4, F32x8, I32x16, I32x4, I32x8, IntegerLanes, LaneIndexError, Mask,
+    MaskElement, ParseVectorError, SignedLanes, Simd, SimdElement, SupportedLanes, TrueLanes,
+    U32x16, U32x4, U32x8,
+};
+
+#[cfg(feature = "geometry")]
+pub use wasm_simd::geometry;
+
+#[cfg(feature = "serde")]
+pub use wasm_simd::compact;
+
+#[cfg(feature = "derive")]
+pub use wasm_simd_derive::SimdSoa;
+
+// Paths used by the exported macros and by the code `SimdSoa` generates.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "derive")]
+    pub use alloc::vec::Vec;
+
+    pub mod arch {
+        pub use crate::wasm_simd::arch::*;
+    }
+}
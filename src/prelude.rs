@@ -0,0 +1,10 @@
+//! Glob-import this module to bring every vector, mask and supporting type into scope.
+
+pub use crate::wasm_simd::{
+    Bx16, Bx4, Bx8, F32x16, F32x4, F32x8, I32x16, I32x4, I32x8, IntegerLanes, LaneIndexError, Mask,
+    MaskElement, ParseVectorError, SignedLanes, Simd, SimdElement, SupportedLanes, TrueLanes,
+    U32x16, U32x4, U32x8,
+};
+
+#[cfg(feature = "serde")]
+pub use crate::wasm_simd::compact::CompactBytes;
@@ -0,0 +1,54 @@
+//! The instruction set the vector types are built on.
+//!
+//! - On `wasm32` with the `simd128` target feature this is `core::arch::wasm32` and every
+//!   operation lowers to a WebAssembly SIMD instruction.
+//! - On `x86_64` it is an SSE implementation of the same intrinsics that produces the same
+//!   results as WebAssembly, bit for bit.
+//! - On little-endian `aarch64` it is the equivalent NEON implementation.
+//! - Otherwise (`wasm32` without `+simd128`, other targets, or with the `scalar-backend`
+//!   feature) it is a portable scalar implementation on four 32-bit lanes, so one build of a
+//!   library runs on engines with and without SIMD support, with the same results.
+
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    not(feature = "scalar-backend")
+))]
+pub use core::arch::wasm32::*;
+
+#[cfg(all(target_arch = "x86_64", not(feature = "scalar-backend")))]
+mod x86;
+#[cfg(all(target_arch = "x86_64", not(feature = "scalar-backend")))]
+pub use x86::*;
+
+#[cfg(all(
+    target_arch = "aarch64",
+    target_endian = "little",
+    not(feature = "scalar-backend")
+))]
+mod neon;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_endian = "little",
+    not(feature = "scalar-backend")
+))]
+pub use neon::*;
+
+#[cfg(any(
+    feature = "scalar-backend",
+    not(any(
+        all(target_arch = "wasm32", target_feature = "simd128"),
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_endian = "little")
+    ))
+))]
+mod scalar;
+#[cfg(any(
+    feature = "scalar-backend",
+    not(any(
+        all(target_arch = "wasm32", target_feature = "simd128"),
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_endian = "little")
+    ))
+))]
+pub use scalar::*;
@@ -0,0 +1,527 @@
+// NEON implementation of the `core::arch::wasm32` intrinsics used by this crate, for
+// little-endian AArch64.
+//
+// Vectors are stored as `uint32x4_t`; signed and float operations reinterpret them with the
+// zero-cost `vreinterpretq_*` intrinsics. Most WebAssembly instructions have a NEON
+// counterpart with identical results: the float to integer conversions saturate and map NaN
+// to 0, `tbl` zeroes out-of-range swizzle indices and `fmin`/`fmax` order -0.0 below 0.0.
+// Shifts take the amount modulo 32 first, and `f32x4_min`/`f32x4_max` replace NaN lanes with
+// the canonical NaN, which matches the other backends.
+//
+// The intrinsics are `unsafe` to call because they require their target feature. NEON is
+// part of the AArch64 baseline, so every call below is sound.
+#![allow(non_camel_case_types)]
+
+use core::arch::aarch64::*;
+
+pub use core::arch::aarch64::uint32x4_t as v128;
+
+const CANONICAL_NAN: u32 = 0x7fc0_0000;
+
+#[inline(always)]
+fn f(a: v128) -> float32x4_t {
+    unsafe { vreinterpretq_f32_u32(a) }
+}
+
+#[inline(always)]
+fn from_f(a: float32x4_t) -> v128 {
+    unsafe { vreinterpretq_u32_f32(a) }
+}
+
+#[inline(always)]
+fn s(a: v128) -> int32x4_t {
+    unsafe { vreinterpretq_s32_u32(a) }
+}
+
+#[inline(always)]
+fn from_s(a: int32x4_t) -> v128 {
+    unsafe { vreinterpretq_u32_s32(a) }
+}
+
+#[inline(always)]
+fn to_lanes(a: v128) -> [u32; 4] {
+    // SAFETY: `uint32x4_t` and `[u32; 4]` have the same size and every bit pattern is valid.
+    // On little-endian targets lane `i` is element `i` of the array.
+    unsafe { core::mem::transmute(a) }
+}
+
+#[inline(always)]
+fn from_lanes(lanes: [u32; 4]) -> v128 {
+    // SAFETY: as above.
+    unsafe { core::mem::transmute(lanes) }
+}
+
+// Lanes where neither input is NaN.
+#[inline(always)]
+fn ordered(a: float32x4_t, b: float32x4_t) -> v128 {
+    unsafe { vandq_u32(vceqq_f32(a, a), vceqq_f32(b, b)) }
+}
+
+pub const fn i32x4(a0: i32, a1: i32, a2: i32, a3: i32) -> v128 {
+    // SAFETY: `uint32x4_t` and `[i32; 4]` have the same size and every bit pattern is valid.
+    unsafe { core::mem::transmute([a0, a1, a2, a3]) }
+}
+
+pub const fn u32x4(a0: u32, a1: u32, a2: u32, a3: u32) -> v128 {
+    // SAFETY: as above.
+    unsafe { core::mem::transmute([a0, a1, a2, a3]) }
+}
+
+pub const fn f32x4(a0: f32, a1: f32, a2: f32, a3: f32) -> v128 {
+    // SAFETY: as above.
+    unsafe { core::mem::transmute([a0, a1, a2, a3]) }
+}
+
+#[inline]
+pub fn i32x4_splat(a: i32) -> v128 {
+    unsafe { vdupq_n_u32(a as u32) }
+}
+
+#[inline]
+pub fn u32x4_splat(a: u32) -> v128 {
+    unsafe { vdupq_n_u32(a) }
+}
+
+#[inline]
+pub fn i32x4_extract_lane<const N: usize>(a: v128) -> i32 {
+    to_lanes(a)[N] as i32
+}
+
+#[inline]
+pub fn u32x4_extract_lane<const N: usize>(a: v128) -> u32 {
+    to_lanes(a)[N]
+}
+
+#[inline]
+pub fn f32x4_extract_lane<const N: usize>(a: v128) -> f32 {
+    f32::from_bits(to_lanes(a)[N])
+}
+
+#[inline]
+pub fn i32x4_replace_lane<const N: usize>(a: v128, val: i32) -> v128 {
+    u32x4_replace_lane::<N>(a, val as u32)
+}
+
+#[inline]
+pub fn u32x4_replace_lane<const N: usize>(a: v128, val: u32) -> v128 {
+    let mut lanes = to_lanes(a);
+    lanes[N] = val;
+    from_lanes(lanes)
+}
+
+#[inline]
+pub fn f32x4_replace_lane<const N: usize>(a: v128, val: f32) -> v128 {
+    u32x4_replace_lane::<N>(a, val.to_bits())
+}
+
+#[inline]
+pub fn i32x4_add(a: v128, b: v128) -> v128 {
+    unsafe { vaddq_u32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_sub(a: v128, b: v128) -> v128 {
+    unsafe { vsubq_u32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_mul(a: v128, b: v128) -> v128 {
+    unsafe { vmulq_u32(a, b) }
+}
+
+#[inline]
+pub fn u32x4_add(a: v128, b: v128) -> v128 {
+    i32x4_add(a, b)
+}
+
+#[inline]
+pub fn u32x4_sub(a: v128, b: v128) -> v128 {
+    i32x4_sub(a, b)
+}
+
+#[inline]
+pub fn u32x4_mul(a: v128, b: v128) -> v128 {
+    i32x4_mul(a, b)
+}
+
+#[inline]
+pub fn i32x4_neg(a: v128) -> v128 {
+    let a = s(a);
+    from_s(unsafe { vnegq_s32(a) })
+}
+
+#[inline]
+pub fn i32x4_abs(a: v128) -> v128 {
+    let a = s(a);
+    from_s(unsafe { vabsq_s32(a) })
+}
+
+#[inline]
+pub fn i32x4_min(a: v128, b: v128) -> v128 {
+    let (a, b) = (s(a), s(b));
+    from_s(unsafe { vminq_s32(a, b) })
+}
+
+#[inline]
+pub fn i32x4_max(a: v128, b: v128) -> v128 {
+    let (a, b) = (s(a), s(b));
+    from_s(unsafe { vmaxq_s32(a, b) })
+}
+
+#[inline]
+pub fn u32x4_min(a: v128, b: v128) -> v128 {
+    unsafe { vminq_u32(a, b) }
+}
+
+#[inline]
+pub fn u32x4_max(a: v128, b: v128) -> v128 {
+    unsafe { vmaxq_u32(a, b) }
+}
+
+// WebAssembly takes shift amounts modulo the lane width. NEON only has register shifts to the
+// left, so right shifts use a negative amount.
+#[inline]
+pub fn i32x4_shl(a: v128, amt: u32) -> v128 {
+    unsafe { vshlq_u32(a, vdupq_n_s32((amt & 31) as i32)) }
+}
+
+#[inline]
+pub fn i32x4_shr(a: v128, amt: u32) -> v128 {
+    let a = s(a);
+    from_s(unsafe { vshlq_s32(a, vdupq_n_s32(-((amt & 31) as i32))) })
+}
+
+#[inline]
+pub fn u32x4_shl(a: v128, amt: u32) -> v128 {
+    i32x4_shl(a, amt)
+}
+
+#[inline]
+pub fn u32x4_shr(a: v128, amt: u32) -> v128 {
+    unsafe { vshlq_u32(a, vdupq_n_s32(-((amt & 31) as i32))) }
+}
+
+#[inline]
+pub fn i32x4_eq(a: v128, b: v128) -> v128 {
+    unsafe { vceqq_u32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_ne(a: v128, b: v128) -> v128 {
+    unsafe { vmvnq_u32(vceqq_u32(a, b)) }
+}
+
+#[inline]
+pub fn i32x4_lt(a: v128, b: v128) -> v128 {
+    let (a, b) = (s(a), s(b));
+    unsafe { vcltq_s32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_le(a: v128, b: v128) -> v128 {
+    let (a, b) = (s(a), s(b));
+    unsafe { vcleq_s32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_gt(a: v128, b: v128) -> v128 {
+    let (a, b) = (s(a), s(b));
+    unsafe { vcgtq_s32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_ge(a: v128, b: v128) -> v128 {
+    let (a, b) = (s(a), s(b));
+    unsafe { vcgeq_s32(a, b) }
+}
+
+#[inline]
+pub fn u32x4_eq(a: v128, b: v128) -> v128 {
+    i32x4_eq(a, b)
+}
+
+#[inline]
+pub fn u32x4_ne(a: v128, b: v128) -> v128 {
+    i32x4_ne(a, b)
+}
+
+#[inline]
+pub fn u32x4_lt(a: v128, b: v128) -> v128 {
+    unsafe { vcltq_u32(a, b) }
+}
+
+#[inline]
+pub fn u32x4_le(a: v128, b: v128) -> v128 {
+    unsafe { vcleq_u32(a, b) }
+}
+
+#[inline]
+pub fn u32x4_gt(a: v128, b: v128) -> v128 {
+    unsafe { vcgtq_u32(a, b) }
+}
+
+#[inline]
+pub fn u32x4_ge(a: v128, b: v128) -> v128 {
+    unsafe { vcgeq_u32(a, b) }
+}
+
+#[inline]
+pub fn f32x4_eq(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    unsafe { vceqq_f32(a, b) }
+}
+
+#[inline]
+pub fn f32x4_ne(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    unsafe { vmvnq_u32(vceqq_f32(a, b)) }
+}
+
+#[inline]
+pub fn f32x4_lt(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    unsafe { vcltq_f32(a, b) }
+}
+
+#[inline]
+pub fn f32x4_le(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    unsafe { vcleq_f32(a, b) }
+}
+
+#[inline]
+pub fn f32x4_gt(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    unsafe { vcgtq_f32(a, b) }
+}
+
+#[inline]
+pub fn f32x4_ge(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    unsafe { vcgeq_f32(a, b) }
+}
+
+#[inline]
+pub fn f32x4_add(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    from_f(unsafe { vaddq_f32(a, b) })
+}
+
+#[inline]
+pub fn f32x4_sub(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    from_f(unsafe { vsubq_f32(a, b) })
+}
+
+#[inline]
+pub fn f32x4_mul(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    from_f(unsafe { vmulq_f32(a, b) })
+}
+
+#[inline]
+pub fn f32x4_div(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    from_f(unsafe { vdivq_f32(a, b) })
+}
+
+// `fmin`/`fmax` already order -0.0 below 0.0 and return a NaN when either lane is NaN; the
+// NaN is replaced with the canonical one so the payload does not depend on the inputs.
+#[inline]
+pub fn f32x4_min(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    let min = from_f(unsafe { vminq_f32(a, b) });
+    v128_bitselect(min, u32x4_splat(CANONICAL_NAN), ordered(a, b))
+}
+
+#[inline]
+pub fn f32x4_max(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    let max = from_f(unsafe { vmaxq_f32(a, b) });
+    v128_bitselect(max, u32x4_splat(CANONICAL_NAN), ordered(a, b))
+}
+
+#[inline]
+pub fn f32x4_abs(a: v128) -> v128 {
+    let a = f(a);
+    from_f(unsafe { vabsq_f32(a) })
+}
+
+#[inline]
+pub fn f32x4_neg(a: v128) -> v128 {
+    let a = f(a);
+    from_f(unsafe { vnegq_f32(a) })
+}
+
+#[inline]
+pub fn f32x4_sqrt(a: v128) -> v128 {
+    let a = f(a);
+    from_f(unsafe { vsqrtq_f32(a) })
+}
+
+#[inline]
+pub fn f32x4_ceil(a: v128) -> v128 {
+    let a = f(a);
+    from_f(unsafe { vrndpq_f32(a) })
+}
+
+#[inline]
+pub fn f32x4_floor(a: v128) -> v128 {
+    let a = f(a);
+    from_f(unsafe { vrndmq_f32(a) })
+}
+
+#[inline]
+pub fn f32x4_trunc(a: v128) -> v128 {
+    let a = f(a);
+    from_f(unsafe { vrndq_f32(a) })
+}
+
+#[inline]
+pub fn f32x4_nearest(a: v128) -> v128 {
+    let a = f(a);
+    from_f(unsafe { vrndnq_f32(a) })
+}
+
+// `fcvtzs`/`fcvtzu` saturate out-of-range lanes and turn NaN into 0, like WebAssembly.
+#[inline]
+pub fn i32x4_trunc_sat_f32x4(a: v128) -> v128 {
+    let a = f(a);
+    from_s(unsafe { vcvtq_s32_f32(a) })
+}
+
+#[inline]
+pub fn u32x4_trunc_sat_f32x4(a: v128) -> v128 {
+    let a = f(a);
+    unsafe { vcvtq_u32_f32(a) }
+}
+
+#[inline]
+pub fn f32x4_convert_i32x4(a: v128) -> v128 {
+    let a = s(a);
+    from_f(unsafe { vcvtq_f32_s32(a) })
+}
+
+#[inline]
+pub fn f32x4_convert_u32x4(a: v128) -> v128 {
+    from_f(unsafe { vcvtq_f32_u32(a) })
+}
+
+#[inline]
+pub fn v128_and(a: v128, b: v128) -> v128 {
+    unsafe { vandq_u32(a, b) }
+}
+
+#[inline]
+pub fn v128_or(a: v128, b: v128) -> v128 {
+    unsafe { vorrq_u32(a, b) }
+}
+
+#[inline]
+pub fn v128_xor(a: v128, b: v128) -> v128 {
+    unsafe { veorq_u32(a, b) }
+}
+
+#[inline]
+pub fn v128_not(a: v128) -> v128 {
+    unsafe { vmvnq_u32(a) }
+}
+
+#[inline]
+pub fn v128_bitselect(v1: v128, v2: v128, c: v128) -> v128 {
+    unsafe { vbslq_u32(c, v1, v2) }
+}
+
+#[inline]
+pub fn v128_any_true(a: v128) -> bool {
+    unsafe { vmaxvq_u32(a) != 0 }
+}
+
+#[inline]
+pub fn i32x4_all_true(a: v128) -> bool {
+    unsafe { vminvq_u32(a) != 0 }
+}
+
+#[inline]
+pub fn u32x4_all_true(a: v128) -> bool {
+    i32x4_all_true(a)
+}
+
+// Moves each sign bit to bit 0, scales lane `i` by `1 << i` and sums the lanes.
+#[inline]
+pub fn i32x4_bitmask(a: v128) -> u8 {
+    let shifts = s(i32x4(0, 1, 2, 3));
+    unsafe { vaddvq_u32(vshlq_u32(vshrq_n_u32::<31>(a), shifts)) as u8 }
+}
+
+// The indices are constants, so LLVM lowers the lane picks to `zip`/`ext`/`ins`.
+#[inline]
+pub fn i32x4_shuffle<const I0: usize, const I1: usize, const I2: usize, const I3: usize>(
+    a: v128,
+    b: v128,
+) -> v128 {
+    let (a, b) = (to_lanes(a), to_lanes(b));
+    let lanes = [a[0], a[1], a[2], a[3], b[0], b[1], b[2], b[3]];
+    from_lanes([lanes[I0], lanes[I1], lanes[I2], lanes[I3]])
+}
+
+// `tbl` zeroes bytes whose index is 16 or above, like WebAssembly.
+#[inline]
+pub fn i8x16_swizzle(a: v128, s: v128) -> v128 {
+    unsafe { vreinterpretq_u32_u8(vqtbl1q_u8(vreinterpretq_u8_u32(a), vreinterpretq_u8_u32(s))) }
+}
+
+// The relaxed instructions use the fused or raw NEON instruction where its behavior is one of
+// the allowed results.
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn f32x4_relaxed_madd(a: v128, b: v128, c: v128) -> v128 {
+    let (a, b, c) = (f(a), f(b), f(c));
+    from_f(unsafe { vfmaq_f32(c, a, b) })
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn f32x4_relaxed_nmadd(a: v128, b: v128, c: v128) -> v128 {
+    let (a, b, c) = (f(a), f(b), f(c));
+    from_f(unsafe { vfmsq_f32(c, a, b) })
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn f32x4_relaxed_min(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    from_f(unsafe { vminq_f32(a, b) })
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn f32x4_relaxed_max(a: v128, b: v128) -> v128 {
+    let (a, b) = (f(a), f(b));
+    from_f(unsafe { vmaxq_f32(a, b) })
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn i32x4_relaxed_trunc_f32x4(a: v128) -> v128 {
+    i32x4_trunc_sat_f32x4(a)
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn u32x4_relaxed_trunc_f32x4(a: v128) -> v128 {
+    u32x4_trunc_sat_f32x4(a)
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn i32x4_relaxed_laneselect(a: v128, b: v128, m: v128) -> v128 {
+    v128_bitselect(a, b, m)
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn i8x16_relaxed_swizzle(a: v128, s: v128) -> v128 {
+    i8x16_swizzle(a, s)
+}
@@ -0,0 +1,530 @@
+// Portable implementation of the `core::arch::wasm32` intrinsics used by this crate, for
+// targets without WebAssembly SIMD. Each function follows the semantics of the instruction
+// it is named after, including wrapping integer arithmetic, saturating float to integer
+// conversions, NaN-propagating `min`/`max` and little-endian byte order for swizzles.
+#![allow(non_camel_case_types)]
+
+/// A 128-bit vector holding four 32-bit lanes.
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+pub struct v128([u32; 4]);
+
+#[inline]
+fn map(a: v128, f: impl Fn(u32) -> u32) -> v128 {
+    v128(a.0.map(f))
+}
+
+#[inline]
+fn zip(a: v128, b: v128, f: impl Fn(u32, u32) -> u32) -> v128 {
+    v128([
+        f(a.0[0], b.0[0]),
+        f(a.0[1], b.0[1]),
+        f(a.0[2], b.0[2]),
+        f(a.0[3], b.0[3]),
+    ])
+}
+
+#[inline]
+fn map_i32(a: v128, f: impl Fn(i32) -> i32) -> v128 {
+    map(a, |x| f(x as i32) as u32)
+}
+
+#[inline]
+fn zip_i32(a: v128, b: v128, f: impl Fn(i32, i32) -> i32) -> v128 {
+    zip(a, b, |x, y| f(x as i32, y as i32) as u32)
+}
+
+#[inline]
+fn map_f32(a: v128, f: impl Fn(f32) -> f32) -> v128 {
+    map(a, |x| f(f32::from_bits(x)).to_bits())
+}
+
+#[inline]
+fn zip_f32(a: v128, b: v128, f: impl Fn(f32, f32) -> f32) -> v128 {
+    zip(a, b, |x, y| {
+        f(f32::from_bits(x), f32::from_bits(y)).to_bits()
+    })
+}
+
+#[inline]
+fn mask(b: bool) -> u32 {
+    if b {
+        u32::MAX
+    } else {
+        0
+    }
+}
+
+#[inline]
+fn cmp_i32(a: v128, b: v128, f: impl Fn(i32, i32) -> bool) -> v128 {
+    zip(a, b, |x, y| mask(f(x as i32, y as i32)))
+}
+
+#[inline]
+fn cmp_u32(a: v128, b: v128, f: impl Fn(u32, u32) -> bool) -> v128 {
+    zip(a, b, |x, y| mask(f(x, y)))
+}
+
+#[inline]
+fn cmp_f32(a: v128, b: v128, f: impl Fn(f32, f32) -> bool) -> v128 {
+    zip(a, b, |x, y| mask(f(f32::from_bits(x), f32::from_bits(y))))
+}
+
+// WebAssembly numbers the bytes of a vector in little-endian lane order.
+#[inline]
+fn to_bytes(a: v128) -> [u8; 16] {
+    let mut bytes = [0; 16];
+    for (chunk, lane) in bytes.chunks_exact_mut(4).zip(a.0) {
+        chunk.copy_from_slice(&lane.to_le_bytes());
+    }
+    bytes
+}
+
+#[inline]
+fn from_bytes(bytes: [u8; 16]) -> v128 {
+    let lane = |i: usize| {
+        u32::from_le_bytes([
+            bytes[i * 4],
+            bytes[i * 4 + 1],
+            bytes[i * 4 + 2],
+            bytes[i * 4 + 3],
+        ])
+    };
+    v128([lane(0), lane(1), lane(2), lane(3)])
+}
+
+const SIGN: u32 = 0x8000_0000;
+const QUIET_NAN: u32 = 0x0040_0000;
+const EXPONENT_BIAS: i32 = 127;
+const MANTISSA_BITS: i32 = 23;
+
+// Rounding a NaN gives the same NaN with the quiet bit set, as the hardware instructions do.
+fn quiet(x: f32) -> f32 {
+    f32::from_bits(x.to_bits() | QUIET_NAN)
+}
+
+// `f32::trunc` and friends live in `std`, so rounding is done on the bit pattern.
+fn trunc(x: f32) -> f32 {
+    let bits = x.to_bits();
+    let exponent = ((bits >> MANTISSA_BITS) & 0xff) as i32 - EXPONENT_BIAS;
+    if x.is_nan() {
+        quiet(x)
+    } else if exponent >= MANTISSA_BITS {
+        // Already an integer or infinite.
+        x
+    } else if exponent < 0 {
+        f32::from_bits(bits & SIGN)
+    } else {
+        f32::from_bits(bits & !(0x007f_ffff >> exponent))
+    }
+}
+
+fn floor(x: f32) -> f32 {
+    let t = trunc(x);
+    if t != x && x < 0.0 {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+fn ceil(x: f32) -> f32 {
+    let t = trunc(x);
+    if t != x && x > 0.0 {
+        t + 1.0
+    } else {
+        t
+    }
+}
+
+// Adding and subtracting 2^23 rounds to an integer using the default ties-to-even mode.
+fn nearest(x: f32) -> f32 {
+    const TWO_POW_23: f32 = 8_388_608.0;
+    let abs = f32::from_bits(x.to_bits() & !SIGN);
+    if abs < TWO_POW_23 {
+        let rounded = (abs + TWO_POW_23) - TWO_POW_23;
+        f32::from_bits(rounded.to_bits() | (x.to_bits() & SIGN))
+    } else if x.is_nan() {
+        quiet(x)
+    } else {
+        x
+    }
+}
+
+pub const fn i32x4(a0: i32, a1: i32, a2: i32, a3: i32) -> v128 {
+    v128([a0 as u32, a1 as u32, a2 as u32, a3 as u32])
+}
+
+pub const fn u32x4(a0: u32, a1: u32, a2: u32, a3: u32) -> v128 {
+    v128([a0, a1, a2, a3])
+}
+
+pub const fn f32x4(a0: f32, a1: f32, a2: f32, a3: f32) -> v128 {
+    v128([a0.to_bits(), a1.to_bits(), a2.to_bits(), a3.to_bits()])
+}
+
+pub fn i32x4_splat(a: i32) -> v128 {
+    v128([a as u32; 4])
+}
+
+pub fn u32x4_splat(a: u32) -> v128 {
+    v128([a; 4])
+}
+
+pub fn i32x4_extract_lane<const N: usize>(a: v128) -> i32 {
+    a.0[N] as i32
+}
+
+pub fn u32x4_extract_lane<const N: usize>(a: v128) -> u32 {
+    a.0[N]
+}
+
+pub fn f32x4_extract_lane<const N: usize>(a: v128) -> f32 {
+    f32::from_bits(a.0[N])
+}
+
+pub fn i32x4_replace_lane<const N: usize>(mut a: v128, val: i32) -> v128 {
+    a.0[N] = val as u32;
+    a
+}
+
+pub fn u32x4_replace_lane<const N: usize>(mut a: v128, val: u32) -> v128 {
+    a.0[N] = val;
+    a
+}
+
+pub fn f32x4_replace_lane<const N: usize>(mut a: v128, val: f32) -> v128 {
+    a.0[N] = val.to_bits();
+    a
+}
+
+pub fn i32x4_add(a: v128, b: v128) -> v128 {
+    zip(a, b, u32::wrapping_add)
+}
+
+pub fn i32x4_sub(a: v128, b: v128) -> v128 {
+    zip(a, b, u32::wrapping_sub)
+}
+
+pub fn i32x4_mul(a: v128, b: v128) -> v128 {
+    zip(a, b, u32::wrapping_mul)
+}
+
+pub fn u32x4_add(a: v128, b: v128) -> v128 {
+    i32x4_add(a, b)
+}
+
+pub fn u32x4_sub(a: v128, b: v128) -> v128 {
+    i32x4_sub(a, b)
+}
+
+pub fn u32x4_mul(a: v128, b: v128) -> v128 {
+    i32x4_mul(a, b)
+}
+
+pub fn i32x4_neg(a: v128) -> v128 {
+    map_i32(a, i32::wrapping_neg)
+}
+
+pub fn i32x4_abs(a: v128) -> v128 {
+    map_i32(a, i32::wrapping_abs)
+}
+
+pub fn i32x4_min(a: v128, b: v128) -> v128 {
+    zip_i32(a, b, i32::min)
+}
+
+pub fn i32x4_max(a: v128, b: v128) -> v128 {
+    zip_i32(a, b, i32::max)
+}
+
+pub fn u32x4_min(a: v128, b: v128) -> v128 {
+    zip(a, b, u32::min)
+}
+
+pub fn u32x4_max(a: v128, b: v128) -> v128 {
+    zip(a, b, u32::max)
+}
+
+pub fn i32x4_shl(a: v128, amt: u32) -> v128 {
+    map(a, |x| x << (amt & 31))
+}
+
+pub fn i32x4_shr(a: v128, amt: u32) -> v128 {
+    map_i32(a, |x| x >> (amt & 31))
+}
+
+pub fn u32x4_shl(a: v128, amt: u32) -> v128 {
+    i32x4_shl(a, amt)
+}
+
+pub fn u32x4_shr(a: v128, amt: u32) -> v128 {
+    map(a, |x| x >> (amt & 31))
+}
+
+pub fn i32x4_eq(a: v128, b: v128) -> v128 {
+    cmp_u32(a, b, |x, y| x == y)
+}
+
+pub fn i32x4_ne(a: v128, b: v128) -> v128 {
+    cmp_u32(a, b, |x, y| x != y)
+}
+
+pub fn i32x4_lt(a: v128, b: v128) -> v128 {
+    cmp_i32(a, b, |x, y| x < y)
+}
+
+pub fn i32x4_le(a: v128, b: v128) -> v128 {
+    cmp_i32(a, b, |x, y| x <= y)
+}
+
+pub fn i32x4_gt(a: v128, b: v128) -> v128 {
+    cmp_i32(a, b, |x, y| x > y)
+}
+
+pub fn i32x4_ge(a: v128, b: v128) -> v128 {
+    cmp_i32(a, b, |x, y| x >= y)
+}
+
+pub fn u32x4_eq(a: v128, b: v128) -> v128 {
+    i32x4_eq(a, b)
+}
+
+pub fn u32x4_ne(a: v128, b: v128) -> v128 {
+    i32x4_ne(a, b)
+}
+
+pub fn u32x4_lt(a: v128, b: v128) -> v128 {
+    cmp_u32(a, b, |x, y| x < y)
+}
+
+pub fn u32x4_le(a: v128, b: v128) -> v128 {
+    cmp_u32(a, b, |x, y| x <= y)
+}
+
+pub fn u32x4_gt(a: v128, b: v128) -> v128 {
+    cmp_u32(a, b, |x, y| x > y)
+}
+
+pub fn u32x4_ge(a: v128, b: v128) -> v128 {
+    cmp_u32(a, b, |x, y| x >= y)
+}
+
+pub fn f32x4_eq(a: v128, b: v128) -> v128 {
+    cmp_f32(a, b, |x, y| x == y)
+}
+
+pub fn f32x4_ne(a: v128, b: v128) -> v128 {
+    cmp_f32(a, b, |x, y| x != y)
+}
+
+pub fn f32x4_lt(a: v128, b: v128) -> v128 {
+    cmp_f32(a, b, |x, y| x < y)
+}
+
+pub fn f32x4_le(a: v128, b: v128) -> v128 {
+    cmp_f32(a, b, |x, y| x <= y)
+}
+
+pub fn f32x4_gt(a: v128, b: v128) -> v128 {
+    cmp_f32(a, b, |x, y| x > y)
+}
+
+pub fn f32x4_ge(a: v128, b: v128) -> v128 {
+    cmp_f32(a, b, |x, y| x >= y)
+}
+
+pub fn f32x4_add(a: v128, b: v128) -> v128 {
+    zip_f32(a, b, |x, y| x + y)
+}
+
+pub fn f32x4_sub(a: v128, b: v128) -> v128 {
+    zip_f32(a, b, |x, y| x - y)
+}
+
+pub fn f32x4_mul(a: v128, b: v128) -> v128 {
+    zip_f32(a, b, |x, y| x * y)
+}
+
+pub fn f32x4_div(a: v128, b: v128) -> v128 {
+    zip_f32(a, b, |x, y| x / y)
+}
+
+// NaN in either lane gives NaN, and -0.0 is less than 0.0.
+pub fn f32x4_min(a: v128, b: v128) -> v128 {
+    zip_f32(a, b, |x, y| {
+        if x.is_nan() || y.is_nan() {
+            f32::NAN
+        } else if x == y {
+            f32::from_bits(x.to_bits() | y.to_bits())
+        } else if x < y {
+            x
+        } else {
+            y
+        }
+    })
+}
+
+pub fn f32x4_max(a: v128, b: v128) -> v128 {
+    zip_f32(a, b, |x, y| {
+        if x.is_nan() || y.is_nan() {
+            f32::NAN
+        } else if x == y {
+            f32::from_bits(x.to_bits() & y.to_bits())
+        } else if x > y {
+            x
+        } else {
+            y
+        }
+    })
+}
+
+pub fn f32x4_abs(a: v128) -> v128 {
+    map(a, |x| x & !SIGN)
+}
+
+pub fn f32x4_neg(a: v128) -> v128 {
+    map(a, |x| x ^ SIGN)
+}
+
+pub fn f32x4_sqrt(a: v128) -> v128 {
+    map_f32(a, libm::sqrtf)
+}
+
+pub fn f32x4_ceil(a: v128) -> v128 {
+    map_f32(a, ceil)
+}
+
+pub fn f32x4_floor(a: v128) -> v128 {
+    map_f32(a, floor)
+}
+
+pub fn f32x4_trunc(a: v128) -> v128 {
+    map_f32(a, trunc)
+}
+
+pub fn f32x4_nearest(a: v128) -> v128 {
+    map_f32(a, nearest)
+}
+
+// `as` casts from float to integer saturate and map NaN to 0, like `trunc_sat`.
+pub fn i32x4_trunc_sat_f32x4(a: v128) -> v128 {
+    map(a, |x| f32::from_bits(x) as i32 as u32)
+}
+
+pub fn u32x4_trunc_sat_f32x4(a: v128) -> v128 {
+    map(a, |x| f32::from_bits(x) as u32)
+}
+
+pub fn f32x4_convert_i32x4(a: v128) -> v128 {
+    map(a, |x| (x as i32 as f32).to_bits())
+}
+
+pub fn f32x4_convert_u32x4(a: v128) -> v128 {
+    map(a, |x| (x as f32).to_bits())
+}
+
+pub fn v128_and(a: v128, b: v128) -> v128 {
+    zip(a, b, |x, y| x & y)
+}
+
+pub fn v128_or(a: v128, b: v128) -> v128 {
+    zip(a, b, |x, y| x | y)
+}
+
+pub fn v128_xor(a: v128, b: v128) -> v128 {
+    zip(a, b, |x, y| x ^ y)
+}
+
+pub fn v128_not(a: v128) -> v128 {
+    map(a, |x| !x)
+}
+
+pub fn v128_bitselect(v1: v128, v2: v128, c: v128) -> v128 {
+    v128_or(v128_and(v1, c), v128_and(v2, v128_not(c)))
+}
+
+pub fn v128_any_true(a: v128) -> bool {
+    a.0.iter().any(|&x| x != 0)
+}
+
+pub fn i32x4_all_true(a: v128) -> bool {
+    a.0.iter().all(|&x| x != 0)
+}
+
+pub fn u32x4_all_true(a: v128) -> bool {
+    i32x4_all_true(a)
+}
+
+pub fn i32x4_bitmask(a: v128) -> u8 {
+    a.0.iter()
+        .enumerate()
+        .fold(0, |bits, (i, &x)| bits | (((x >> 31) as u8) << i))
+}
+
+pub fn i32x4_shuffle<const I0: usize, const I1: usize, const I2: usize, const I3: usize>(
+    a: v128,
+    b: v128,
+) -> v128 {
+    let lanes = [
+        a.0[0], a.0[1], a.0[2], a.0[3], b.0[0], b.0[1], b.0[2], b.0[3],
+    ];
+    v128([lanes[I0], lanes[I1], lanes[I2], lanes[I3]])
+}
+
+// Bytes whose index is 16 or more become 0.
+pub fn i8x16_swizzle(a: v128, s: v128) -> v128 {
+    let (bytes, indices) = (to_bytes(a), to_bytes(s));
+    from_bytes(indices.map(|i| bytes.get(i as usize).copied().unwrap_or(0)))
+}
+
+// The relaxed instructions take their deterministic behavior here.
+
+#[cfg(feature = "relaxed-simd")]
+pub fn f32x4_relaxed_madd(a: v128, b: v128, c: v128) -> v128 {
+    f32x4_add(f32x4_mul(a, b), c)
+}
+
+#[cfg(feature = "relaxed-simd")]
+pub fn f32x4_relaxed_nmadd(a: v128, b: v128, c: v128) -> v128 {
+    f32x4_sub(c, f32x4_mul(a, b))
+}
+
+#[cfg(feature = "relaxed-simd")]
+pub fn f32x4_relaxed_min(a: v128, b: v128) -> v128 {
+    f32x4_min(a, b)
+}
+
+#[cfg(feature = "relaxed-simd")]
+pub fn f32x4_relaxed_max(a: v128, b: v128) -> v128 {
+    f32x4_max(a, b)
+}
+
+#[cfg(feature = "relaxed-simd")]
+pub fn i32x4_relaxed_trunc_f32x4(a: v128) -> v128 {
+    i32x4_trunc_sat_f32x4(a)
+}
+
+#[cfg(feature = "relaxed-simd")]
+pub fn u32x4_relaxed_trunc_f32x4(a: v128) -> v128 {
+    u32x4_trunc_sat_f32x4(a)
+}
+
+#[cfg(feature = "relaxed-simd")]
+pub fn i32x4_relaxed_laneselect(a: v128, b: v128, m: v128) -> v128 {
+    v128_bitselect(a, b, m)
+}
+
+#[cfg(feature = "relaxed-simd")]
+pub fn i8x16_relaxed_swizzle(a: v128, s: v128) -> v128 {
+    i8x16_swizzle(a, s)
+}
@@ -0,0 +1,707 @@
+// SSE implementation of the `core::arch::wasm32` intrinsics used by this crate, for x86_64.
+//
+// Vectors are stored as `__m128i`; float operations reinterpret them as `__m128` with the
+// zero-cost `_mm_cast*` intrinsics. SSE2 is always available on x86_64. When the crate is
+// built with `+ssse3` or `+sse4.1` (for example `-C target-cpu=native`) shorter sequences are
+// used for the operations those extensions cover. Every function returns exactly what the
+// WebAssembly instruction of the same name returns, bit for bit, including NaN-propagating
+// `min`/`max`, saturating float to integer conversions and out-of-range swizzle indices. The
+// only NaN payloads produced here that the hardware does not are the canonical NaNs from
+// `f32x4_min`/`f32x4_max`, which match the scalar backend.
+//
+// The intrinsics are `unsafe` to call because they require their target feature. SSE2 is
+// part of the x86_64 baseline and the SSSE3/SSE4.1 paths are only compiled when those
+// features are enabled for the whole build, so every call below is sound.
+#![allow(non_camel_case_types)]
+
+use core::arch::x86_64::*;
+
+pub use core::arch::x86_64::__m128i as v128;
+
+const SIGN: i32 = i32::MIN;
+const CANONICAL_NAN: i32 = 0x7fc0_0000;
+#[cfg(not(target_feature = "sse4.1"))]
+const QUIET_NAN: i32 = 0x0040_0000;
+
+#[inline(always)]
+fn ps(a: v128) -> __m128 {
+    unsafe { _mm_castsi128_ps(a) }
+}
+
+#[inline(always)]
+fn si(a: __m128) -> v128 {
+    unsafe { _mm_castps_si128(a) }
+}
+
+#[inline(always)]
+fn to_lanes(a: v128) -> [u32; 4] {
+    // SAFETY: `__m128i` and `[u32; 4]` have the same size and every bit pattern is valid.
+    unsafe { core::mem::transmute(a) }
+}
+
+#[inline(always)]
+fn from_lanes(lanes: [u32; 4]) -> v128 {
+    // SAFETY: as above.
+    unsafe { core::mem::transmute(lanes) }
+}
+
+// `(if_true & mask) | (if_false & !mask)`
+#[inline(always)]
+fn select(mask: v128, if_true: v128, if_false: v128) -> v128 {
+    unsafe {
+        _mm_or_si128(
+            _mm_and_si128(mask, if_true),
+            _mm_andnot_si128(mask, if_false),
+        )
+    }
+}
+
+#[inline(always)]
+fn flip_sign(a: v128) -> v128 {
+    unsafe { _mm_xor_si128(a, _mm_set1_epi32(SIGN)) }
+}
+
+pub const fn i32x4(a0: i32, a1: i32, a2: i32, a3: i32) -> v128 {
+    // SAFETY: `__m128i` and `[i32; 4]` have the same size and every bit pattern is valid.
+    unsafe { core::mem::transmute([a0, a1, a2, a3]) }
+}
+
+pub const fn u32x4(a0: u32, a1: u32, a2: u32, a3: u32) -> v128 {
+    // SAFETY: as above.
+    unsafe { core::mem::transmute([a0, a1, a2, a3]) }
+}
+
+pub const fn f32x4(a0: f32, a1: f32, a2: f32, a3: f32) -> v128 {
+    // SAFETY: as above.
+    unsafe { core::mem::transmute([a0, a1, a2, a3]) }
+}
+
+#[inline]
+pub fn i32x4_splat(a: i32) -> v128 {
+    unsafe { _mm_set1_epi32(a) }
+}
+
+#[inline]
+pub fn u32x4_splat(a: u32) -> v128 {
+    unsafe { _mm_set1_epi32(a as i32) }
+}
+
+#[inline]
+pub fn i32x4_extract_lane<const N: usize>(a: v128) -> i32 {
+    to_lanes(a)[N] as i32
+}
+
+#[inline]
+pub fn u32x4_extract_lane<const N: usize>(a: v128) -> u32 {
+    to_lanes(a)[N]
+}
+
+#[inline]
+pub fn f32x4_extract_lane<const N: usize>(a: v128) -> f32 {
+    f32::from_bits(to_lanes(a)[N])
+}
+
+#[inline]
+pub fn i32x4_replace_lane<const N: usize>(a: v128, val: i32) -> v128 {
+    u32x4_replace_lane::<N>(a, val as u32)
+}
+
+#[inline]
+pub fn u32x4_replace_lane<const N: usize>(a: v128, val: u32) -> v128 {
+    let mut lanes = to_lanes(a);
+    lanes[N] = val;
+    from_lanes(lanes)
+}
+
+#[inline]
+pub fn f32x4_replace_lane<const N: usize>(a: v128, val: f32) -> v128 {
+    u32x4_replace_lane::<N>(a, val.to_bits())
+}
+
+#[inline]
+pub fn i32x4_add(a: v128, b: v128) -> v128 {
+    unsafe { _mm_add_epi32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_sub(a: v128, b: v128) -> v128 {
+    unsafe { _mm_sub_epi32(a, b) }
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+pub fn i32x4_mul(a: v128, b: v128) -> v128 {
+    unsafe { _mm_mullo_epi32(a, b) }
+}
+
+// Multiplies the even and odd lanes separately into 64-bit products and keeps the low halves.
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+pub fn i32x4_mul(a: v128, b: v128) -> v128 {
+    unsafe {
+        let even = _mm_mul_epu32(a, b);
+        let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(a), _mm_srli_epi64::<32>(b));
+        let even = _mm_shuffle_epi32::<0b00_00_10_00>(even);
+        let odd = _mm_shuffle_epi32::<0b00_00_10_00>(odd);
+        _mm_unpacklo_epi32(even, odd)
+    }
+}
+
+#[inline]
+pub fn u32x4_add(a: v128, b: v128) -> v128 {
+    i32x4_add(a, b)
+}
+
+#[inline]
+pub fn u32x4_sub(a: v128, b: v128) -> v128 {
+    i32x4_sub(a, b)
+}
+
+#[inline]
+pub fn u32x4_mul(a: v128, b: v128) -> v128 {
+    i32x4_mul(a, b)
+}
+
+#[inline]
+pub fn i32x4_neg(a: v128) -> v128 {
+    unsafe { _mm_sub_epi32(_mm_setzero_si128(), a) }
+}
+
+#[cfg(target_feature = "ssse3")]
+#[inline]
+pub fn i32x4_abs(a: v128) -> v128 {
+    unsafe { _mm_abs_epi32(a) }
+}
+
+#[cfg(not(target_feature = "ssse3"))]
+#[inline]
+pub fn i32x4_abs(a: v128) -> v128 {
+    unsafe {
+        let sign = _mm_srai_epi32::<31>(a);
+        _mm_sub_epi32(_mm_xor_si128(a, sign), sign)
+    }
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+pub fn i32x4_min(a: v128, b: v128) -> v128 {
+    unsafe { _mm_min_epi32(a, b) }
+}
+
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+pub fn i32x4_min(a: v128, b: v128) -> v128 {
+    select(unsafe { _mm_cmplt_epi32(a, b) }, a, b)
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+pub fn i32x4_max(a: v128, b: v128) -> v128 {
+    unsafe { _mm_max_epi32(a, b) }
+}
+
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+pub fn i32x4_max(a: v128, b: v128) -> v128 {
+    select(unsafe { _mm_cmpgt_epi32(a, b) }, a, b)
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+pub fn u32x4_min(a: v128, b: v128) -> v128 {
+    unsafe { _mm_min_epu32(a, b) }
+}
+
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+pub fn u32x4_min(a: v128, b: v128) -> v128 {
+    select(u32x4_lt(a, b), a, b)
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+pub fn u32x4_max(a: v128, b: v128) -> v128 {
+    unsafe { _mm_max_epu32(a, b) }
+}
+
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+pub fn u32x4_max(a: v128, b: v128) -> v128 {
+    select(u32x4_gt(a, b), a, b)
+}
+
+// WebAssembly takes shift amounts modulo the lane width, SSE saturates them.
+#[inline]
+pub fn i32x4_shl(a: v128, amt: u32) -> v128 {
+    unsafe { _mm_sll_epi32(a, _mm_cvtsi32_si128((amt & 31) as i32)) }
+}
+
+#[inline]
+pub fn i32x4_shr(a: v128, amt: u32) -> v128 {
+    unsafe { _mm_sra_epi32(a, _mm_cvtsi32_si128((amt & 31) as i32)) }
+}
+
+#[inline]
+pub fn u32x4_shl(a: v128, amt: u32) -> v128 {
+    i32x4_shl(a, amt)
+}
+
+#[inline]
+pub fn u32x4_shr(a: v128, amt: u32) -> v128 {
+    unsafe { _mm_srl_epi32(a, _mm_cvtsi32_si128((amt & 31) as i32)) }
+}
+
+#[inline]
+pub fn i32x4_eq(a: v128, b: v128) -> v128 {
+    unsafe { _mm_cmpeq_epi32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_ne(a: v128, b: v128) -> v128 {
+    v128_not(unsafe { _mm_cmpeq_epi32(a, b) })
+}
+
+#[inline]
+pub fn i32x4_lt(a: v128, b: v128) -> v128 {
+    unsafe { _mm_cmplt_epi32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_le(a: v128, b: v128) -> v128 {
+    v128_not(unsafe { _mm_cmpgt_epi32(a, b) })
+}
+
+#[inline]
+pub fn i32x4_gt(a: v128, b: v128) -> v128 {
+    unsafe { _mm_cmpgt_epi32(a, b) }
+}
+
+#[inline]
+pub fn i32x4_ge(a: v128, b: v128) -> v128 {
+    v128_not(unsafe { _mm_cmplt_epi32(a, b) })
+}
+
+#[inline]
+pub fn u32x4_eq(a: v128, b: v128) -> v128 {
+    i32x4_eq(a, b)
+}
+
+#[inline]
+pub fn u32x4_ne(a: v128, b: v128) -> v128 {
+    i32x4_ne(a, b)
+}
+
+// SSE only has signed comparisons, so unsigned ones flip the sign bit of both sides first.
+#[inline]
+pub fn u32x4_lt(a: v128, b: v128) -> v128 {
+    i32x4_lt(flip_sign(a), flip_sign(b))
+}
+
+#[inline]
+pub fn u32x4_le(a: v128, b: v128) -> v128 {
+    i32x4_le(flip_sign(a), flip_sign(b))
+}
+
+#[inline]
+pub fn u32x4_gt(a: v128, b: v128) -> v128 {
+    i32x4_gt(flip_sign(a), flip_sign(b))
+}
+
+#[inline]
+pub fn u32x4_ge(a: v128, b: v128) -> v128 {
+    i32x4_ge(flip_sign(a), flip_sign(b))
+}
+
+#[inline]
+pub fn f32x4_eq(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_cmpeq_ps(a, b) })
+}
+
+#[inline]
+pub fn f32x4_ne(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_cmpneq_ps(a, b) })
+}
+
+#[inline]
+pub fn f32x4_lt(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_cmplt_ps(a, b) })
+}
+
+#[inline]
+pub fn f32x4_le(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_cmple_ps(a, b) })
+}
+
+#[inline]
+pub fn f32x4_gt(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_cmpgt_ps(a, b) })
+}
+
+#[inline]
+pub fn f32x4_ge(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_cmpge_ps(a, b) })
+}
+
+#[inline]
+pub fn f32x4_add(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_add_ps(a, b) })
+}
+
+#[inline]
+pub fn f32x4_sub(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_sub_ps(a, b) })
+}
+
+#[inline]
+pub fn f32x4_mul(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_mul_ps(a, b) })
+}
+
+#[inline]
+pub fn f32x4_div(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_div_ps(a, b) })
+}
+
+// `minps` returns its second operand when either input is NaN or both are zero. Taking it
+// both ways round and combining the results orders -0.0 below 0.0, then NaN lanes are
+// replaced with the canonical NaN.
+#[inline]
+pub fn f32x4_min(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    let (min, nan, canonical) = unsafe {
+        (
+            _mm_or_ps(_mm_min_ps(a, b), _mm_min_ps(b, a)),
+            _mm_cmpunord_ps(a, b),
+            _mm_set1_epi32(CANONICAL_NAN),
+        )
+    };
+    select(si(nan), canonical, si(min))
+}
+
+#[inline]
+pub fn f32x4_max(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    let (max, nan, canonical) = unsafe {
+        (
+            _mm_and_ps(_mm_max_ps(a, b), _mm_max_ps(b, a)),
+            _mm_cmpunord_ps(a, b),
+            _mm_set1_epi32(CANONICAL_NAN),
+        )
+    };
+    select(si(nan), canonical, si(max))
+}
+
+#[inline]
+pub fn f32x4_abs(a: v128) -> v128 {
+    unsafe { _mm_andnot_si128(_mm_set1_epi32(SIGN), a) }
+}
+
+#[inline]
+pub fn f32x4_neg(a: v128) -> v128 {
+    flip_sign(a)
+}
+
+#[inline]
+pub fn f32x4_sqrt(a: v128) -> v128 {
+    let a = ps(a);
+    si(unsafe { _mm_sqrt_ps(a) })
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+pub fn f32x4_ceil(a: v128) -> v128 {
+    let a = ps(a);
+    si(unsafe { _mm_round_ps::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a) })
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+pub fn f32x4_floor(a: v128) -> v128 {
+    let a = ps(a);
+    si(unsafe { _mm_round_ps::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(a) })
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+pub fn f32x4_trunc(a: v128) -> v128 {
+    let a = ps(a);
+    si(unsafe { _mm_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a) })
+}
+
+#[cfg(target_feature = "sse4.1")]
+#[inline]
+pub fn f32x4_nearest(a: v128) -> v128 {
+    let a = ps(a);
+    si(unsafe { _mm_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a) })
+}
+
+// Without `roundps`, lanes below 2^23 in magnitude are rounded through an integer conversion
+// (or the 2^23 addition trick for ties-to-even). Larger lanes are already integers and are
+// passed through, with NaNs quieted like `roundps` does.
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+fn round_small(a: v128, rounded: __m128) -> v128 {
+    let (rounded, abs, float) = (si(rounded), ps(f32x4_abs(a)), ps(a));
+    let (rounded, small, nan) = unsafe {
+        let sign = _mm_and_si128(a, _mm_set1_epi32(SIGN));
+        (
+            _mm_or_si128(rounded, sign),
+            _mm_cmplt_ps(abs, _mm_set1_ps(8_388_608.0)),
+            _mm_cmpunord_ps(float, float),
+        )
+    };
+    let nan = si(nan);
+    let passthrough = unsafe { _mm_or_si128(a, _mm_and_si128(nan, _mm_set1_epi32(QUIET_NAN))) };
+    select(si(small), rounded, passthrough)
+}
+
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+pub fn f32x4_trunc(a: v128) -> v128 {
+    let float = ps(a);
+    round_small(a, unsafe { _mm_cvtepi32_ps(_mm_cvttps_epi32(float)) })
+}
+
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+pub fn f32x4_floor(a: v128) -> v128 {
+    let (t, float) = (ps(f32x4_trunc(a)), ps(a));
+    let floor = unsafe {
+        let adjust = _mm_and_ps(_mm_cmpgt_ps(t, float), _mm_set1_ps(1.0));
+        _mm_sub_ps(t, adjust)
+    };
+    round_small(a, floor)
+}
+
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+pub fn f32x4_ceil(a: v128) -> v128 {
+    let (t, float) = (ps(f32x4_trunc(a)), ps(a));
+    let ceil = unsafe {
+        let adjust = _mm_and_ps(_mm_cmplt_ps(t, float), _mm_set1_ps(1.0));
+        _mm_add_ps(t, adjust)
+    };
+    round_small(a, ceil)
+}
+
+#[cfg(not(target_feature = "sse4.1"))]
+#[inline]
+pub fn f32x4_nearest(a: v128) -> v128 {
+    let abs = ps(f32x4_abs(a));
+    let nearest = unsafe {
+        let two_pow_23 = _mm_set1_ps(8_388_608.0);
+        _mm_sub_ps(_mm_add_ps(abs, two_pow_23), two_pow_23)
+    };
+    round_small(a, nearest)
+}
+
+// `cvttps2dq` returns `i32::MIN` for NaN and out-of-range lanes. Positive overflow is turned
+// into `i32::MAX` and NaN into 0.
+#[inline]
+pub fn i32x4_trunc_sat_f32x4(a: v128) -> v128 {
+    let a = ps(a);
+    let (truncated, overflow, ordered) = unsafe {
+        (
+            _mm_cvttps_epi32(a),
+            _mm_cmpge_ps(a, _mm_set1_ps(2_147_483_648.0)),
+            _mm_cmpord_ps(a, a),
+        )
+    };
+    let (overflow, ordered) = (si(overflow), si(ordered));
+    unsafe { _mm_and_si128(_mm_xor_si128(truncated, overflow), ordered) }
+}
+
+// Negative and NaN lanes are zeroed first. Lanes of 2^31 and above are converted after
+// subtracting 2^31, which is added back with the sign bit, and lanes of 2^32 and above
+// saturate to `u32::MAX`.
+#[inline]
+pub fn u32x4_trunc_sat_f32x4(a: v128) -> v128 {
+    let a = ps(a);
+    let (low, high, is_high, overflow) = unsafe {
+        let two_pow_31 = _mm_set1_ps(2_147_483_648.0);
+        let positive = _mm_and_ps(a, _mm_cmpgt_ps(a, _mm_setzero_ps()));
+        (
+            _mm_cvttps_epi32(positive),
+            _mm_cvttps_epi32(_mm_sub_ps(positive, two_pow_31)),
+            _mm_cmpge_ps(positive, two_pow_31),
+            _mm_cmpge_ps(positive, _mm_set1_ps(4_294_967_296.0)),
+        )
+    };
+    let converted = select(si(is_high), flip_sign(high), low);
+    let overflow = si(overflow);
+    unsafe { _mm_or_si128(converted, overflow) }
+}
+
+#[inline]
+pub fn f32x4_convert_i32x4(a: v128) -> v128 {
+    si(unsafe { _mm_cvtepi32_ps(a) })
+}
+
+// Both 16-bit halves convert exactly and scaling the high half by 2^16 is exact, so the
+// final addition is the only rounding step, as in a direct conversion.
+#[inline]
+pub fn f32x4_convert_u32x4(a: v128) -> v128 {
+    si(unsafe {
+        let high = _mm_cvtepi32_ps(_mm_srli_epi32::<16>(a));
+        let low = _mm_cvtepi32_ps(_mm_and_si128(a, _mm_set1_epi32(0xffff)));
+        _mm_add_ps(_mm_mul_ps(high, _mm_set1_ps(65_536.0)), low)
+    })
+}
+
+#[inline]
+pub fn v128_and(a: v128, b: v128) -> v128 {
+    unsafe { _mm_and_si128(a, b) }
+}
+
+#[inline]
+pub fn v128_or(a: v128, b: v128) -> v128 {
+    unsafe { _mm_or_si128(a, b) }
+}
+
+#[inline]
+pub fn v128_xor(a: v128, b: v128) -> v128 {
+    unsafe { _mm_xor_si128(a, b) }
+}
+
+#[inline]
+pub fn v128_not(a: v128) -> v128 {
+    unsafe { _mm_xor_si128(a, _mm_set1_epi32(-1)) }
+}
+
+#[inline]
+pub fn v128_bitselect(v1: v128, v2: v128, c: v128) -> v128 {
+    select(c, v1, v2)
+}
+
+#[inline]
+pub fn v128_any_true(a: v128) -> bool {
+    unsafe { _mm_movemask_epi8(_mm_cmpeq_epi8(a, _mm_setzero_si128())) != 0xffff }
+}
+
+#[inline]
+pub fn i32x4_all_true(a: v128) -> bool {
+    let zero_lanes = ps(unsafe { _mm_cmpeq_epi32(a, _mm_setzero_si128()) });
+    unsafe { _mm_movemask_ps(zero_lanes) == 0 }
+}
+
+#[inline]
+pub fn u32x4_all_true(a: v128) -> bool {
+    i32x4_all_true(a)
+}
+
+#[inline]
+pub fn i32x4_bitmask(a: v128) -> u8 {
+    let a = ps(a);
+    unsafe { _mm_movemask_ps(a) as u8 }
+}
+
+// The indices are constants, so LLVM lowers the lane picks to `shufps`/`pshufd`.
+#[inline]
+pub fn i32x4_shuffle<const I0: usize, const I1: usize, const I2: usize, const I3: usize>(
+    a: v128,
+    b: v128,
+) -> v128 {
+    let (a, b) = (to_lanes(a), to_lanes(b));
+    let lanes = [a[0], a[1], a[2], a[3], b[0], b[1], b[2], b[3]];
+    from_lanes([lanes[I0], lanes[I1], lanes[I2], lanes[I3]])
+}
+
+// `pshufb` zeroes bytes whose index has the top bit set and otherwise uses the low four
+// bits, so indices of 16 and above are pushed past 0x80 with a saturating add first.
+#[cfg(target_feature = "ssse3")]
+#[inline]
+pub fn i8x16_swizzle(a: v128, s: v128) -> v128 {
+    unsafe { _mm_shuffle_epi8(a, _mm_adds_epu8(s, _mm_set1_epi8(0x70))) }
+}
+
+#[cfg(not(target_feature = "ssse3"))]
+#[inline]
+pub fn i8x16_swizzle(a: v128, s: v128) -> v128 {
+    // SAFETY: `__m128i` and `[u8; 16]` have the same size and every bit pattern is valid.
+    let (bytes, indices) = unsafe {
+        (
+            core::mem::transmute::<v128, [u8; 16]>(a),
+            core::mem::transmute::<v128, [u8; 16]>(s),
+        )
+    };
+    let swizzled = indices.map(|i| bytes.get(i as usize).copied().unwrap_or(0));
+    // SAFETY: as above.
+    unsafe { core::mem::transmute::<[u8; 16], v128>(swizzled) }
+}
+
+// The relaxed instructions use the plain SSE instruction where its behavior is one of the
+// allowed results.
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn f32x4_relaxed_madd(a: v128, b: v128, c: v128) -> v128 {
+    f32x4_add(f32x4_mul(a, b), c)
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn f32x4_relaxed_nmadd(a: v128, b: v128, c: v128) -> v128 {
+    f32x4_sub(c, f32x4_mul(a, b))
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn f32x4_relaxed_min(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_min_ps(a, b) })
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn f32x4_relaxed_max(a: v128, b: v128) -> v128 {
+    let (a, b) = (ps(a), ps(b));
+    si(unsafe { _mm_max_ps(a, b) })
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn i32x4_relaxed_trunc_f32x4(a: v128) -> v128 {
+    let a = ps(a);
+    unsafe { _mm_cvttps_epi32(a) }
+}
+
+#[cfg(feature = "relaxed-simd")]
+#[inline]
+pub fn u32x4_relaxed_trunc_f32x4(a: v128) -> v128 {
+    u32x4_trunc_sat_f32x4(a)
+}
+
+#[cfg(all(feature = "relaxed-simd", target_feature = "sse4.1"))]
+#[inline]
+pub fn i32x4_relaxed_laneselect(a: v128, b: v128, m: v128) -> v128 {
+    let (a, b, m) = (ps(a), ps(b), ps(m));
+    si(unsafe { _mm_blendv_ps(b, a, m) })
+}
+
+#[cfg(all(feature = "relaxed-simd", not(target_feature = "sse4.1")))]
+#[inline]
+pub fn i32x4_relaxed_laneselect(a: v128, b: v128, m: v128) -> v128 {
+    v128_bitselect(a, b, m)
+}
+
+#[cfg(all(feature = "relaxed-simd", target_feature = "ssse3"))]
+#[inline]
+pub fn i8x16_relaxed_swizzle(a: v128, s: v128) -> v128 {
+    unsafe { _mm_shuffle_epi8(a, s) }
+}
+
+#[cfg(all(feature = "relaxed-simd", not(target_feature = "ssse3")))]
+#[inline]
+pub fn i8x16_relaxed_swizzle(a: v128, s: v128) -> v128 {
+    i8x16_swizzle(a, s)
+}
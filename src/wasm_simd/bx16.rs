@@ -0,0 +1,14 @@
+use crate::wasm_simd::bx4::Bx4;
+use crate::wasm_simd::i32x16::I32x16;
+use crate::wasm_simd::macros::define_wide_mask;
+use crate::wasm_simd::u32x16::U32x16;
+
+define_wide_mask! {
+    /// A 16-lane mask made of four `Bx4`, produced by comparing wide vectors.
+    Bx16 {
+        parts: 4,
+        lanes: 16,
+        bitmask: u16,
+        vectors: [I32x16 => to_i32x16, U32x16 => to_u32x16_ones],
+    }
+}
@@ -1,16 +1,59 @@
-use core::arch::wasm32::*;
-use std::fmt::Debug;
-use std::ops::Not;
-use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use crate::wasm_simd::arch::*;
+use core::fmt::Debug;
 
-use crate::impl_default;
+use crate::wasm_simd::error::LaneIndexError;
+use crate::wasm_simd::i32x4::I32x4;
+use crate::wasm_simd::simd::{Mask, MaskElement};
+use crate::wasm_simd::u32x4::U32x4;
+use crate::{impl_vec_from_str, impl_vec_shuffle};
 
 const BIT_MASK_32: i32 = -1i32;
 
-pub struct Bx4(v128);
+pub type Bx4 = Mask<i32, 4>;
+
+impl MaskElement<4> for i32 {
+    type MaskRepr = v128;
+    type Bitmask = u8;
+
+    fn mask_splat(value: bool) -> v128 {
+        Bx4::splat(value).0
+    }
+
+    fn mask_from_array([v1, v2, v3, v4]: [bool; 4]) -> v128 {
+        Bx4::new(v1, v2, v3, v4).0
+    }
+
+    fn mask_to_array(repr: v128) -> [bool; 4] {
+        let (v1, v2, v3, v4) = Bx4::from_v128(repr).extract_lanes();
+        [v1, v2, v3, v4]
+    }
+
+    fn mask_and(a: v128, b: v128) -> v128 {
+        v128_and(a, b)
+    }
+
+    fn mask_or(a: v128, b: v128) -> v128 {
+        v128_or(a, b)
+    }
+
+    fn mask_xor(a: v128, b: v128) -> v128 {
+        v128_xor(a, b)
+    }
+
+    fn mask_not(a: v128) -> v128 {
+        v128_not(a)
+    }
+
+    fn mask_to_bitmask(repr: v128) -> u8 {
+        Bx4::from_v128(repr).to_bitmask()
+    }
+}
 
 impl Bx4 {
-    pub fn new(v1: bool, v2: bool, v3: bool, v4: bool) -> Self {
+    pub const ALL_FALSE: Self = Self::splat(false);
+    pub const ALL_TRUE: Self = Self::splat(true);
+
+    pub const fn new(v1: bool, v2: bool, v3: bool, v4: bool) -> Self {
         let mask_v1 = if v1 { BIT_MASK_32 } else { 0 };
         let mask_v2 = if v2 { BIT_MASK_32 } else { 0 };
         let mask_v3 = if v3 { BIT_MASK_32 } else { 0 };
@@ -19,10 +62,10 @@ impl Bx4 {
         Self(i32x4(mask_v1, mask_v2, mask_v3, mask_v4))
     }
 
-    pub fn splat(value: bool) -> Self {
+    pub const fn splat(value: bool) -> Self {
         let mask = if value { BIT_MASK_32 } else { 0 };
 
-        Self(i32x4_splat(mask))
+        Self(i32x4(mask, mask, mask, mask))
     }
 
     pub fn extract_lanes(&self) -> (bool, bool, bool, bool) {
@@ -55,178 +98,176 @@ impl Bx4 {
         };
     }
 
-    pub fn to_bitmask(self) -> u8 {
-        i32x4_bitmask(self.0)
+    pub fn extract<const N: usize>(&self) -> bool {
+        const { assert!(N < 4, "Index out of bounds for Bx4") };
+        i32x4_extract_lane::<N>(self.0) != 0
     }
 
-    pub(crate) fn from_v128(data: v128) -> Self {
-        Self(data)
+    pub fn with_lane<const N: usize>(&self, value: bool) -> Self {
+        const { assert!(N < 4, "Index out of bounds for Bx4") };
+        let mask_value = if value { BIT_MASK_32 } else { 0 };
+        Self(i32x4_replace_lane::<N>(self.0, mask_value))
     }
 
-    pub(crate) fn to_v128(self) -> v128 {
-        self.0
+    pub fn set<const N: usize>(&mut self, value: bool) {
+        *self = self.with_lane::<N>(value);
     }
-}
 
-impl_default!(Bx4, bool);
-
-impl Clone for Bx4 {
-    fn clone(&self) -> Self {
-        *self
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < 4 {
+            Some(self.extract_lane(index))
+        } else {
+            None
+        }
     }
-}
-
-impl Copy for Bx4 {}
 
-impl BitAnd for Bx4 {
-    type Output = Self;
-
-    fn bitand(self, other: Self) -> Self::Output {
-        Self(v128_and(self.0, other.0))
+    pub fn try_set_lane(&mut self, index: usize, value: bool) -> Result<(), LaneIndexError> {
+        if index < 4 {
+            self.set_lane(index, value);
+            Ok(())
+        } else {
+            Err(LaneIndexError { index, lanes: 4 })
+        }
     }
-}
 
-impl BitAndAssign for Bx4 {
-    fn bitand_assign(&mut self, other: Self) {
-        self.0 = v128_and(self.0, other.0);
+    pub fn to_bitmask(self) -> u8 {
+        i32x4_bitmask(self.0)
     }
-}
 
-impl BitAnd<bool> for Bx4 {
-    type Output = Self;
-
-    fn bitand(self, other: bool) -> Self::Output {
-        let other_val = if other { BIT_MASK_32 } else { 0 };
-
-        Self(v128_and(
-            self.0,
-            i32x4(other_val, other_val, other_val, other_val),
-        ))
+    /// Returns the mask as integers, with -1 in set lanes and 0 elsewhere.
+    pub fn to_i32x4(self) -> I32x4 {
+        I32x4::from_v128(self.0)
     }
-}
 
-impl BitAnd<Bx4> for bool {
-    type Output = Bx4;
+    /// Returns the mask as integers, with 1 in set lanes and 0 elsewhere.
+    pub fn to_u32x4_ones(self) -> U32x4 {
+        U32x4::from_v128(u32x4_shr(self.0, 31))
+    }
 
-    fn bitand(self, other: Bx4) -> Self::Output {
-        let self_val = if self { BIT_MASK_32 } else { 0 };
+    /// Builds a mask from the low four bits of `bitmask`, bit `i` setting lane `i`.
+    /// This is the inverse of `to_bitmask`; the upper four bits are ignored.
+    pub fn from_bitmask(bitmask: u8) -> Self {
+        let bits = v128_and(i32x4_splat(bitmask as i32), i32x4(1, 2, 4, 8));
+        Self(i32x4_ne(bits, i32x4_splat(0)))
+    }
 
-        Bx4(v128_and(
-            i32x4(self_val, self_val, self_val, self_val),
-            other.0,
-        ))
+    pub fn any(self) -> bool {
+        v128_any_true(self.0)
     }
-}
 
-impl BitAndAssign<bool> for Bx4 {
-    fn bitand_assign(&mut self, other: bool) {
-        let other_splat = Bx4::splat(other);
-        self.0 = v128_and(self.0, other_splat.0);
+    pub fn all(self) -> bool {
+        i32x4_all_true(self.0)
     }
-}
 
-impl BitOr for Bx4 {
-    type Output = Self;
+    pub fn none(self) -> bool {
+        !self.any()
+    }
 
-    fn bitor(self, other: Self) -> Self::Output {
-        Self(v128_or(self.0, other.0))
+    pub fn count_true(self) -> usize {
+        self.to_bitmask().count_ones() as usize
     }
-}
 
-impl BitOrAssign for Bx4 {
-    fn bitor_assign(&mut self, other: Self) {
-        self.0 = v128_or(self.0, other.0);
+    /// Returns the index of the lowest set lane, or `None` if no lane is set.
+    pub fn first_true(self) -> Option<usize> {
+        let bitmask = self.to_bitmask();
+        if bitmask == 0 {
+            None
+        } else {
+            Some(bitmask.trailing_zeros() as usize)
+        }
     }
-}
 
-impl BitOr<bool> for Bx4 {
-    type Output = Self;
+    /// Returns the index of the highest set lane, or `None` if no lane is set.
+    pub fn last_true(self) -> Option<usize> {
+        let bitmask = self.to_bitmask();
+        if bitmask == 0 {
+            None
+        } else {
+            Some(7 - bitmask.leading_zeros() as usize)
+        }
+    }
 
-    fn bitor(self, other: bool) -> Self::Output {
-        let other_val = if other { BIT_MASK_32 } else { 0 };
-        Self(v128_or(
-            self.0,
-            i32x4(other_val, other_val, other_val, other_val),
-        ))
+    /// Returns an iterator over the indices of the set lanes, in ascending order.
+    pub fn true_lanes(self) -> TrueLanes {
+        TrueLanes::new(self.to_bitmask().into())
     }
-}
 
-impl BitOr<Bx4> for bool {
-    type Output = Bx4;
+    impl_vec_shuffle!();
 
-    fn bitor(self, other: Bx4) -> Self::Output {
-        let self_val = if self { BIT_MASK_32 } else { 0 };
-        Bx4(v128_or(
-            i32x4(self_val, self_val, self_val, self_val),
-            other.0,
-        ))
+    pub fn to_ne_bytes(self) -> [u8; 16] {
+        // SAFETY: `v128` and `[u8; 16]` have the same size and every bit pattern is
+        // valid for both.
+        unsafe { core::mem::transmute::<v128, [u8; 16]>(self.0) }
     }
-}
 
-impl BitOrAssign<bool> for Bx4 {
-    fn bitor_assign(&mut self, other: bool) {
-        let other_splat = Bx4::splat(other);
-        self.0 = v128_or(self.0, other_splat.0);
+    pub(crate) fn from_v128(data: v128) -> Self {
+        Self(data)
     }
-}
 
-impl BitXor for Bx4 {
-    type Output = Self;
-
-    fn bitxor(self, other: Self) -> Self::Output {
-        Self(v128_xor(self.0, other.0))
+    pub(crate) fn to_v128(self) -> v128 {
+        self.0
     }
 }
 
-impl BitXorAssign for Bx4 {
-    fn bitxor_assign(&mut self, other: Self) {
-        self.0 = v128_xor(self.0, other.0);
+// Only the conversion out of the mask is provided, since an arbitrary `v128` may hold lanes
+// that are neither all ones nor all zeros.
+impl From<Bx4> for v128 {
+    fn from(val: Bx4) -> Self {
+        val.to_v128()
     }
 }
 
-impl BitXor<bool> for Bx4 {
-    type Output = Self;
+/// Iterator over the indices of the set lanes of a mask, created by `true_lanes`.
+#[derive(Clone, Copy, Debug)]
+pub struct TrueLanes {
+    bitmask: u32,
+}
 
-    fn bitxor(self, other: bool) -> Self::Output {
-        let other_val = if other { BIT_MASK_32 } else { 0 };
-        Self(v128_xor(
-            self.0,
-            i32x4(other_val, other_val, other_val, other_val),
-        ))
+impl TrueLanes {
+    pub(crate) fn new(bitmask: u32) -> Self {
+        Self { bitmask }
     }
 }
 
-impl BitXor<Bx4> for bool {
-    type Output = Bx4;
+impl Iterator for TrueLanes {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.bitmask == 0 {
+            return None;
+        }
 
-    fn bitxor(self, other: Bx4) -> Self::Output {
-        let self_val = if self { BIT_MASK_32 } else { 0 };
-        Bx4(v128_xor(
-            i32x4(self_val, self_val, self_val, self_val),
-            other.0,
-        ))
+        let index = self.bitmask.trailing_zeros() as usize;
+        self.bitmask &= self.bitmask - 1;
+        Some(index)
     }
-}
 
-impl BitXorAssign<bool> for Bx4 {
-    fn bitxor_assign(&mut self, other: bool) {
-        let other_splat = Bx4::splat(other);
-        self.0 = v128_xor(self.0, other_splat.0);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bitmask.count_ones() as usize;
+        (len, Some(len))
     }
 }
 
-impl Not for Bx4 {
-    type Output = Self;
+impl DoubleEndedIterator for TrueLanes {
+    fn next_back(&mut self) -> Option<usize> {
+        if self.bitmask == 0 {
+            return None;
+        }
 
-    fn not(self) -> Self::Output {
-        Self(v128_not(self.0))
+        let index = 31 - self.bitmask.leading_zeros() as usize;
+        self.bitmask &= !(1 << index);
+        Some(index)
     }
 }
 
+impl ExactSizeIterator for TrueLanes {}
+
 impl Debug for Bx4 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let (v1, v2, v3, v4) = self.extract_lanes();
 
         write!(f, "Bx4({}, {}, {}, {})", v1, v2, v3, v4)
     }
 }
+
+impl_vec_from_str!(Bx4, bool);
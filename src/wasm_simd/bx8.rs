@@ -0,0 +1,14 @@
+use crate::wasm_simd::bx4::Bx4;
+use crate::wasm_simd::i32x8::I32x8;
+use crate::wasm_simd::macros::define_wide_mask;
+use crate::wasm_simd::u32x8::U32x8;
+
+define_wide_mask! {
+    /// An 8-lane mask made of two `Bx4`, produced by comparing wide vectors.
+    Bx8 {
+        parts: 2,
+        lanes: 8,
+        bitmask: u8,
+        vectors: [I32x8 => to_i32x8, U32x8 => to_u32x8_ones],
+    }
+}
@@ -0,0 +1,28 @@
+// The lane types are `#[repr(transparent)]` over `v128`, so any 16 bytes form a valid value
+// and they can be cast freely. Slice casts such as `bytemuck::try_cast_slice` still check
+// that the input is 16-byte aligned and a multiple of 16 bytes long.
+use bytemuck::{CheckedBitPattern, NoUninit, Pod, Zeroable};
+
+use crate::wasm_simd::{Bx4, F32x4, I32x4, U32x4};
+
+unsafe impl Zeroable for I32x4 {}
+unsafe impl Pod for I32x4 {}
+
+unsafe impl Zeroable for U32x4 {}
+unsafe impl Pod for U32x4 {}
+
+unsafe impl Zeroable for F32x4 {}
+unsafe impl Pod for F32x4 {}
+
+// A mask lane must be either all ones or all zeros, so `Bx4` is not `Pod`. Reading one
+// from bytes goes through `bytemuck::checked`, which validates every lane.
+unsafe impl Zeroable for Bx4 {}
+unsafe impl NoUninit for Bx4 {}
+
+unsafe impl CheckedBitPattern for Bx4 {
+    type Bits = [i32; 4];
+
+    fn is_valid_bit_pattern(bits: &[i32; 4]) -> bool {
+        bits.iter().all(|&lane| lane == 0 || lane == -1)
+    }
+}
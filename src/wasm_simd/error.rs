@@ -0,0 +1,41 @@
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LaneIndexError {
+    pub index: usize,
+    /// The lane count of the vector.
+    pub lanes: usize,
+}
+
+impl fmt::Display for LaneIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "lane index {} is out of bounds for {} lanes",
+            self.index, self.lanes
+        )
+    }
+}
+
+impl core::error::Error for LaneIndexError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseVectorError {
+    /// The input did not hold as many lanes as the vector.
+    WrongLaneCount { expected: usize, found: usize },
+    /// The lane at this index is not a valid scalar.
+    InvalidLane(usize),
+}
+
+impl fmt::Display for ParseVectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLaneCount { expected, found } => {
+                write!(f, "expected {} lanes, found {}", expected, found)
+            }
+            Self::InvalidLane(lane) => write!(f, "invalid value for lane {}", lane),
+        }
+    }
+}
+
+impl core::error::Error for ParseVectorError {}
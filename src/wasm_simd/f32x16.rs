@@ -0,0 +1,16 @@
+use crate::wasm_simd::bx16::Bx16;
+use crate::wasm_simd::f32x4::F32x4;
+use crate::wasm_simd::i32x16::I32x16;
+use crate::wasm_simd::macros::define_wide_type;
+use crate::wasm_simd::u32x16::U32x16;
+
+define_wide_type! {
+    /// A 16-lane `f32` vector made of four `F32x4`, for kernels written for 512-bit vectors.
+    F32x16: f32 {
+        part: F32x4,
+        parts: 4,
+        lanes: 16,
+        mask: Bx16,
+        vectors: [I32x16, U32x16, F32x16],
+    }
+}
@@ -1,149 +1,137 @@
-use crate::wasm_simd::bx4::Bx4;
+use crate::impl_vec_unary_op;
+use crate::wasm_simd::arch::*;
 use crate::wasm_simd::i32x4::I32x4;
-use crate::{
-    impl_debug, impl_default, impl_vec_assign_op, impl_vec_binary_op, impl_vec_cmp,
-    impl_vec_overload_op, impl_vec_unary_op,
-};
-use core::arch::wasm32::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::wasm_simd::macros::define_simd_type;
+use crate::wasm_simd::relaxed;
+use crate::wasm_simd::u32x4::U32x4;
+
+// `PartialEq` compares lanes with IEEE 754 semantics, so any NaN lane makes two vectors
+// unequal (including a vector compared with itself) and `0.0` equals `-0.0`. For the same
+// reason there is no `Eq` or `Hash` implementation.
+define_simd_type! {
+    F32x4: f32 {
+        new: f32x4,
+        extract_lane: f32x4_extract_lane,
+        replace_lane: f32x4_replace_lane,
+        add: f32x4_add,
+        sub: f32x4_sub,
+        mul: f32x4_mul,
+        div: f32x4_div,
+        eq: f32x4_eq,
+        ne: f32x4_ne,
+        lt: f32x4_lt,
+        le: f32x4_le,
+        gt: f32x4_gt,
+        ge: f32x4_ge,
+        min: f32x4_min,
+        max: f32x4_max,
+        all_true: i32x4_all_true,
+        neg: f32x4_neg,
+    }
+}
 
-pub struct F32x4(v128);
+// Only needed by `num_traits::Float`; see `impl_vec_partial_ord!` for the lane-wise order.
+#[cfg(feature = "num-traits")]
+crate::impl_vec_partial_ord!(F32x4);
 
 impl F32x4 {
-    pub fn new(v1: f32, v2: f32, v3: f32, v4: f32) -> Self {
-        Self(f32x4(v1, v2, v3, v4))
-    }
+    pub const NAN: Self = Self::splat(f32::NAN);
+    pub const INFINITY: Self = Self::splat(f32::INFINITY);
+    pub const NEG_INFINITY: Self = Self::splat(f32::NEG_INFINITY);
 
-    pub fn splat(value: f32) -> Self {
-        Self(f32x4_splat(value))
+    /// Returns the raw IEEE 754 bits of each lane, like `f32::to_bits`.
+    pub fn to_bits(self) -> U32x4 {
+        U32x4::from_v128(self.0)
     }
 
-    pub fn new_from_fn<F>(f: F) -> Self
-    where
-        F: Fn(usize) -> f32,
-    {
-        Self(f32x4(f(0), f(1), f(2), f(3)))
+    /// Builds a vector from the raw IEEE 754 bits of each lane, like `f32::from_bits`.
+    pub fn from_bits(bits: U32x4) -> Self {
+        Self(bits.to_v128())
     }
 
-    pub(crate) fn from_v128(data: v128) -> Self {
-        Self(data)
+    /// Rounds each lane to the nearest integer (ties to even) and converts to `i32`.
+    /// Out-of-range lanes saturate to `i32::MIN`/`i32::MAX` and NaN lanes become 0
+    /// (unspecified with `relaxed-simd-default`).
+    pub fn to_i32_round(self) -> I32x4 {
+        I32x4::from_v128(relaxed::trunc_i32(f32x4_nearest(self.0)))
     }
 
-    pub(crate) fn to_v128(self) -> v128 {
-        self.0
+    /// Rounds each lane toward negative infinity and converts to `i32`.
+    /// Out-of-range lanes saturate to `i32::MIN`/`i32::MAX` and NaN lanes become 0
+    /// (unspecified with `relaxed-simd-default`).
+    pub fn to_i32_floor(self) -> I32x4 {
+        I32x4::from_v128(relaxed::trunc_i32(f32x4_floor(self.0)))
     }
 
-    pub fn apply<F>(&self, f: F) -> Self
-    where
-        F: Fn(f32) -> f32,
-    {
-        let (v1, v2, v3, v4) = self.extract_lanes();
-        Self::new(f(v1), f(v2), f(v3), f(v4))
+    /// Rounds each lane toward positive infinity and converts to `i32`.
+    /// Out-of-range lanes saturate to `i32::MIN`/`i32::MAX` and NaN lanes become 0
+    /// (unspecified with `relaxed-simd-default`).
+    pub fn to_i32_ceil(self) -> I32x4 {
+        I32x4::from_v128(relaxed::trunc_i32(f32x4_ceil(self.0)))
     }
 
-    pub fn extract_lanes(&self) -> (f32, f32, f32, f32) {
-        (
-            f32x4_extract_lane::<0>(self.0),
-            f32x4_extract_lane::<1>(self.0),
-            f32x4_extract_lane::<2>(self.0),
-            f32x4_extract_lane::<3>(self.0),
-        )
+    /// Rounds each lane toward zero and converts to `i32`, the same as `I32x4::from`.
+    /// Out-of-range lanes saturate to `i32::MIN`/`i32::MAX` and NaN lanes become 0
+    /// (unspecified with `relaxed-simd-default`).
+    pub fn to_i32_trunc(self) -> I32x4 {
+        I32x4::from_v128(relaxed::trunc_i32(self.0))
     }
 
-    pub fn extract_lane(&self, index: usize) -> f32 {
-        match index {
-            0 => f32x4_extract_lane::<0>(self.0),
-            1 => f32x4_extract_lane::<1>(self.0),
-            2 => f32x4_extract_lane::<2>(self.0),
-            3 => f32x4_extract_lane::<3>(self.0),
-            _ => panic!("Index out of bounds"),
-        }
+    /// Rounds each lane to the nearest integer (ties to even) and converts to `u32`.
+    /// Negative lanes saturate to 0, lanes above `u32::MAX` saturate to `u32::MAX`
+    /// and NaN lanes become 0 (unspecified with `relaxed-simd-default`).
+    pub fn to_u32_round(self) -> U32x4 {
+        U32x4::from_v128(relaxed::trunc_u32(f32x4_nearest(self.0)))
     }
 
-    pub fn set_lane(&mut self, index: usize, value: f32) {
-        let new_vec = match index {
-            0 => f32x4_replace_lane::<0>(self.0, value),
-            1 => f32x4_replace_lane::<1>(self.0, value),
-            2 => f32x4_replace_lane::<2>(self.0, value),
-            3 => f32x4_replace_lane::<3>(self.0, value),
-            _ => panic!("Index out of bounds"),
-        };
-
-        self.0 = new_vec;
+    /// Rounds each lane toward negative infinity and converts to `u32`.
+    /// Negative lanes saturate to 0, lanes above `u32::MAX` saturate to `u32::MAX`
+    /// and NaN lanes become 0 (unspecified with `relaxed-simd-default`).
+    pub fn to_u32_floor(self) -> U32x4 {
+        U32x4::from_v128(relaxed::trunc_u32(f32x4_floor(self.0)))
     }
 
-    pub fn if_else(self, other: &Self, mask: Bx4) -> Self {
-        let data = v128_bitselect(self.0, other.0, mask.to_v128());
-        Self(data)
+    /// Rounds each lane toward positive infinity and converts to `u32`.
+    /// Negative lanes saturate to 0, lanes above `u32::MAX` saturate to `u32::MAX`
+    /// and NaN lanes become 0 (unspecified with `relaxed-simd-default`).
+    pub fn to_u32_ceil(self) -> U32x4 {
+        U32x4::from_v128(relaxed::trunc_u32(f32x4_ceil(self.0)))
     }
 
-    impl_vec_cmp!(eq, s_eq, f32x4_eq, Bx4);
-    impl_vec_cmp!(ne, s_ne, f32x4_ne, Bx4);
-
-    impl_vec_cmp!(lt, s_lt, f32x4_lt, Bx4);
-    impl_vec_cmp!(le, s_le, f32x4_le, Bx4);
-    impl_vec_cmp!(gt, s_gt, f32x4_gt, Bx4);
-    impl_vec_cmp!(ge, s_ge, f32x4_ge, Bx4);
-
-    impl_vec_binary_op!(min, s_min, f32x4_min, f32);
-    impl_vec_binary_op!(max, s_max, f32x4_max, f32);
+    /// Rounds each lane toward zero and converts to `u32`, the same as `U32x4::from`.
+    /// Negative lanes saturate to 0, lanes above `u32::MAX` saturate to `u32::MAX`
+    /// and NaN lanes become 0 (unspecified with `relaxed-simd-default`).
+    pub fn to_u32_trunc(self) -> U32x4 {
+        U32x4::from_v128(relaxed::trunc_u32(self.0))
+    }
 
     impl_vec_unary_op!(abs, f32x4_abs);
     impl_vec_unary_op!(ceil, f32x4_ceil);
     impl_vec_unary_op!(floor, f32x4_floor);
-}
 
-impl_default!(F32x4, f32);
+    #[cfg(any(feature = "geometry", feature = "num-traits"))]
+    #[inline]
+    pub(crate) fn sqrt(self) -> Self {
+        Self(f32x4_sqrt(self.0))
+    }
 
-impl Clone for F32x4 {
-    fn clone(&self) -> Self {
-        *self
+    /// Computes `self * a + b` lane-wise. WebAssembly SIMD has no fused multiply-add, so the
+    /// product is rounded before the addition. With `relaxed-simd-default` this uses
+    /// `relaxed_mul_add`, which may fuse.
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        Self(relaxed::mul_add(self.0, a.0, b.0))
     }
 }
 
-impl Copy for F32x4 {}
-
 impl From<I32x4> for F32x4 {
     fn from(value: I32x4) -> Self {
         Self(f32x4_convert_i32x4(value.to_v128()))
     }
 }
 
-impl From<[f32; 4]> for F32x4 {
-    fn from(arr: [f32; 4]) -> Self {
-        let [v1, v2, v3, v4] = arr;
-        Self::new(v1, v2, v3, v4)
+impl From<U32x4> for F32x4 {
+    fn from(value: U32x4) -> Self {
+        Self(f32x4_convert_u32x4(value.to_v128()))
     }
 }
-impl From<F32x4> for [f32; 4] {
-    fn from(val: F32x4) -> Self {
-        let (v1, v2, v3, v4) = val.extract_lanes();
-        [v1, v2, v3, v4]
-    }
-}
-
-impl From<F32x4> for Vec<f32> {
-    fn from(val: F32x4) -> Self {
-        let (v1, v2, v3, v4) = val.extract_lanes();
-        vec![v1, v2, v3, v4]
-    }
-}
-
-impl_debug!(F32x4, (v1, v2, v3, v4));
-
-impl Neg for F32x4 {
-    type Output = Self;
-    fn neg(self) -> Self::Output {
-        Self(i32x4_neg(self.0))
-    }
-}
-
-impl_vec_overload_op!(F32x4, f32, Add, add, f32x4_add);
-impl_vec_overload_op!(F32x4, f32, Sub, sub, f32x4_sub);
-impl_vec_overload_op!(F32x4, f32, Mul, mul, f32x4_mul);
-impl_vec_overload_op!(F32x4, f32, Div, div, f32x4_div);
-
-impl_vec_assign_op!(F32x4, f32, AddAssign, add_assign, +);
-impl_vec_assign_op!(F32x4, f32, SubAssign, sub_assign, -);
-impl_vec_assign_op!(F32x4, f32, MulAssign, mul_assign, *);
-impl_vec_assign_op!(F32x4, f32, DivAssign, div_assign, /);
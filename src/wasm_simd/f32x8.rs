@@ -0,0 +1,16 @@
+use crate::wasm_simd::bx8::Bx8;
+use crate::wasm_simd::f32x4::F32x4;
+use crate::wasm_simd::i32x8::I32x8;
+use crate::wasm_simd::macros::define_wide_type;
+use crate::wasm_simd::u32x8::U32x8;
+
+define_wide_type! {
+    /// An 8-lane `f32` vector made of two `F32x4`, for kernels written for 256-bit vectors.
+    F32x8: f32 {
+        part: F32x4,
+        parts: 2,
+        lanes: 8,
+        mask: Bx8,
+        vectors: [I32x8, U32x8, F32x8],
+    }
+}
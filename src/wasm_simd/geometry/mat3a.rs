@@ -0,0 +1,178 @@
+use crate::wasm_simd::f32x4::F32x4;
+use crate::wasm_simd::geometry::{transpose4, Mat4, Vec3A};
+use core::ops::{Mul, MulAssign};
+
+/// A column-major 3×3 matrix whose columns are `Vec3A`s, so lane 3 of each column is
+/// padding.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat3A(pub [Vec3A; 3]);
+
+impl Mat3A {
+    pub const ZERO: Self = Self([Vec3A::ZERO; 3]);
+    pub const IDENTITY: Self = Self([Vec3A::X, Vec3A::Y, Vec3A::Z]);
+
+    pub const fn from_cols(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A) -> Self {
+        Self([x_axis, y_axis, z_axis])
+    }
+
+    /// Builds a matrix from its components, one column after the other.
+    pub fn from_cols_array(m: [f32; 9]) -> Self {
+        Self::from_cols(
+            Vec3A::new(m[0], m[1], m[2]),
+            Vec3A::new(m[3], m[4], m[5]),
+            Vec3A::new(m[6], m[7], m[8]),
+        )
+    }
+
+    /// Returns the components, one column after the other.
+    pub fn to_cols_array(&self) -> [f32; 9] {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.0.map(Vec3A::to_array);
+        [a, b, c, d, e, f, g, h, i]
+    }
+
+    /// Returns column `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is 3 or more.
+    pub fn col(&self, index: usize) -> Vec3A {
+        self.0[index]
+    }
+
+    /// Returns the upper-left 3×3 part of `m`.
+    pub fn from_mat4(m: Mat4) -> Self {
+        let [x, y, z, _] = m.0;
+        Self::from_cols(x.truncate(), y.truncate(), z.truncate())
+    }
+
+    pub fn from_scale(scale: Vec3A) -> Self {
+        Self::from_cols(
+            Vec3A::new(scale.x(), 0.0, 0.0),
+            Vec3A::new(0.0, scale.y(), 0.0),
+            Vec3A::new(0.0, 0.0, scale.z()),
+        )
+    }
+
+    /// Rotates by `angle` radians around the x axis, counter-clockwise looking towards the
+    /// origin.
+    pub fn from_rotation_x(angle: f32) -> Self {
+        let (sin, cos) = libm::sincosf(angle);
+        Self::from_cols(
+            Vec3A::X,
+            Vec3A::new(0.0, cos, sin),
+            Vec3A::new(0.0, -sin, cos),
+        )
+    }
+
+    /// Rotates by `angle` radians around the y axis, counter-clockwise looking towards the
+    /// origin.
+    pub fn from_rotation_y(angle: f32) -> Self {
+        let (sin, cos) = libm::sincosf(angle);
+        Self::from_cols(
+            Vec3A::new(cos, 0.0, -sin),
+            Vec3A::Y,
+            Vec3A::new(sin, 0.0, cos),
+        )
+    }
+
+    /// Rotates by `angle` radians around the z axis, counter-clockwise looking towards the
+    /// origin.
+    pub fn from_rotation_z(angle: f32) -> Self {
+        let (sin, cos) = libm::sincosf(angle);
+        Self::from_cols(
+            Vec3A::new(cos, sin, 0.0),
+            Vec3A::new(-sin, cos, 0.0),
+            Vec3A::Z,
+        )
+    }
+
+    /// Rotates by `angle` radians around the unit vector `axis`.
+    pub fn from_axis_angle(axis: Vec3A, angle: f32) -> Self {
+        let (sin, cos) = libm::sincosf(angle);
+        let [x, y, z] = axis.to_array();
+        // Column `i` is `axis * (1 - cos) * axis[i]` plus the cosine on the diagonal and the
+        // sine terms of the cross product matrix.
+        let scaled = axis * (1.0 - cos);
+        Self::from_cols(
+            scaled * x + Vec3A::new(cos, sin * z, -sin * y),
+            scaled * y + Vec3A::new(-sin * z, cos, sin * x),
+            scaled * z + Vec3A::new(sin * y, -sin * x, cos),
+        )
+    }
+
+    #[inline]
+    pub fn mul_vec3a(&self, v: Vec3A) -> Vec3A {
+        let [x, y, z] = self.0.map(|col| col.0);
+        let v = v.0;
+        let result = x * v.broadcast_lane::<0>();
+        let result = y.mul_add(v.broadcast_lane::<1>(), result);
+        Vec3A::with_padding_cleared(z.mul_add(v.broadcast_lane::<2>(), result))
+    }
+
+    #[inline]
+    pub fn mul_mat3(&self, rhs: &Self) -> Self {
+        Self(rhs.0.map(|col| self.mul_vec3a(col)))
+    }
+
+    /// Swaps rows and columns with lane shuffles.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        let [x, y, z] = self.0.map(|col| col.0);
+        let [x, y, z, _] = transpose4([x, y, z, F32x4::ZERO]);
+        Self([Vec3A(x), Vec3A(y), Vec3A(z)])
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        let [x, y, z] = self.0;
+        x.dot(y.cross(z))
+    }
+
+    /// Returns the inverse matrix. Its components are not finite if the matrix is not
+    /// invertible.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let [x, y, z] = self.0;
+        // The rows of the inverse are the cross products of pairs of columns, over the
+        // determinant.
+        let (yz, zx, xy) = (y.cross(z), z.cross(x), x.cross(y));
+        let det_recip = F32x4::ONE / x.dot_splat(yz);
+        Self([
+            Vec3A(yz.0 * det_recip),
+            Vec3A(zx.0 * det_recip),
+            Vec3A(xy.0 * det_recip),
+        ])
+        .transpose()
+    }
+}
+
+impl Default for Mat3A {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Mat3A {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_mat3(&rhs)
+    }
+}
+
+impl MulAssign for Mat3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_mat3(&rhs);
+    }
+}
+
+impl Mul<Vec3A> for Mat3A {
+    type Output = Vec3A;
+
+    #[inline]
+    fn mul(self, rhs: Vec3A) -> Vec3A {
+        self.mul_vec3a(rhs)
+    }
+}
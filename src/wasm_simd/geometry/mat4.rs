@@ -0,0 +1,224 @@
+use crate::wasm_simd::geometry::{transpose4, Mat3A, Vec3A, Vec4};
+use core::ops::{Mul, MulAssign};
+
+/// A column-major 4×4 matrix whose columns are `Vec4`s.
+///
+/// The projection and view constructors follow the OpenGL and WebGL conventions: a
+/// right-handed view space looking down `-z`, and clip space `z` from -1 to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4(pub [Vec4; 4]);
+
+impl Mat4 {
+    pub const ZERO: Self = Self([Vec4::ZERO; 4]);
+    pub const IDENTITY: Self = Self([Vec4::X, Vec4::Y, Vec4::Z, Vec4::W]);
+
+    pub const fn from_cols(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Self {
+        Self([x_axis, y_axis, z_axis, w_axis])
+    }
+
+    /// Builds a matrix from its components, one column after the other, which is the layout
+    /// WebGL's `uniformMatrix4fv` expects.
+    pub fn from_cols_array(m: [f32; 16]) -> Self {
+        Self(core::array::from_fn(|i| {
+            Vec4::new(m[4 * i], m[4 * i + 1], m[4 * i + 2], m[4 * i + 3])
+        }))
+    }
+
+    /// Returns the components, one column after the other.
+    pub fn to_cols_array(&self) -> [f32; 16] {
+        let cols = self.0.map(Vec4::to_array);
+        core::array::from_fn(|i| cols[i / 4][i % 4])
+    }
+
+    /// Returns column `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is 4 or more.
+    pub fn col(&self, index: usize) -> Vec4 {
+        self.0[index]
+    }
+
+    /// Returns `m` extended with a last row and column of the identity matrix.
+    pub fn from_mat3a(m: Mat3A) -> Self {
+        let [x, y, z] = m.0;
+        Self::from_cols(x.extend(0.0), y.extend(0.0), z.extend(0.0), Vec4::W)
+    }
+
+    pub fn from_translation(translation: Vec3A) -> Self {
+        Self::from_cols(Vec4::X, Vec4::Y, Vec4::Z, translation.extend(1.0))
+    }
+
+    pub fn from_scale(scale: Vec3A) -> Self {
+        Self::from_mat3a(Mat3A::from_scale(scale))
+    }
+
+    /// Rotates by `angle` radians around the x axis, counter-clockwise looking towards the
+    /// origin.
+    pub fn from_rotation_x(angle: f32) -> Self {
+        Self::from_mat3a(Mat3A::from_rotation_x(angle))
+    }
+
+    /// Rotates by `angle` radians around the y axis, counter-clockwise looking towards the
+    /// origin.
+    pub fn from_rotation_y(angle: f32) -> Self {
+        Self::from_mat3a(Mat3A::from_rotation_y(angle))
+    }
+
+    /// Rotates by `angle` radians around the z axis, counter-clockwise looking towards the
+    /// origin.
+    pub fn from_rotation_z(angle: f32) -> Self {
+        Self::from_mat3a(Mat3A::from_rotation_z(angle))
+    }
+
+    /// Rotates by `angle` radians around the unit vector `axis`.
+    pub fn from_axis_angle(axis: Vec3A, angle: f32) -> Self {
+        Self::from_mat3a(Mat3A::from_axis_angle(axis, angle))
+    }
+
+    /// A perspective projection with a vertical field of view of `fov_y` radians, the given
+    /// width to height ratio and distances to the near and far planes.
+    pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / libm::tanf(0.5 * fov_y);
+        let depth_recip = 1.0 / (near - far);
+        Self::from_cols(
+            Vec4::new(f / aspect, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, f, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, (far + near) * depth_recip, -1.0),
+            Vec4::new(0.0, 0.0, 2.0 * far * near * depth_recip, 0.0),
+        )
+    }
+
+    /// An orthographic projection of the box between the given planes, with `near` and `far`
+    /// measured along `-z`.
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+        Self::from_cols(
+            Vec4::new(2.0 / width, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 2.0 / height, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, -2.0 / depth, 0.0),
+            Vec4::new(
+                -(right + left) / width,
+                -(top + bottom) / height,
+                -(far + near) / depth,
+                1.0,
+            ),
+        )
+    }
+
+    /// A view matrix for a camera at `eye` looking at `target`, with `up` pointing up.
+    pub fn look_at(eye: Vec3A, target: Vec3A, up: Vec3A) -> Self {
+        let forward = (target - eye).normalize();
+        let side = forward.cross(up).normalize();
+        let up = side.cross(forward);
+        // The rows are the camera axes, with the translation moving `eye` to the origin.
+        Self::from_cols(
+            side.extend(-side.dot(eye)),
+            up.extend(-up.dot(eye)),
+            (-forward).extend(forward.dot(eye)),
+            Vec4::W,
+        )
+        .transpose()
+    }
+
+    #[inline]
+    pub fn mul_vec4(&self, v: Vec4) -> Vec4 {
+        let [x, y, z, w] = self.0.map(|col| col.0);
+        let v = v.0;
+        let result = x * v.broadcast_lane::<0>();
+        let result = y.mul_add(v.broadcast_lane::<1>(), result);
+        let result = z.mul_add(v.broadcast_lane::<2>(), result);
+        Vec4(w.mul_add(v.broadcast_lane::<3>(), result))
+    }
+
+    #[inline]
+    pub fn mul_mat4(&self, rhs: &Self) -> Self {
+        Self(rhs.0.map(|col| self.mul_vec4(col)))
+    }
+
+    /// Transforms `point` as a position, applying the translation, without dividing by `w`.
+    #[inline]
+    pub fn transform_point3(&self, point: Vec3A) -> Vec3A {
+        self.mul_vec4(point.extend(1.0)).truncate()
+    }
+
+    /// Transforms `vector` as a direction, ignoring the translation.
+    #[inline]
+    pub fn transform_vector3(&self, vector: Vec3A) -> Vec3A {
+        self.mul_vec4(vector.extend(0.0)).truncate()
+    }
+
+    /// Swaps rows and columns with lane shuffles.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Self(transpose4(self.0.map(|col| col.0)).map(Vec4))
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        let (s, t, u, v) = self.cofactor_parts();
+        s.dot(v) + t.dot(u)
+    }
+
+    /// Returns the inverse matrix. Its components are not finite if the matrix is not
+    /// invertible.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let [a, b, c, d] = self.0.map(Vec4::truncate);
+        let [x, y, z, w] = self.0.map(Vec4::w);
+        let (s, t, u, v) = self.cofactor_parts();
+
+        let det_recip = 1.0 / (s.dot(v) + t.dot(u));
+        let (s, t, u, v) = (s * det_recip, t * det_recip, u * det_recip, v * det_recip);
+
+        // The rows of the inverse, from which the columns are one transpose away.
+        Self::from_cols(
+            (b.cross(v) + t * y).extend(-b.dot(t)),
+            (v.cross(a) - t * x).extend(a.dot(t)),
+            (d.cross(u) + s * w).extend(-d.dot(s)),
+            (u.cross(c) - s * z).extend(c.dot(s)),
+        )
+        .transpose()
+    }
+
+    // Writing the columns as `a` to `d` (their xyz parts) over a last row `x` to `w`, returns
+    // `a × b`, `c × d`, `a * y - b * x` and `c * w - d * z`, from which the determinant and
+    // inverse follow with a few dot and cross products.
+    #[inline]
+    fn cofactor_parts(&self) -> (Vec3A, Vec3A, Vec3A, Vec3A) {
+        let [a, b, c, d] = self.0.map(Vec4::truncate);
+        let [x, y, z, w] = self.0.map(Vec4::w);
+        (a.cross(b), c.cross(d), a * y - b * x, c * w - d * z)
+    }
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_mat4(&rhs)
+    }
+}
+
+impl MulAssign for Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_mat4(&rhs);
+    }
+}
+
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    #[inline]
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        self.mul_vec4(rhs)
+    }
+}
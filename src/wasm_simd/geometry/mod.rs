@@ -0,0 +1,35 @@
+//! 3D and 4D vector and matrix types for geometry, built on `F32x4`.
+//!
+//! Horizontal operations such as `dot` sum the lanes with shuffles, so chains like
+//! `v.normalize()` stay in one register instead of extracting the components.
+//!
+//! Enabled by the `geometry` feature, which also pulls in `libm` for the trigonometry in the
+//! matrix constructors.
+
+pub mod mat3a;
+pub mod mat4;
+pub mod vec3a;
+pub mod vec4;
+
+pub use mat3a::Mat3A;
+pub use mat4::Mat4;
+pub use vec3a::Vec3A;
+pub use vec4::Vec4;
+
+use crate::wasm_simd::f32x4::F32x4;
+
+// Transposes the 4×4 matrix whose columns are `cols`: the first round of shuffles
+// interleaves pairs of columns, the second gathers each row from two of those pairs.
+#[inline]
+pub(crate) fn transpose4([a, b, c, d]: [F32x4; 4]) -> [F32x4; 4] {
+    let ab_low = a.shuffle::<0, 1, 4, 5>(&b);
+    let ab_high = a.shuffle::<2, 3, 6, 7>(&b);
+    let cd_low = c.shuffle::<0, 1, 4, 5>(&d);
+    let cd_high = c.shuffle::<2, 3, 6, 7>(&d);
+    [
+        ab_low.shuffle::<0, 2, 4, 6>(&cd_low),
+        ab_low.shuffle::<1, 3, 5, 7>(&cd_low),
+        ab_high.shuffle::<0, 2, 4, 6>(&cd_high),
+        ab_high.shuffle::<1, 3, 5, 7>(&cd_high),
+    ]
+}
@@ -0,0 +1,191 @@
+use crate::wasm_simd::f32x4::F32x4;
+use crate::wasm_simd::geometry::Vec4;
+use crate::{impl_geometry_ops, impl_geometry_swizzles};
+use core::fmt;
+
+/// A 3D vector of `f32` components stored in one `F32x4`, with `x` in lane 0. Lane 3 is
+/// padding: every operation ignores it, including comparisons, and every constructor, method
+/// and operator sets it to zero. Only `From<F32x4>` and the public field keep other values.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default)]
+pub struct Vec3A(pub F32x4);
+
+impl Vec3A {
+    pub const ZERO: Self = Self::splat(0.0);
+    pub const ONE: Self = Self::splat(1.0);
+    pub const X: Self = Self::new(1.0, 0.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0);
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(F32x4::new(x, y, z, 0.0))
+    }
+
+    pub const fn splat(value: f32) -> Self {
+        Self(F32x4::new(value, value, value, 0.0))
+    }
+
+    pub fn from_array([x, y, z]: [f32; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+
+    pub fn to_array(self) -> [f32; 3] {
+        let [x, y, z, _] = self.0.to_array();
+        [x, y, z]
+    }
+
+    pub fn x(self) -> f32 {
+        self.0.extract::<0>()
+    }
+
+    pub fn y(self) -> f32 {
+        self.0.extract::<1>()
+    }
+
+    pub fn z(self) -> f32 {
+        self.0.extract::<2>()
+    }
+
+    /// Returns a `Vec4` of these components and `w`.
+    pub fn extend(self, w: f32) -> Vec4 {
+        Vec4(self.0.with_lane::<3>(w))
+    }
+
+    /// Returns the dot product in lanes 0 to 2, without leaving the register.
+    #[inline]
+    pub fn dot_splat(self, rhs: Self) -> F32x4 {
+        let products = self.0 * rhs.0;
+        products
+            + products.shuffle::<1, 2, 0, 3>(&products)
+            + products.shuffle::<2, 0, 1, 3>(&products)
+    }
+
+    #[inline]
+    pub fn dot(self, rhs: Self) -> f32 {
+        self.dot_splat(rhs).extract::<0>()
+    }
+
+    #[inline]
+    pub fn cross(self, rhs: Self) -> Self {
+        // With `a.yzx` written for `a` rotated by one lane, the cross product is
+        // `a.yzx * b.zxy - a.zxy * b.yzx`, which is `(a * b.yzx - a.yzx * b).yzx`.
+        let (a, b) = (self.0, rhs.0);
+        let a_yzx = a.shuffle::<1, 2, 0, 3>(&a);
+        let b_yzx = b.shuffle::<1, 2, 0, 3>(&b);
+        let c = a * b_yzx - a_yzx * b;
+        Self(c.shuffle::<1, 2, 0, 3>(&c))
+    }
+
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.dot_splat(self).sqrt().extract::<0>()
+    }
+
+    /// Scales the vector to length 1. The result is not finite if the length is zero or not
+    /// finite; see `try_normalize`.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        Self::with_padding_cleared(self.0 / self.dot_splat(self).sqrt())
+    }
+
+    /// Scales the vector to length 1, or returns `None` if the length is zero or not finite.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let recip = F32x4::ONE / self.dot_splat(self).sqrt();
+        let r = recip.extract::<0>();
+        (r.is_finite() && r > 0.0).then(|| Self::with_padding_cleared(self.0 * recip))
+    }
+
+    /// Reflects the vector off a plane with the given unit `normal`.
+    #[inline]
+    pub fn reflect(self, normal: Self) -> Self {
+        let twice_dot = self.dot_splat(normal) * 2.0;
+        Self::with_padding_cleared(self.0 - normal.0 * twice_dot)
+    }
+
+    /// Returns the projection of the vector onto `onto`, which must not be zero.
+    #[inline]
+    pub fn project(self, onto: Self) -> Self {
+        Self::with_padding_cleared(onto.0 * (self.dot_splat(onto) / onto.dot_splat(onto)))
+    }
+
+    // Scalar operands and divisions write to lane 3 too (`v + 1.0`, `0.0 / 0.0`), so results
+    // put the padding back to zero.
+    #[inline]
+    pub(crate) fn with_padding_cleared(lanes: F32x4) -> Self {
+        Self(lanes.with_lane::<3>(0.0))
+    }
+
+    /// Interpolates linearly from `self` at `t == 0.0` to `rhs` at `t == 1.0`.
+    #[inline]
+    pub fn lerp(self, rhs: Self, t: f32) -> Self {
+        Self::with_padding_cleared((rhs.0 - self.0).mul_add(F32x4::splat(t), self.0))
+    }
+
+    /// Rearranges the components, taking result component `x` from component `X` and so on.
+    #[inline]
+    pub fn swizzle<const X: usize, const Y: usize, const Z: usize>(self) -> Self {
+        Self(self.0.shuffle::<X, Y, Z, 3>(&self.0))
+    }
+
+    impl_geometry_swizzles!(
+        xxx => [0, 0, 0], xxy => [0, 0, 1], xxz => [0, 0, 2],
+        xyx => [0, 1, 0], xyy => [0, 1, 1], xyz => [0, 1, 2],
+        xzx => [0, 2, 0], xzy => [0, 2, 1], xzz => [0, 2, 2],
+        yxx => [1, 0, 0], yxy => [1, 0, 1], yxz => [1, 0, 2],
+        yyx => [1, 1, 0], yyy => [1, 1, 1], yyz => [1, 1, 2],
+        yzx => [1, 2, 0], yzy => [1, 2, 1], yzz => [1, 2, 2],
+        zxx => [2, 0, 0], zxy => [2, 0, 1], zxz => [2, 0, 2],
+        zyx => [2, 1, 0], zyy => [2, 1, 1], zyz => [2, 1, 2],
+        zzx => [2, 2, 0], zzy => [2, 2, 1], zzz => [2, 2, 2],
+    );
+}
+
+impl_geometry_ops!(Vec3A, Vec3A::with_padding_cleared);
+
+// Compares `x`, `y` and `z` only, since lane 3 holds whatever the operations left there.
+impl PartialEq for Vec3A {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq(&other.0).to_bitmask() & 0b0111 == 0b0111
+    }
+}
+
+impl From<F32x4> for Vec3A {
+    fn from(value: F32x4) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Vec3A> for F32x4 {
+    fn from(value: Vec3A) -> Self {
+        value.0
+    }
+}
+
+impl From<[f32; 3]> for Vec3A {
+    fn from(components: [f32; 3]) -> Self {
+        Self::from_array(components)
+    }
+}
+
+impl From<Vec3A> for [f32; 3] {
+    fn from(value: Vec3A) -> Self {
+        value.to_array()
+    }
+}
+
+impl fmt::Debug for Vec3A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, y, z] = self.to_array();
+        f.debug_tuple("Vec3A")
+            .field(&x)
+            .field(&y)
+            .field(&z)
+            .finish()
+    }
+}
@@ -0,0 +1,174 @@
+use crate::wasm_simd::f32x4::F32x4;
+use crate::wasm_simd::geometry::Vec3A;
+use crate::{impl_geometry_ops, impl_geometry_swizzles};
+use core::fmt;
+
+/// A 4D vector of `f32` components stored in one `F32x4`, with `x` in lane 0.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Vec4(pub F32x4);
+
+impl Vec4 {
+    pub const ZERO: Self = Self::splat(0.0);
+    pub const ONE: Self = Self::splat(1.0);
+    pub const X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+    pub const W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self(F32x4::new(x, y, z, w))
+    }
+
+    pub const fn splat(value: f32) -> Self {
+        Self(F32x4::splat(value))
+    }
+
+    pub fn from_array(components: [f32; 4]) -> Self {
+        Self(F32x4::from_array(components))
+    }
+
+    pub fn to_array(self) -> [f32; 4] {
+        self.0.to_array()
+    }
+
+    pub fn x(self) -> f32 {
+        self.0.extract::<0>()
+    }
+
+    pub fn y(self) -> f32 {
+        self.0.extract::<1>()
+    }
+
+    pub fn z(self) -> f32 {
+        self.0.extract::<2>()
+    }
+
+    pub fn w(self) -> f32 {
+        self.0.extract::<3>()
+    }
+
+    /// Returns `x`, `y` and `z`, dropping `w`.
+    pub fn truncate(self) -> Vec3A {
+        Vec3A(self.0.with_lane::<3>(0.0))
+    }
+
+    /// Returns the dot product in every lane, without leaving the register.
+    #[inline]
+    pub fn dot_splat(self, rhs: Self) -> F32x4 {
+        let products = self.0 * rhs.0;
+        let pairs = products + products.shuffle::<1, 0, 3, 2>(&products);
+        pairs + pairs.shuffle::<2, 3, 0, 1>(&pairs)
+    }
+
+    #[inline]
+    pub fn dot(self, rhs: Self) -> f32 {
+        self.dot_splat(rhs).extract::<0>()
+    }
+
+    /// Returns the cross product of the `xyz` parts, with `w` set to 0.
+    #[inline]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self(self.truncate().cross(rhs.truncate()).0.with_lane::<3>(0.0))
+    }
+
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.dot_splat(self).sqrt().extract::<0>()
+    }
+
+    /// Scales the vector to length 1. The result is not finite if the length is zero or not
+    /// finite; see `try_normalize`.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        Self(self.0 / self.dot_splat(self).sqrt())
+    }
+
+    /// Scales the vector to length 1, or returns `None` if the length is zero or not finite.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let recip = F32x4::ONE / self.dot_splat(self).sqrt();
+        let r = recip.extract::<0>();
+        (r.is_finite() && r > 0.0).then(|| Self(self.0 * recip))
+    }
+
+    /// Reflects the vector off a plane with the given unit `normal`.
+    #[inline]
+    pub fn reflect(self, normal: Self) -> Self {
+        let twice_dot = self.dot_splat(normal) * 2.0;
+        Self(self.0 - normal.0 * twice_dot)
+    }
+
+    /// Returns the projection of the vector onto `onto`, which must not be zero.
+    #[inline]
+    pub fn project(self, onto: Self) -> Self {
+        Self(onto.0 * (self.dot_splat(onto) / onto.dot_splat(onto)))
+    }
+
+    /// Interpolates linearly from `self` at `t == 0.0` to `rhs` at `t == 1.0`.
+    #[inline]
+    pub fn lerp(self, rhs: Self, t: f32) -> Self {
+        Self((rhs.0 - self.0).mul_add(F32x4::splat(t), self.0))
+    }
+
+    /// Rearranges the components, taking result component `x` from component `X` and so on.
+    #[inline]
+    pub fn swizzle<const X: usize, const Y: usize, const Z: usize, const W: usize>(self) -> Self {
+        Self(self.0.shuffle::<X, Y, Z, W>(&self.0))
+    }
+
+    impl_geometry_swizzles!(
+        xxx => [0, 0, 0], xxy => [0, 0, 1], xxz => [0, 0, 2],
+        xyx => [0, 1, 0], xyy => [0, 1, 1], xyz => [0, 1, 2],
+        xzx => [0, 2, 0], xzy => [0, 2, 1], xzz => [0, 2, 2],
+        yxx => [1, 0, 0], yxy => [1, 0, 1], yxz => [1, 0, 2],
+        yyx => [1, 1, 0], yyy => [1, 1, 1], yyz => [1, 1, 2],
+        yzx => [1, 2, 0], yzy => [1, 2, 1], yzz => [1, 2, 2],
+        zxx => [2, 0, 0], zxy => [2, 0, 1], zxz => [2, 0, 2],
+        zyx => [2, 1, 0], zyy => [2, 1, 1], zyz => [2, 1, 2],
+        zzx => [2, 2, 0], zzy => [2, 2, 1], zzz => [2, 2, 2],
+    );
+}
+
+impl_geometry_ops!(Vec4, Vec4);
+
+impl From<F32x4> for Vec4 {
+    fn from(value: F32x4) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Vec4> for F32x4 {
+    fn from(value: Vec4) -> Self {
+        value.0
+    }
+}
+
+impl From<[f32; 4]> for Vec4 {
+    fn from(components: [f32; 4]) -> Self {
+        Self::from_array(components)
+    }
+}
+
+impl From<Vec4> for [f32; 4] {
+    fn from(value: Vec4) -> Self {
+        value.to_array()
+    }
+}
+
+impl fmt::Debug for Vec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, y, z, w] = self.to_array();
+        f.debug_tuple("Vec4")
+            .field(&x)
+            .field(&y)
+            .field(&z)
+            .field(&w)
+            .finish()
+    }
+}
@@ -0,0 +1,15 @@
+use crate::wasm_simd::bx16::Bx16;
+use crate::wasm_simd::f32x16::F32x16;
+use crate::wasm_simd::i32x4::I32x4;
+use crate::wasm_simd::macros::define_wide_type;
+
+define_wide_type! {
+    /// A 16-lane `i32` vector made of four `I32x4`, for kernels written for 512-bit vectors.
+    I32x16: i32 {
+        part: I32x4,
+        parts: 4,
+        lanes: 16,
+        mask: Bx16,
+        vectors: [I32x16, U32x16, F32x16],
+    }
+}
@@ -0,0 +1,15 @@
+use crate::wasm_simd::bx8::Bx8;
+use crate::wasm_simd::f32x8::F32x8;
+use crate::wasm_simd::i32x4::I32x4;
+use crate::wasm_simd::macros::define_wide_type;
+
+define_wide_type! {
+    /// An 8-lane `i32` vector made of two `I32x4`, for kernels written for 256-bit vectors.
+    I32x8: i32 {
+        part: I32x4,
+        parts: 2,
+        lanes: 8,
+        mask: Bx8,
+        vectors: [I32x8, U32x8, F32x8],
+    }
+}
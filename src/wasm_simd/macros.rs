@@ -3,7 +3,7 @@ macro_rules! impl_vec_binary_op {
     ($name:ident, $scalar_name:ident, $fn:ident, $type:ty) => {
         #[inline]
         pub fn $name(&self, other: &Self) -> Self {
-            Self(::core::arch::wasm32::$fn(self.0, other.0))
+            Self($crate::__private::arch::$fn(self.0, other.0))
         }
 
         #[inline]
@@ -18,72 +18,398 @@ macro_rules! impl_vec_unary_op {
     ($name:ident, $fn:ident) => {
         #[inline]
         pub fn $name(self) -> Self {
-            Self(::core::arch::wasm32::$fn(self.0))
+            Self($crate::__private::arch::$fn(self.0))
         }
     };
 }
 
 #[macro_export]
-macro_rules! impl_vec_overload_op {
-    ($vec_type:ty, $scalar_type:ty, $trait:ident, $fn:ident, $op_fn:ident) => {
-        impl $trait for $vec_type {
+macro_rules! impl_vec_lane_access {
+    ($type:ty, $extract_fn:ident, $replace_fn:ident) => {
+        #[inline]
+        pub fn extract<const N: usize>(&self) -> $type {
+            const { assert!(N < 4, "Index out of bounds") };
+            $crate::__private::arch::$extract_fn::<N>(self.0)
+        }
+
+        #[inline]
+        pub fn with_lane<const N: usize>(&self, value: $type) -> Self {
+            const { assert!(N < 4, "Index out of bounds") };
+            Self($crate::__private::arch::$replace_fn::<N>(self.0, value))
+        }
+
+        #[inline]
+        pub fn set<const N: usize>(&mut self, value: $type) {
+            *self = self.with_lane::<N>(value);
+        }
+
+        pub fn get(&self, index: usize) -> Option<$type> {
+            if index < 4 {
+                Some(self.extract_lane(index))
+            } else {
+                None
+            }
+        }
+
+        pub fn try_set_lane(
+            &mut self,
+            index: usize,
+            value: $type,
+        ) -> Result<(), $crate::wasm_simd::LaneIndexError> {
+            if index < 4 {
+                self.set_lane(index, value);
+                Ok(())
+            } else {
+                Err($crate::wasm_simd::LaneIndexError { index, lanes: 4 })
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_vec_bitcast {
+    () => {
+        /// Reinterprets the 128 bits of `self` as another vector type without any
+        /// conversion of the lane values.
+        #[inline]
+        pub fn bitcast<T: From<$crate::__private::arch::v128>>(self) -> T {
+            T::from(self.0)
+        }
+
+        #[inline]
+        pub fn to_ne_bytes(self) -> [u8; 16] {
+            // SAFETY: `v128` and `[u8; 16]` have the same size and every bit pattern is
+            // valid for both.
+            unsafe { ::core::mem::transmute::<$crate::__private::arch::v128, [u8; 16]>(self.0) }
+        }
+
+        #[inline]
+        pub fn to_le_bytes(self) -> [u8; 16] {
+            let mut bytes = self.to_ne_bytes();
+            if cfg!(target_endian = "big") {
+                bytes.chunks_exact_mut(4).for_each(|lane| lane.reverse());
+            }
+            bytes
+        }
+
+        #[inline]
+        pub fn from_ne_bytes(bytes: [u8; 16]) -> Self {
+            // SAFETY: `v128` and `[u8; 16]` have the same size and every bit pattern is
+            // valid for both.
+            Self(unsafe {
+                ::core::mem::transmute::<[u8; 16], $crate::__private::arch::v128>(bytes)
+            })
+        }
+
+        #[inline]
+        pub fn from_le_bytes(mut bytes: [u8; 16]) -> Self {
+            if cfg!(target_endian = "big") {
+                bytes.chunks_exact_mut(4).for_each(|lane| lane.reverse());
+            }
+            Self::from_ne_bytes(bytes)
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_v128_conversions {
+    ($struct_name:ident) => {
+        impl From<$crate::__private::arch::v128> for $struct_name {
+            #[inline]
+            fn from(data: $crate::__private::arch::v128) -> Self {
+                Self::from_v128(data)
+            }
+        }
+
+        impl From<$struct_name> for $crate::__private::arch::v128 {
+            #[inline]
+            fn from(val: $struct_name) -> Self {
+                val.to_v128()
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_vec_masked_op {
+    ($name:ident, $fn:ident) => {
+        #[inline]
+        pub fn $name(&self, mask: &$crate::wasm_simd::Bx4, other: &Self) -> Self {
+            let data = $crate::__private::arch::$fn(self.0, other.0);
+            Self($crate::__private::arch::v128_bitselect(
+                data,
+                self.0,
+                mask.to_v128(),
+            ))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_vec_select {
+    () => {
+        #[inline]
+        pub fn select(mask: &$crate::wasm_simd::Bx4, if_true: &Self, if_false: &Self) -> Self {
+            Self($crate::wasm_simd::relaxed::lane_select(
+                if_true.0,
+                if_false.0,
+                mask.to_v128(),
+            ))
+        }
+
+        #[inline]
+        pub fn masked_assign(&mut self, mask: &$crate::wasm_simd::Bx4, value: &Self) {
+            self.0 = $crate::wasm_simd::relaxed::lane_select(value.0, self.0, mask.to_v128());
+        }
+    };
+}
+
+// Relaxed SIMD forms of `select` and `swizzle`, see the `relaxed` module for their semantics.
+#[macro_export]
+macro_rules! impl_vec_relaxed {
+    () => {
+        /// Like `select`, using the relaxed lane select instruction. Deterministic, since
+        /// every `Bx4` lane is either all ones or all zeros.
+        #[cfg(feature = "relaxed-simd")]
+        #[inline]
+        pub fn relaxed_select(
+            mask: &$crate::wasm_simd::Bx4,
+            if_true: &Self,
+            if_false: &Self,
+        ) -> Self {
+            Self($crate::__private::arch::i32x4_relaxed_laneselect(
+                if_true.0,
+                if_false.0,
+                mask.to_v128(),
+            ))
+        }
+
+        /// Like `swizzle`, but lanes whose index is out of range hold an unspecified value
+        /// instead of 0.
+        #[cfg(feature = "relaxed-simd")]
+        #[inline]
+        pub fn relaxed_swizzle(&self, indices: &$crate::wasm_simd::I32x4) -> Self {
+            use $crate::__private::arch::*;
+
+            let lane_bytes = i32x4_mul(i32x4_shl(indices.0, 2), i32x4_splat(0x0101_0101));
+            let byte_indices = i32x4_add(lane_bytes, i32x4_splat(0x0302_0100));
+            Self(i8x16_relaxed_swizzle(self.0, byte_indices))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_vec_shuffle {
+    () => {
+        #[inline]
+        pub fn shuffle<const I0: usize, const I1: usize, const I2: usize, const I3: usize>(
+            &self,
+            other: &Self,
+        ) -> Self {
+            Self($crate::__private::arch::i32x4_shuffle::<I0, I1, I2, I3>(
+                self.0, other.0,
+            ))
+        }
+
+        #[inline]
+        pub fn reverse(&self) -> Self {
+            self.shuffle::<3, 2, 1, 0>(self)
+        }
+
+        /// Moves every lane `N` places towards lane 0, wrapping around.
+        #[inline]
+        pub fn rotate_lanes_left<const N: usize>(&self) -> Self {
+            match N % 4 {
+                0 => *self,
+                1 => self.shuffle::<1, 2, 3, 0>(self),
+                2 => self.shuffle::<2, 3, 0, 1>(self),
+                _ => self.shuffle::<3, 0, 1, 2>(self),
+            }
+        }
+
+        /// Moves every lane `N` places towards lane 3, wrapping around.
+        #[inline]
+        pub fn rotate_lanes_right<const N: usize>(&self) -> Self {
+            match N % 4 {
+                0 => *self,
+                1 => self.shuffle::<3, 0, 1, 2>(self),
+                2 => self.shuffle::<2, 3, 0, 1>(self),
+                _ => self.shuffle::<1, 2, 3, 0>(self),
+            }
+        }
+
+        #[inline]
+        pub fn broadcast_lane<const N: usize>(&self) -> Self {
+            const { assert!(N < 4, "Index out of bounds") };
+            self.shuffle::<N, N, N, N>(self)
+        }
+
+        /// Returns `(self[0], other[0], self[1], other[1])`.
+        #[inline]
+        pub fn interleave_low(&self, other: &Self) -> Self {
+            self.shuffle::<0, 4, 1, 5>(other)
+        }
+
+        /// Returns `(self[2], other[2], self[3], other[3])`.
+        #[inline]
+        pub fn interleave_high(&self, other: &Self) -> Self {
+            self.shuffle::<2, 6, 3, 7>(other)
+        }
+
+        /// Returns the low half of `self` followed by the high half of `other`.
+        #[inline]
+        pub fn concat_low_high(&self, other: &Self) -> Self {
+            self.shuffle::<0, 1, 6, 7>(other)
+        }
+
+        /// Picks lane `indices[i]` of `self` for every lane `i`. Lanes whose index is
+        /// negative or greater than 3 are set to zero.
+        #[inline]
+        pub fn swizzle(&self, indices: &$crate::wasm_simd::I32x4) -> Self {
+            use $crate::__private::arch::*;
+
+            let out_of_range = u32x4_gt(indices.0, u32x4_splat(3));
+            let lane_bytes = i32x4_mul(i32x4_shl(indices.0, 2), i32x4_splat(0x0101_0101));
+            let byte_indices = i32x4_add(lane_bytes, i32x4_splat(0x0302_0100));
+            Self(i8x16_swizzle(self.0, v128_or(byte_indices, out_of_range)))
+        }
+    };
+}
+
+// Implements an arithmetic operator and its assignment form for every `Simd`, with a vector
+// or a scalar on the right, and with a scalar on the left for each lane type.
+macro_rules! impl_simd_op {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl<T: $crate::wasm_simd::SupportedLanes<N>, const N: usize> ::core::ops::$trait
+            for $crate::wasm_simd::Simd<T, N>
+        {
             type Output = Self;
             #[inline]
             fn $fn(self, other: Self) -> Self::Output {
-                Self($op_fn(self.0, other.0))
+                Self(<T as $crate::wasm_simd::SupportedLanes<N>>::$fn(self.0, other.0))
             }
         }
 
-        impl $trait<$scalar_type> for $vec_type {
+        impl<T: $crate::wasm_simd::SupportedLanes<N>, const N: usize> ::core::ops::$trait<T>
+            for $crate::wasm_simd::Simd<T, N>
+        {
             type Output = Self;
             #[inline]
-            fn $fn(self, other: $scalar_type) -> Self::Output {
-                Self($op_fn(self.0, Self::splat(other).to_v128()))
+            fn $fn(self, other: T) -> Self::Output {
+                let other = <T as $crate::wasm_simd::SupportedLanes<N>>::splat(other);
+                Self(<T as $crate::wasm_simd::SupportedLanes<N>>::$fn(self.0, other))
             }
         }
 
-        impl $trait<$vec_type> for $scalar_type {
-            type Output = $vec_type;
+        impl<T: $crate::wasm_simd::SupportedLanes<N>, const N: usize> ::core::ops::$assign_trait
+            for $crate::wasm_simd::Simd<T, N>
+        {
             #[inline]
+            fn $assign_fn(&mut self, other: Self) {
+                *self = ::core::ops::$trait::$fn(*self, other);
+            }
+        }
 
-            fn $fn(self, other: $vec_type) -> Self::Output {
-                <$vec_type>::from_v128($op_fn(<$vec_type>::splat(self).to_v128(), other.0))
+        impl<T: $crate::wasm_simd::SupportedLanes<N>, const N: usize>
+            ::core::ops::$assign_trait<T> for $crate::wasm_simd::Simd<T, N>
+        {
+            #[inline]
+            fn $assign_fn(&mut self, other: T) {
+                *self = ::core::ops::$trait::$fn(*self, other);
             }
         }
+
+        $crate::wasm_simd::macros::impl_simd_op!(@scalar $trait, $fn, i32, u32, f32);
+    };
+    (@scalar $trait:ident, $fn:ident, $($scalar:ty),+) => {
+        $(
+            impl<const N: usize> ::core::ops::$trait<$crate::wasm_simd::Simd<$scalar, N>>
+                for $scalar
+            where
+                $scalar: $crate::wasm_simd::SupportedLanes<N>,
+            {
+                type Output = $crate::wasm_simd::Simd<$scalar, N>;
+                #[inline]
+                fn $fn(self, other: $crate::wasm_simd::Simd<$scalar, N>) -> Self::Output {
+                    let this = <$scalar as $crate::wasm_simd::SupportedLanes<N>>::splat(self);
+                    $crate::wasm_simd::Simd(
+                        <$scalar as $crate::wasm_simd::SupportedLanes<N>>::$fn(this, other.0),
+                    )
+                }
+            }
+        )+
     };
 }
+pub(crate) use impl_simd_op;
 
-#[macro_export]
-macro_rules! impl_vec_assign_op {
-    ($vec_type:ty, $scalar_type: ty, $trait:ident, $fn:ident, $op:tt) => {
-        impl $trait for $vec_type {
-            #[inline]
-            fn $fn(&mut self, other: Self) {
-                (*self) = self.clone() $op other;
+// Implements a bitwise operator and its assignment form for every `Mask`, with a mask or a
+// `bool` on either side.
+macro_rules! impl_mask_op {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $mask_fn:ident) => {
+        impl<T: $crate::wasm_simd::MaskElement<N>, const N: usize> ::core::ops::$trait
+            for $crate::wasm_simd::Mask<T, N>
+        {
+            type Output = Self;
+
+            fn $fn(self, other: Self) -> Self::Output {
+                Self(T::$mask_fn(self.0, other.0))
             }
         }
 
-        impl $trait<$scalar_type> for $vec_type {
-            #[inline]
-            fn $fn(&mut self, other: $scalar_type) {
-                (*self) = self.clone() $op Self::splat(other);
+        impl<T: $crate::wasm_simd::MaskElement<N>, const N: usize> ::core::ops::$trait<bool>
+            for $crate::wasm_simd::Mask<T, N>
+        {
+            type Output = Self;
+
+            fn $fn(self, other: bool) -> Self::Output {
+                Self(T::$mask_fn(self.0, T::mask_splat(other)))
+            }
+        }
+
+        impl<T: $crate::wasm_simd::MaskElement<N>, const N: usize>
+            ::core::ops::$trait<$crate::wasm_simd::Mask<T, N>> for bool
+        {
+            type Output = $crate::wasm_simd::Mask<T, N>;
+
+            fn $fn(self, other: $crate::wasm_simd::Mask<T, N>) -> Self::Output {
+                $crate::wasm_simd::Mask(T::$mask_fn(T::mask_splat(self), other.0))
+            }
+        }
+
+        impl<T: $crate::wasm_simd::MaskElement<N>, const N: usize> ::core::ops::$assign_trait
+            for $crate::wasm_simd::Mask<T, N>
+        {
+            fn $assign_fn(&mut self, other: Self) {
+                *self = ::core::ops::$trait::$fn(*self, other);
+            }
+        }
+
+        impl<T: $crate::wasm_simd::MaskElement<N>, const N: usize> ::core::ops::$assign_trait<bool>
+            for $crate::wasm_simd::Mask<T, N>
+        {
+            fn $assign_fn(&mut self, other: bool) {
+                *self = ::core::ops::$trait::$fn(*self, other);
             }
         }
     };
 }
+pub(crate) use impl_mask_op;
 
 #[macro_export]
 macro_rules! impl_vec_cmp {
     ($vec_fn:ident, $scalar_fn:ident, $cmp_fn:ident, $ret:ty) => {
         #[inline]
         pub fn $vec_fn(&self, other: &Self) -> $ret {
-            let mask = ::core::arch::wasm32::$cmp_fn(self.0, other.0);
+            let mask = $crate::__private::arch::$cmp_fn(self.0, other.0);
             <$ret>::from_v128(mask)
         }
 
         #[inline]
         pub fn $scalar_fn(&self, other: i32) -> $ret {
-            let mask = ::core::arch::wasm32::$cmp_fn(self.0, i32x4_splat(other));
+            let mask = $crate::__private::arch::$cmp_fn(
+                self.0,
+                $crate::__private::arch::i32x4_splat(other),
+            );
             <$ret>::from_v128(mask)
         }
     };
@@ -91,9 +417,20 @@ macro_rules! impl_vec_cmp {
 
 #[macro_export]
 macro_rules! impl_debug {
+    ($struct_name:ident) => {
+        impl core::fmt::Debug for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut tuple = f.debug_tuple(stringify!($struct_name));
+                for lane in (*self).into_iter() {
+                    tuple.field(&lane);
+                }
+                tuple.finish()
+            }
+        }
+    };
     ($struct_name:ident, ($($field_var:ident),+)) => {
-        impl std::fmt::Debug for $struct_name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl core::fmt::Debug for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 let ($($field_var),+) = self.extract_lanes();
                 f.debug_tuple(stringify!($struct_name))
                     $(.field(&$field_var))+
@@ -103,13 +440,1236 @@ macro_rules! impl_debug {
     };
 }
 
+// Implements each formatting trait for every `Simd` whose lane type implements it.
+macro_rules! impl_simd_fmt {
+    ($($trait:ident),+) => {
+        $(
+            impl<T, const N: usize> ::core::fmt::$trait for $crate::wasm_simd::Simd<T, N>
+            where
+                T: $crate::wasm_simd::SupportedLanes<N> + ::core::fmt::$trait,
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    $crate::wasm_simd::simd::fmt_lanes(
+                        self.to_array(),
+                        f,
+                        ::core::fmt::$trait::fmt,
+                    )
+                }
+            }
+        )+
+    };
+}
+pub(crate) use impl_simd_fmt;
+
 #[macro_export]
-macro_rules! impl_default {
+macro_rules! impl_vec_from_str {
     ($struct_name:ident, $type:ty) => {
-        impl Default for $struct_name {
-            fn default() -> Self {
-                Self::splat(<$type>::default())
+        $crate::impl_vec_from_str!($struct_name, $type, 4);
+    };
+    ($struct_name:ident, $type:ty, $lanes:literal) => {
+        impl ::core::str::FromStr for $struct_name {
+            type Err = $crate::wasm_simd::ParseVectorError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let lanes = $crate::wasm_simd::parse::parse_lanes::<$type, $lanes>(
+                    s,
+                    stringify!($struct_name),
+                )?;
+                Ok(Self::from(lanes))
+            }
+        }
+    };
+}
+
+// Vectors are ordered lane-wise (the product order): `a <= b` holds when every lane of `a` is
+// less than or equal to the matching lane of `b`. Vectors with lanes ordered both ways, or
+// with a NaN lane, are unordered and every comparison operator returns `false`.
+#[macro_export]
+macro_rules! impl_vec_partial_ord {
+    ($struct_name:ident) => {
+        impl PartialOrd for $struct_name {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                // These resolve to the inherent lane-wise comparisons, which return a mask.
+                let le = self.le(other).all();
+                let ge = self.ge(other).all();
+                match (le, ge) {
+                    (true, true) => Some(::core::cmp::Ordering::Equal),
+                    (true, false) => Some(::core::cmp::Ordering::Less),
+                    (false, true) => Some(::core::cmp::Ordering::Greater),
+                    (false, false) => None,
+                }
+            }
+        }
+    };
+}
+
+// Defines a 4-lane vector type, `$name = Simd<$lane, 4>`, with its `SupportedLanes<4>` storage
+// and everything the three 4-lane types share, from the table of intrinsics implementing it.
+// `div: lanewise` divides lane by lane for lane types without a division instruction. The
+// optional `neg` entry implements `SignedLanes<4>`, and the `integer` block implements
+// `IntegerLanes<4>` and adds the integer-only reductions. Anything else specific to one type
+// is implemented next to it.
+macro_rules! define_simd_type {
+    (
+        $name:ident: $lane:ident {
+            new: $new:ident,
+            extract_lane: $extract:ident,
+            replace_lane: $replace:ident,
+            add: $add:ident,
+            sub: $sub:ident,
+            mul: $mul:ident,
+            div: $div:ident,
+            eq: $eq:ident,
+            ne: $ne:ident,
+            lt: $lt:ident,
+            le: $le:ident,
+            gt: $gt:ident,
+            ge: $ge:ident,
+            min: $min:ident,
+            max: $max:ident,
+            all_true: $all_true:ident,
+            $(neg: $neg:ident,)?
+            $(integer: { shl: $shl:ident, shr: $shr:ident },)?
+        }
+    ) => {
+        pub type $name = $crate::wasm_simd::simd::Simd<$lane, 4>;
+
+        impl $crate::wasm_simd::simd::SupportedLanes<4> for $lane {
+            type Repr = $crate::__private::arch::v128;
+
+            #[inline]
+            fn splat(value: $lane) -> Self::Repr {
+                $crate::__private::arch::$new(value, value, value, value)
+            }
+
+            #[inline]
+            fn from_array([v1, v2, v3, v4]: [$lane; 4]) -> Self::Repr {
+                $crate::__private::arch::$new(v1, v2, v3, v4)
+            }
+
+            #[inline]
+            fn to_array(repr: Self::Repr) -> [$lane; 4] {
+                [
+                    $crate::__private::arch::$extract::<0>(repr),
+                    $crate::__private::arch::$extract::<1>(repr),
+                    $crate::__private::arch::$extract::<2>(repr),
+                    $crate::__private::arch::$extract::<3>(repr),
+                ]
+            }
+
+            #[inline]
+            fn add(a: Self::Repr, b: Self::Repr) -> Self::Repr {
+                $crate::__private::arch::$add(a, b)
+            }
+
+            #[inline]
+            fn sub(a: Self::Repr, b: Self::Repr) -> Self::Repr {
+                $crate::__private::arch::$sub(a, b)
+            }
+
+            #[inline]
+            fn mul(a: Self::Repr, b: Self::Repr) -> Self::Repr {
+                $crate::__private::arch::$mul(a, b)
+            }
+
+            #[inline]
+            fn div(a: Self::Repr, b: Self::Repr) -> Self::Repr {
+                $crate::wasm_simd::macros::define_simd_type!(@div $div, $new, a, b)
+            }
+
+            #[inline]
+            fn all_eq(a: Self::Repr, b: Self::Repr) -> bool {
+                $crate::__private::arch::$all_true($crate::__private::arch::$eq(a, b))
+            }
+
+            #[inline]
+            fn select(
+                mask: $crate::wasm_simd::Bx4,
+                if_true: Self::Repr,
+                if_false: Self::Repr,
+            ) -> Self::Repr {
+                $crate::wasm_simd::relaxed::lane_select(if_true, if_false, mask.to_v128())
+            }
+        }
+
+        impl $name {
+            pub const ZERO: Self =
+                Self::splat(<$lane as $crate::wasm_simd::simd::SimdElement>::ZERO);
+            pub const ONE: Self =
+                Self::splat(<$lane as $crate::wasm_simd::simd::SimdElement>::ONE);
+            pub const MIN: Self = Self::splat($lane::MIN);
+            pub const MAX: Self = Self::splat($lane::MAX);
+
+            pub const fn new(v1: $lane, v2: $lane, v3: $lane, v4: $lane) -> Self {
+                Self($crate::__private::arch::$new(v1, v2, v3, v4))
+            }
+
+            pub const fn splat(value: $lane) -> Self {
+                Self($crate::__private::arch::$new(value, value, value, value))
+            }
+
+            pub fn new_from_fn<F>(f: F) -> Self
+            where
+                F: Fn(usize) -> $lane,
+            {
+                Self($crate::__private::arch::$new(f(0), f(1), f(2), f(3)))
+            }
+
+            pub(crate) fn from_v128(data: $crate::__private::arch::v128) -> Self {
+                Self(data)
+            }
+
+            pub(crate) fn to_v128(self) -> $crate::__private::arch::v128 {
+                self.0
+            }
+
+            $crate::impl_vec_bitcast!();
+
+            pub fn apply<F>(&self, f: F) -> Self
+            where
+                F: Fn($lane) -> $lane,
+            {
+                let (v1, v2, v3, v4) = self.extract_lanes();
+                Self::new(f(v1), f(v2), f(v3), f(v4))
+            }
+
+            pub fn fold<F>(&self, f: F, init_val: $lane) -> $lane
+            where
+                F: Fn($lane, $lane) -> $lane,
+            {
+                let (v1, v2, v3, v4) = self.extract_lanes();
+                f(f(f(f(init_val, v1), v2), v3), v4)
+            }
+
+            pub fn extract_lanes(&self) -> ($lane, $lane, $lane, $lane) {
+                (
+                    $crate::__private::arch::$extract::<0>(self.0),
+                    $crate::__private::arch::$extract::<1>(self.0),
+                    $crate::__private::arch::$extract::<2>(self.0),
+                    $crate::__private::arch::$extract::<3>(self.0),
+                )
+            }
+
+            pub fn extract_lane(&self, index: usize) -> $lane {
+                match index {
+                    0 => $crate::__private::arch::$extract::<0>(self.0),
+                    1 => $crate::__private::arch::$extract::<1>(self.0),
+                    2 => $crate::__private::arch::$extract::<2>(self.0),
+                    3 => $crate::__private::arch::$extract::<3>(self.0),
+                    _ => panic!("Index out of bounds"),
+                }
+            }
+
+            pub fn set_lane(&mut self, index: usize, value: $lane) {
+                let new_vec = match index {
+                    0 => $crate::__private::arch::$replace::<0>(self.0, value),
+                    1 => $crate::__private::arch::$replace::<1>(self.0, value),
+                    2 => $crate::__private::arch::$replace::<2>(self.0, value),
+                    3 => $crate::__private::arch::$replace::<3>(self.0, value),
+                    _ => panic!("Index out of bounds"),
+                };
+
+                self.0 = new_vec;
+            }
+
+            $crate::impl_vec_lane_access!($lane, $extract, $replace);
+
+            $crate::impl_vec_shuffle!();
+
+            $crate::impl_vec_cmp!(eq, s_eq, $eq, $crate::wasm_simd::Bx4);
+            $crate::impl_vec_cmp!(ne, s_ne, $ne, $crate::wasm_simd::Bx4);
+            $crate::impl_vec_cmp!(lt, s_lt, $lt, $crate::wasm_simd::Bx4);
+            $crate::impl_vec_cmp!(le, s_le, $le, $crate::wasm_simd::Bx4);
+            $crate::impl_vec_cmp!(gt, s_gt, $gt, $crate::wasm_simd::Bx4);
+            $crate::impl_vec_cmp!(ge, s_ge, $ge, $crate::wasm_simd::Bx4);
+
+            $crate::impl_vec_masked_op!(masked_add, $add);
+            $crate::impl_vec_masked_op!(masked_sub, $sub);
+            $crate::impl_vec_masked_op!(masked_mul, $mul);
+            $crate::impl_vec_select!();
+            $crate::impl_vec_relaxed!();
+
+            $crate::impl_vec_binary_op!(min, s_min, $min, $lane);
+            $crate::impl_vec_binary_op!(max, s_max, $max, $lane);
+        }
+
+        $crate::impl_v128_conversions!($name);
+        $crate::impl_vec_from_str!($name, $lane);
+
+        $crate::impl_debug!($name, (v1, v2, v3, v4));
+
+        $(
+            impl $crate::wasm_simd::simd::SignedLanes<4> for $lane {
+                #[inline]
+                fn neg(a: Self::Repr) -> Self::Repr {
+                    $crate::__private::arch::$neg(a)
+                }
+            }
+        )?
+
+        $(
+            impl $name {
+                /// Sums the lanes. Overflow wraps in every build profile, like the lane-wise
+                /// `+`, instead of panicking in debug builds as scalar `+` does.
+                pub fn reduce_add(&self) -> $lane {
+                    self.fold(|a, b| a.wrapping_add(b), 0)
+                }
+
+                /// Multiplies the lanes. Overflow wraps in every build profile, like the
+                /// lane-wise `*`, instead of panicking in debug builds as scalar `*` does.
+                pub fn reduce_mul(&self) -> $lane {
+                    self.fold(|a, b| a.wrapping_mul(b), 1)
+                }
+
+                pub fn reduce_min(&self) -> $lane {
+                    self.fold(|a, b| if a < b { a } else { b }, $lane::MAX)
+                }
+
+                pub fn reduce_max(&self) -> $lane {
+                    self.fold(|a, b| if a > b { a } else { b }, $lane::MIN)
+                }
+
+                pub fn all_nonzero(self) -> bool {
+                    $crate::__private::arch::$all_true(self.0)
+                }
+
+                pub fn nonzero_mask(&self) -> $crate::wasm_simd::Bx4 {
+                    $crate::wasm_simd::Bx4::from_v128($crate::__private::arch::$ne(
+                        self.0,
+                        Self::ZERO.0,
+                    ))
+                }
+            }
+
+            impl $crate::wasm_simd::simd::IntegerLanes<4> for $lane {
+                #[inline]
+                fn shl(a: Self::Repr, amt: u32) -> Self::Repr {
+                    $crate::__private::arch::$shl(a, amt)
+                }
+
+                #[inline]
+                fn shr(a: Self::Repr, amt: u32) -> Self::Repr {
+                    $crate::__private::arch::$shr(a, amt)
+                }
+            }
+        )?
+    };
+
+    // There is no native integer division operation in WASM SIMD, so we implement it by
+    // dividing each value lane-wise
+    (@div lanewise, $new:ident, $a:ident, $b:ident) => {{
+        let ([n1, n2, n3, n4], [d1, d2, d3, d4]) = (Self::to_array($a), Self::to_array($b));
+        $crate::__private::arch::$new(n1 / d1, n2 / d2, n3 / d3, n4 / d4)
+    }};
+    (@div $div:ident, $new:ident, $a:ident, $b:ident) => {
+        $crate::__private::arch::$div($a, $b)
+    };
+}
+pub(crate) use define_simd_type;
+
+// The wide vectors below wrap `[Part; P]`, where part `i` holds lanes `4 * i` to `4 * i + 3`.
+// Lane-wise operations run once per part; everything that moves lanes between parts is
+// written so that each part of the result takes a single 4-lane shuffle.
+
+#[macro_export]
+macro_rules! impl_wide_lanes {
+    ($part:ident, $type:ty, $parts:literal, $lanes:literal) => {
+        /// Builds a vector from its 4-lane parts, in lane order.
+        #[inline]
+        pub const fn from_parts(parts: [$part; $parts]) -> Self {
+            Self(parts)
+        }
+
+        /// Returns the 4-lane parts of the vector, in lane order.
+        #[inline]
+        pub const fn to_parts(self) -> [$part; $parts] {
+            self.0
+        }
+
+        pub const fn splat(value: $type) -> Self {
+            Self([$part::splat(value); $parts])
+        }
+
+        pub fn extract_lane(&self, index: usize) -> $type {
+            assert!(index < $lanes, "Index out of bounds");
+            self.0[index / 4].extract_lane(index % 4)
+        }
+
+        pub fn set_lane(&mut self, index: usize, value: $type) {
+            assert!(index < $lanes, "Index out of bounds");
+            self.0[index / 4].set_lane(index % 4, value);
+        }
+
+        #[inline]
+        pub fn extract<const N: usize>(&self) -> $type {
+            const { assert!(N < $lanes, "Index out of bounds") };
+            self.extract_lane(N)
+        }
+
+        #[inline]
+        pub fn with_lane<const N: usize>(&self, value: $type) -> Self {
+            const { assert!(N < $lanes, "Index out of bounds") };
+            let mut result = *self;
+            result.set_lane(N, value);
+            result
+        }
+
+        #[inline]
+        pub fn set<const N: usize>(&mut self, value: $type) {
+            *self = self.with_lane::<N>(value);
+        }
+
+        pub fn get(&self, index: usize) -> Option<$type> {
+            if index < $lanes {
+                Some(self.extract_lane(index))
+            } else {
+                None
             }
         }
+
+        pub fn try_set_lane(
+            &mut self,
+            index: usize,
+            value: $type,
+        ) -> Result<(), $crate::wasm_simd::LaneIndexError> {
+            if index < $lanes {
+                self.set_lane(index, value);
+                Ok(())
+            } else {
+                Err($crate::wasm_simd::LaneIndexError {
+                    index,
+                    lanes: $lanes,
+                })
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_wide_shuffle {
+    (@shuffle $part:ident, 8, $indices:ident) => {
+        $crate::impl_wide_shuffle!(@shuffle $part, 8, $indices, [I0, I1, I2, I3, I4, I5, I6, I7]);
+    };
+    (@shuffle $part:ident, 16, $indices:ident) => {
+        $crate::impl_wide_shuffle!(
+            @shuffle $part,
+            16,
+            $indices,
+            [I0, I1, I2, I3, I4, I5, I6, I7, I8, I9, I10, I11, I12, I13, I14, I15]
+        );
+    };
+    (@shuffle $part:ident, $lanes:tt, $indices:ident, [$($i:ident),*]) => {
+        /// Picks lane `I` of `self` followed by `other` for every lane of the result, like
+        /// the 4-lane `shuffle`. Every index must be below twice the lane count.
+        #[inline]
+        pub fn shuffle<$(const $i: usize),*>(&self, other: &Self) -> Self {
+            const { assert!($($i < 2 * $lanes)&&*, "Index out of bounds") };
+            // Each swizzle zeroes the lanes that pick from the other vector.
+            let indices = $indices::from_array([$($i as i32),*]);
+            let (low, high) = (self.swizzle(&indices), other.swizzle(&(indices - $lanes)));
+            Self(::core::array::from_fn(|i| {
+                $part::from_v128($crate::__private::arch::v128_or(
+                    low.0[i].to_v128(),
+                    high.0[i].to_v128(),
+                ))
+            }))
+        }
+    };
+    ($part:ident, $parts:literal, $lanes:tt, $indices:ident) => {
+        $crate::impl_wide_shuffle!(@shuffle $part, $lanes, $indices);
+
+        #[inline]
+        pub fn reverse(&self) -> Self {
+            Self(::core::array::from_fn(|i| self.0[$parts - 1 - i].reverse()))
+        }
+
+        // Lane `i` of the result is lane `i + n` of `self`, wrapping around, so every part of
+        // the result is one shuffle of two neighbouring parts.
+        #[inline]
+        fn rotate_left_by(&self, n: usize) -> Self {
+            let (skip, offset) = (n / 4, n % 4);
+            Self(::core::array::from_fn(|i| {
+                let low = &self.0[(i + skip) % $parts];
+                let high = &self.0[(i + skip + 1) % $parts];
+                match offset {
+                    0 => *low,
+                    1 => low.shuffle::<1, 2, 3, 4>(high),
+                    2 => low.shuffle::<2, 3, 4, 5>(high),
+                    _ => low.shuffle::<3, 4, 5, 6>(high),
+                }
+            }))
+        }
+
+        /// Moves every lane `N` places towards lane 0, wrapping around.
+        #[inline]
+        pub fn rotate_lanes_left<const N: usize>(&self) -> Self {
+            self.rotate_left_by(N % $lanes)
+        }
+
+        /// Moves every lane `N` places towards the last lane, wrapping around.
+        #[inline]
+        pub fn rotate_lanes_right<const N: usize>(&self) -> Self {
+            self.rotate_left_by(($lanes - N % $lanes) % $lanes)
+        }
+
+        #[inline]
+        pub fn broadcast_lane<const N: usize>(&self) -> Self {
+            const { assert!(N < $lanes, "Index out of bounds") };
+            let part = &self.0[N / 4];
+            let lane = match N % 4 {
+                0 => part.broadcast_lane::<0>(),
+                1 => part.broadcast_lane::<1>(),
+                2 => part.broadcast_lane::<2>(),
+                _ => part.broadcast_lane::<3>(),
+            };
+            Self([lane; $parts])
+        }
+
+        // Interleaves the parts of `self` and `other` starting at part `first`.
+        #[inline]
+        fn interleave_from(&self, other: &Self, first: usize) -> Self {
+            Self(::core::array::from_fn(|i| {
+                let (a, b) = (&self.0[first + i / 2], &other.0[first + i / 2]);
+                if i % 2 == 0 {
+                    a.interleave_low(b)
+                } else {
+                    a.interleave_high(b)
+                }
+            }))
+        }
+
+        /// Interleaves the low halves: `(self[0], other[0], self[1], other[1], ...)`.
+        #[inline]
+        pub fn interleave_low(&self, other: &Self) -> Self {
+            self.interleave_from(other, 0)
+        }
+
+        /// Interleaves the high halves, starting with the middle lane of `self` and `other`.
+        #[inline]
+        pub fn interleave_high(&self, other: &Self) -> Self {
+            self.interleave_from(other, $parts / 2)
+        }
+
+        /// Returns the low half of `self` followed by the high half of `other`.
+        #[inline]
+        pub fn concat_low_high(&self, other: &Self) -> Self {
+            Self(::core::array::from_fn(|i| {
+                if i < $parts / 2 {
+                    self.0[i]
+                } else {
+                    other.0[i]
+                }
+            }))
+        }
+
+        /// Picks lane `indices[i]` of `self` for every lane `i`. Lanes whose index is
+        /// negative or out of range are set to zero.
+        #[inline]
+        pub fn swizzle(&self, indices: &$indices) -> Self {
+            let indices = indices.to_parts();
+            Self(::core::array::from_fn(|i| {
+                // Each source part fills the lanes whose index points into it and zeroes the
+                // rest, so OR-ing the swizzles of all parts gives the result.
+                let mut bits = self.0[0].swizzle(&indices[i]).to_v128();
+                for (j, part) in self.0.iter().enumerate().skip(1) {
+                    let local = indices[i] - (4 * j) as i32;
+                    bits = $crate::__private::arch::v128_or(bits, part.swizzle(&local).to_v128());
+                }
+                $part::from_v128(bits)
+            }))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_wide_numeric {
+    ($part:ident, $type:ty, $parts:literal, $mask:ident, $indices:ident) => {
+        pub fn new_from_fn<F>(f: F) -> Self
+        where
+            F: Fn(usize) -> $type,
+        {
+            Self(::core::array::from_fn(|i| {
+                $part::new_from_fn(|j| f(4 * i + j))
+            }))
+        }
+
+        pub fn apply<F>(&self, f: F) -> Self
+        where
+            F: Fn($type) -> $type,
+        {
+            Self(self.0.map(|part| part.apply(&f)))
+        }
+
+        #[inline]
+        pub fn to_ne_bytes(self) -> [u8; 16 * $parts] {
+            let mut bytes = [0; 16 * $parts];
+            for (chunk, part) in bytes.chunks_exact_mut(16).zip(self.0) {
+                chunk.copy_from_slice(&part.to_ne_bytes());
+            }
+            bytes
+        }
+
+        #[inline]
+        pub fn to_le_bytes(self) -> [u8; 16 * $parts] {
+            let mut bytes = [0; 16 * $parts];
+            for (chunk, part) in bytes.chunks_exact_mut(16).zip(self.0) {
+                chunk.copy_from_slice(&part.to_le_bytes());
+            }
+            bytes
+        }
+
+        #[inline]
+        pub fn from_ne_bytes(bytes: [u8; 16 * $parts]) -> Self {
+            Self(::core::array::from_fn(|i| {
+                let mut part = [0; 16];
+                part.copy_from_slice(&bytes[16 * i..16 * (i + 1)]);
+                $part::from_ne_bytes(part)
+            }))
+        }
+
+        #[inline]
+        pub fn from_le_bytes(bytes: [u8; 16 * $parts]) -> Self {
+            Self(::core::array::from_fn(|i| {
+                let mut part = [0; 16];
+                part.copy_from_slice(&bytes[16 * i..16 * (i + 1)]);
+                $part::from_le_bytes(part)
+            }))
+        }
+
+        #[inline]
+        pub fn select(mask: &$mask, if_true: &Self, if_false: &Self) -> Self {
+            let masks = mask.to_parts();
+            Self(::core::array::from_fn(|i| {
+                $part::select(&masks[i], &if_true.0[i], &if_false.0[i])
+            }))
+        }
+
+        #[inline]
+        pub fn masked_assign(&mut self, mask: &$mask, value: &Self) {
+            for ((part, mask), value) in self.0.iter_mut().zip(mask.to_parts()).zip(value.0) {
+                part.masked_assign(&mask, &value);
+            }
+        }
+
+        /// Like `select`, using the relaxed lane select instruction. Deterministic, since
+        /// every mask lane is either all ones or all zeros.
+        #[cfg(feature = "relaxed-simd")]
+        #[inline]
+        pub fn relaxed_select(mask: &$mask, if_true: &Self, if_false: &Self) -> Self {
+            let masks = mask.to_parts();
+            Self(::core::array::from_fn(|i| {
+                $part::relaxed_select(&masks[i], &if_true.0[i], &if_false.0[i])
+            }))
+        }
+
+        /// The same as `swizzle`: combining the parts relies on out-of-range lanes being
+        /// zero, so there is no cheaper relaxed form.
+        #[cfg(feature = "relaxed-simd")]
+        #[inline]
+        pub fn relaxed_swizzle(&self, indices: &$indices) -> Self {
+            self.swizzle(indices)
+        }
+
+        $crate::impl_wide_cmp!(eq, s_eq, $mask);
+        $crate::impl_wide_cmp!(ne, s_ne, $mask);
+        $crate::impl_wide_cmp!(lt, s_lt, $mask);
+        $crate::impl_wide_cmp!(le, s_le, $mask);
+        $crate::impl_wide_cmp!(gt, s_gt, $mask);
+        $crate::impl_wide_cmp!(ge, s_ge, $mask);
+
+        $crate::impl_wide_masked_op!(masked_add, $mask);
+        $crate::impl_wide_masked_op!(masked_sub, $mask);
+        $crate::impl_wide_masked_op!(masked_mul, $mask);
+
+        $crate::impl_wide_binary_op!(min, s_min, $type);
+        $crate::impl_wide_binary_op!(max, s_max, $type);
+    };
+}
+
+#[macro_export]
+macro_rules! impl_wide_cmp {
+    ($vec_fn:ident, $scalar_fn:ident, $mask:ident) => {
+        #[inline]
+        pub fn $vec_fn(&self, other: &Self) -> $mask {
+            $mask::from_parts(::core::array::from_fn(|i| self.0[i].$vec_fn(&other.0[i])))
+        }
+
+        #[inline]
+        pub fn $scalar_fn(&self, other: i32) -> $mask {
+            $mask::from_parts(self.0.map(|part| part.$scalar_fn(other)))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_wide_masked_op {
+    ($name:ident, $mask:ident) => {
+        #[inline]
+        pub fn $name(&self, mask: &$mask, other: &Self) -> Self {
+            let masks = mask.to_parts();
+            Self(::core::array::from_fn(|i| {
+                self.0[i].$name(&masks[i], &other.0[i])
+            }))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_wide_binary_op {
+    ($name:ident, $scalar_name:ident, $type:ty) => {
+        #[inline]
+        pub fn $name(&self, other: &Self) -> Self {
+            Self(::core::array::from_fn(|i| self.0[i].$name(&other.0[i])))
+        }
+
+        #[inline]
+        pub fn $scalar_name(&self, other: $type) -> Self {
+            self.$name(&Self::splat(other))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_wide_unary_op {
+    ($name:ident) => {
+        #[inline]
+        pub fn $name(self) -> Self {
+            Self(self.0.map(|part| part.$name()))
+        }
+    };
+    ($name:ident -> $out:ident) => {
+        #[inline]
+        pub fn $name(self) -> $out {
+            $out::from_parts(self.0.map(|part| part.$name()))
+        }
+    };
+}
+
+// Part conversions, parsing and `Debug` shared by the wide number vectors. The operators,
+// comparison, hashing, formatting and array conversions are generic over `Simd`.
+#[macro_export]
+macro_rules! impl_wide_traits {
+    ($struct_name:ident, $part:ident, $type:ty, $parts:literal, $lanes:literal) => {
+        impl From<[$part; $parts]> for $struct_name {
+            fn from(parts: [$part; $parts]) -> Self {
+                Self(parts)
+            }
+        }
+
+        impl From<$struct_name> for [$part; $parts] {
+            fn from(val: $struct_name) -> Self {
+                val.0
+            }
+        }
+
+        $crate::impl_vec_from_str!($struct_name, $type, $lanes);
+        $crate::impl_debug!($struct_name);
+    };
+}
+
+// Defines a wide vector, `$name = Simd<$lane, $lanes>`, made of `$parts` 4-lane `$part`
+// vectors. `vectors` names the `i32`, `u32` and `f32` vectors of the same width, which the
+// conversions and shuffles use; the methods specific to one lane type come from its `@lane`
+// arm.
+macro_rules! define_wide_type {
+    (
+        $(#[$attr:meta])*
+        $name:ident: $lane:ident {
+            part: $part:ident,
+            parts: $parts:literal,
+            lanes: $lanes:tt,
+            mask: $mask:ident,
+            vectors: [$i32_type:ident, $u32_type:ident, $f32_type:ident],
+        }
+    ) => {
+        $(#[$attr])*
+        pub type $name = $crate::wasm_simd::Simd<$lane, $lanes>;
+
+        impl $name {
+            pub const ZERO: Self =
+                Self::splat(<$lane as $crate::wasm_simd::simd::SimdElement>::ZERO);
+            pub const ONE: Self =
+                Self::splat(<$lane as $crate::wasm_simd::simd::SimdElement>::ONE);
+            pub const MIN: Self = Self::splat($lane::MIN);
+            pub const MAX: Self = Self::splat($lane::MAX);
+
+            $crate::impl_wide_lanes!($part, $lane, $parts, $lanes);
+            $crate::impl_wide_numeric!($part, $lane, $parts, $mask, $i32_type);
+            $crate::impl_wide_shuffle!($part, $parts, $lanes, $i32_type);
+        }
+
+        $crate::impl_wide_traits!($name, $part, $lane, $parts, $lanes);
+
+        $crate::wasm_simd::macros::define_wide_type!(
+            @lane $lane, $name, $part, $mask, $i32_type, $u32_type, $f32_type
+        );
+    };
+    (@lane i32, $name:ident, $part:ident, $mask:ident, $i32_type:ident, $u32_type:ident, $f32_type:ident) => {
+        impl $name {
+            pub fn fold<F>(&self, f: F, init_val: i32) -> i32
+            where
+                F: Fn(i32, i32) -> i32,
+            {
+                self.to_array().into_iter().fold(init_val, f)
+            }
+
+            $crate::wasm_simd::macros::define_wide_type!(@integer i32, $part, $mask);
+
+            $crate::impl_wide_unary_op!(abs);
+        }
+
+        impl From<$f32_type> for $name {
+            fn from(value: $f32_type) -> Self {
+                value.to_i32_trunc()
+            }
+        }
+    };
+    (@lane u32, $name:ident, $part:ident, $mask:ident, $i32_type:ident, $u32_type:ident, $f32_type:ident) => {
+        impl $name {
+            pub fn reduce<F>(&self, f: F, init_val: u32) -> u32
+            where
+                F: Fn(u32, u32) -> u32,
+            {
+                self.to_array().into_iter().fold(init_val, f)
+            }
+
+            $crate::wasm_simd::macros::define_wide_type!(@integer u32, $part, $mask);
+        }
+
+        impl From<$f32_type> for $name {
+            fn from(value: $f32_type) -> Self {
+                value.to_u32_trunc()
+            }
+        }
+    };
+    (@lane f32, $name:ident, $part:ident, $mask:ident, $i32_type:ident, $u32_type:ident, $f32_type:ident) => {
+        impl $name {
+            pub const NAN: Self = Self::splat(f32::NAN);
+            pub const INFINITY: Self = Self::splat(f32::INFINITY);
+            pub const NEG_INFINITY: Self = Self::splat(f32::NEG_INFINITY);
+
+            /// Returns the raw IEEE 754 bits of each lane, like `f32::to_bits`.
+            pub fn to_bits(self) -> $u32_type {
+                $u32_type::from_parts(self.0.map($part::to_bits))
+            }
+
+            /// Builds a vector from the raw IEEE 754 bits of each lane, like `f32::from_bits`.
+            pub fn from_bits(bits: $u32_type) -> Self {
+                Self(bits.to_parts().map($part::from_bits))
+            }
+
+            // Rounding and saturation match the `F32x4` conversions.
+            $crate::impl_wide_unary_op!(to_i32_round -> $i32_type);
+            $crate::impl_wide_unary_op!(to_i32_floor -> $i32_type);
+            $crate::impl_wide_unary_op!(to_i32_ceil -> $i32_type);
+            $crate::impl_wide_unary_op!(to_i32_trunc -> $i32_type);
+            $crate::impl_wide_unary_op!(to_u32_round -> $u32_type);
+            $crate::impl_wide_unary_op!(to_u32_floor -> $u32_type);
+            $crate::impl_wide_unary_op!(to_u32_ceil -> $u32_type);
+            $crate::impl_wide_unary_op!(to_u32_trunc -> $u32_type);
+
+            $crate::impl_wide_unary_op!(abs);
+            $crate::impl_wide_unary_op!(ceil);
+            $crate::impl_wide_unary_op!(floor);
+
+            /// Computes `self * a + b` lane-wise, like `F32x4::mul_add`.
+            #[inline]
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                Self(::core::array::from_fn(|i| self.0[i].mul_add(a.0[i], b.0[i])))
+            }
+        }
+
+        impl From<$i32_type> for $name {
+            fn from(value: $i32_type) -> Self {
+                Self(value.to_parts().map($part::from))
+            }
+        }
+
+        impl From<$u32_type> for $name {
+            fn from(value: $u32_type) -> Self {
+                Self(value.to_parts().map($part::from))
+            }
+        }
+    };
+    // The reductions combine the parts lane-wise first, then reduce a single 4-lane part.
+    (@integer $lane:ident, $part:ident, $mask:ident) => {
+        /// Sums the lanes, wrapping on overflow like the lane-wise `+`.
+        pub fn reduce_add(&self) -> $lane {
+            self.0[1..].iter().fold(self.0[0], |acc, part| acc + *part).reduce_add()
+        }
+
+        /// Multiplies the lanes, wrapping on overflow like the lane-wise `*`.
+        pub fn reduce_mul(&self) -> $lane {
+            self.0[1..].iter().fold(self.0[0], |acc, part| acc * *part).reduce_mul()
+        }
+
+        pub fn reduce_min(&self) -> $lane {
+            self.0[1..].iter().fold(self.0[0], |acc, part| acc.min(part)).reduce_min()
+        }
+
+        pub fn reduce_max(&self) -> $lane {
+            self.0[1..].iter().fold(self.0[0], |acc, part| acc.max(part)).reduce_max()
+        }
+
+        pub fn all_nonzero(self) -> bool {
+            self.0.iter().all(|part| part.all_nonzero())
+        }
+
+        pub fn nonzero_mask(&self) -> $mask {
+            $mask::from_parts(self.0.map(|part| part.nonzero_mask()))
+        }
+    };
+}
+pub(crate) use define_wide_type;
+
+// Defines a wide mask, `$struct_name = Mask<i32, $lanes>`, with one `Bx4` per 4-lane part of
+// the vectors it selects between. The operators, comparison, hashing and `Display` are generic
+// over `Mask`.
+macro_rules! define_wide_mask {
+    (
+        $(#[$attr:meta])*
+        $struct_name:ident {
+            parts: $parts:literal,
+            lanes: $lanes:tt,
+            bitmask: $bits:ty,
+            vectors: [$i32_type:ident => $to_i32:ident, $u32_type:ident => $to_u32_ones:ident],
+        }
+    ) => {
+        $(#[$attr])*
+        pub type $struct_name = $crate::wasm_simd::Mask<i32, $lanes>;
+
+        impl $struct_name {
+            pub const ALL_FALSE: Self = Self::splat(false);
+            pub const ALL_TRUE: Self = Self::splat(true);
+
+            $crate::impl_wide_lanes!(Bx4, bool, $parts, $lanes);
+
+            /// Returns bit `i` set for every set lane `i`.
+            pub fn to_bitmask(self) -> $bits {
+                <i32 as $crate::wasm_simd::MaskElement<$lanes>>::mask_to_bitmask(self.0)
+            }
+
+            /// Builds a mask from `bitmask`, bit `i` setting lane `i`. This is the inverse of
+            /// `to_bitmask`.
+            pub fn from_bitmask(bitmask: $bits) -> Self {
+                Self(::core::array::from_fn(|i| {
+                    Bx4::from_bitmask((bitmask >> (4 * i)) as u8)
+                }))
+            }
+
+            /// Returns the mask as integers, with -1 in set lanes and 0 elsewhere.
+            pub fn $to_i32(self) -> $i32_type {
+                $i32_type::from_parts(self.0.map(Bx4::to_i32x4))
+            }
+
+            /// Returns the mask as integers, with 1 in set lanes and 0 elsewhere.
+            pub fn $to_u32_ones(self) -> $u32_type {
+                $u32_type::from_parts(self.0.map(Bx4::to_u32x4_ones))
+            }
+
+            pub fn any(self) -> bool {
+                self.0
+                    .iter()
+                    .fold(Bx4::ALL_FALSE, |acc, part| acc | *part)
+                    .any()
+            }
+
+            pub fn all(self) -> bool {
+                self.0
+                    .iter()
+                    .fold(Bx4::ALL_TRUE, |acc, part| acc & *part)
+                    .all()
+            }
+
+            pub fn none(self) -> bool {
+                !self.any()
+            }
+
+            pub fn count_true(self) -> usize {
+                self.to_bitmask().count_ones() as usize
+            }
+
+            /// Returns the index of the lowest set lane, or `None` if no lane is set.
+            pub fn first_true(self) -> Option<usize> {
+                let bitmask = self.to_bitmask();
+                if bitmask == 0 {
+                    None
+                } else {
+                    Some(bitmask.trailing_zeros() as usize)
+                }
+            }
+
+            /// Returns the index of the highest set lane, or `None` if no lane is set.
+            pub fn last_true(self) -> Option<usize> {
+                let bitmask = self.to_bitmask();
+                if bitmask == 0 {
+                    None
+                } else {
+                    Some((<$bits>::BITS - 1 - bitmask.leading_zeros()) as usize)
+                }
+            }
+
+            /// Returns an iterator over the indices of the set lanes, in ascending order.
+            pub fn true_lanes(self) -> $crate::wasm_simd::TrueLanes {
+                $crate::wasm_simd::TrueLanes::new(self.to_bitmask().into())
+            }
+
+            $crate::impl_wide_shuffle!(Bx4, $parts, $lanes, $i32_type);
+
+            pub fn to_ne_bytes(self) -> [u8; 16 * $parts] {
+                let mut bytes = [0; 16 * $parts];
+                for (chunk, part) in bytes.chunks_exact_mut(16).zip(self.0) {
+                    chunk.copy_from_slice(&part.to_ne_bytes());
+                }
+                bytes
+            }
+        }
+
+        impl From<[Bx4; $parts]> for $struct_name {
+            fn from(parts: [Bx4; $parts]) -> Self {
+                Self(parts)
+            }
+        }
+
+        impl From<$struct_name> for [Bx4; $parts] {
+            fn from(val: $struct_name) -> Self {
+                val.0
+            }
+        }
+
+        $crate::impl_debug!($struct_name);
+        $crate::impl_vec_from_str!($struct_name, bool, $lanes);
+    };
+}
+pub(crate) use define_wide_mask;
+
+// Stores a `Simd` or `Mask` of `$lanes` lanes as `$parts` 4-lane parts, part `i` holding lanes
+// `4 * i` to `4 * i + 3`, and runs every lane-wise operation once per part.
+macro_rules! impl_wide_lane_counts {
+    ($lanes:literal, $parts:literal, $bits:ty) => {
+        impl<T: $crate::wasm_simd::SupportedLanes<4>> $crate::wasm_simd::SupportedLanes<$lanes>
+            for T
+        {
+            type Repr = [$crate::wasm_simd::Simd<T, 4>; $parts];
+
+            #[inline]
+            fn splat(value: T) -> Self::Repr {
+                let part = <T as $crate::wasm_simd::SupportedLanes<4>>::splat(value);
+                [$crate::wasm_simd::Simd(part); $parts]
+            }
+
+            #[inline]
+            fn from_array(lanes: [T; $lanes]) -> Self::Repr {
+                ::core::array::from_fn(|i| {
+                    $crate::wasm_simd::Simd::from_array([
+                        lanes[4 * i],
+                        lanes[4 * i + 1],
+                        lanes[4 * i + 2],
+                        lanes[4 * i + 3],
+                    ])
+                })
+            }
+
+            #[inline]
+            fn to_array(repr: Self::Repr) -> [T; $lanes] {
+                let parts = repr.map($crate::wasm_simd::Simd::to_array);
+                ::core::array::from_fn(|i| parts[i / 4][i % 4])
+            }
+
+            #[inline]
+            fn add(a: Self::Repr, b: Self::Repr) -> Self::Repr {
+                ::core::array::from_fn(|i| a[i] + b[i])
+            }
+
+            #[inline]
+            fn sub(a: Self::Repr, b: Self::Repr) -> Self::Repr {
+                ::core::array::from_fn(|i| a[i] - b[i])
+            }
+
+            #[inline]
+            fn mul(a: Self::Repr, b: Self::Repr) -> Self::Repr {
+                ::core::array::from_fn(|i| a[i] * b[i])
+            }
+
+            #[inline]
+            fn div(a: Self::Repr, b: Self::Repr) -> Self::Repr {
+                ::core::array::from_fn(|i| a[i] / b[i])
+            }
+
+            #[inline]
+            fn all_eq(a: Self::Repr, b: Self::Repr) -> bool {
+                a == b
+            }
+
+            #[inline]
+            fn select(
+                mask: $crate::wasm_simd::Mask<i32, $lanes>,
+                if_true: Self::Repr,
+                if_false: Self::Repr,
+            ) -> Self::Repr {
+                ::core::array::from_fn(|i| {
+                    $crate::wasm_simd::Simd(<T as $crate::wasm_simd::SupportedLanes<4>>::select(
+                        mask.0[i],
+                        if_true[i].0,
+                        if_false[i].0,
+                    ))
+                })
+            }
+        }
+
+        impl<T: $crate::wasm_simd::SignedLanes<4>> $crate::wasm_simd::SignedLanes<$lanes> for T {
+            #[inline]
+            fn neg(a: Self::Repr) -> Self::Repr {
+                a.map(|part| -part)
+            }
+        }
+
+        impl<T: $crate::wasm_simd::IntegerLanes<4>> $crate::wasm_simd::IntegerLanes<$lanes> for T {
+            #[inline]
+            fn shl(a: Self::Repr, amt: u32) -> Self::Repr {
+                a.map(|part| part << amt)
+            }
+
+            #[inline]
+            fn shr(a: Self::Repr, amt: u32) -> Self::Repr {
+                a.map(|part| part >> amt)
+            }
+        }
+
+        impl<T> $crate::wasm_simd::MaskElement<$lanes> for T
+        where
+            T: $crate::wasm_simd::MaskElement<4, Bitmask = u8>,
+        {
+            type MaskRepr = [$crate::wasm_simd::Mask<T, 4>; $parts];
+            type Bitmask = $bits;
+
+            fn mask_splat(value: bool) -> Self::MaskRepr {
+                [$crate::wasm_simd::Mask(<T as $crate::wasm_simd::MaskElement<4>>::mask_splat(
+                    value,
+                )); $parts]
+            }
+
+            fn mask_from_array(lanes: [bool; $lanes]) -> Self::MaskRepr {
+                ::core::array::from_fn(|i| {
+                    $crate::wasm_simd::Mask::from_array([
+                        lanes[4 * i],
+                        lanes[4 * i + 1],
+                        lanes[4 * i + 2],
+                        lanes[4 * i + 3],
+                    ])
+                })
+            }
+
+            fn mask_to_array(repr: Self::MaskRepr) -> [bool; $lanes] {
+                let parts = repr.map($crate::wasm_simd::Mask::to_array);
+                ::core::array::from_fn(|i| parts[i / 4][i % 4])
+            }
+
+            fn mask_and(a: Self::MaskRepr, b: Self::MaskRepr) -> Self::MaskRepr {
+                ::core::array::from_fn(|i| a[i] & b[i])
+            }
+
+            fn mask_or(a: Self::MaskRepr, b: Self::MaskRepr) -> Self::MaskRepr {
+                ::core::array::from_fn(|i| a[i] | b[i])
+            }
+
+            fn mask_xor(a: Self::MaskRepr, b: Self::MaskRepr) -> Self::MaskRepr {
+                ::core::array::from_fn(|i| a[i] ^ b[i])
+            }
+
+            fn mask_not(a: Self::MaskRepr) -> Self::MaskRepr {
+                a.map(|part| !part)
+            }
+
+            fn mask_to_bitmask(repr: Self::MaskRepr) -> $bits {
+                repr.iter().enumerate().fold(0, |bitmask, (i, part)| {
+                    let part = <T as $crate::wasm_simd::MaskElement<4>>::mask_to_bitmask(part.0);
+                    bitmask | <$bits>::from(part) << (4 * i)
+                })
+            }
+        }
+    };
+}
+pub(crate) use impl_wide_lane_counts;
+
+// Lane-wise arithmetic for the geometry types, which wrap an `F32x4` in `.0`, with a vector or
+// a scalar on either side. Every result goes through `$wrap`, which builds the type from its
+// lanes.
+#[cfg(feature = "geometry")]
+#[macro_export]
+macro_rules! impl_geometry_ops {
+    ($name:ident, $wrap:path) => {
+        $crate::impl_geometry_ops!(@op $name, $wrap, Add, add, AddAssign, add_assign);
+        $crate::impl_geometry_ops!(@op $name, $wrap, Sub, sub, SubAssign, sub_assign);
+        $crate::impl_geometry_ops!(@op $name, $wrap, Mul, mul, MulAssign, mul_assign);
+        $crate::impl_geometry_ops!(@op $name, $wrap, Div, div, DivAssign, div_assign);
+
+        impl ::core::ops::Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                $wrap(-self.0)
+            }
+        }
+    };
+    (@op $name:ident, $wrap:path, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl ::core::ops::$trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                $wrap(::core::ops::$trait::$fn(self.0, rhs.0))
+            }
+        }
+
+        impl ::core::ops::$trait<f32> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: f32) -> Self {
+                $wrap(::core::ops::$trait::$fn(self.0, rhs))
+            }
+        }
+
+        impl ::core::ops::$trait<$name> for f32 {
+            type Output = $name;
+
+            #[inline]
+            fn $fn(self, rhs: $name) -> $name {
+                $wrap(::core::ops::$trait::$fn(self, rhs.0))
+            }
+        }
+
+        impl ::core::ops::$assign_trait for $name {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = ::core::ops::$trait::$fn(*self, rhs);
+            }
+        }
+
+        impl ::core::ops::$assign_trait<f32> for $name {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: f32) {
+                *self = ::core::ops::$trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+// Named `Vec3A` swizzles of the `xyz` components such as `v.zyx()`, each a single lane
+// shuffle that also zeroes the padding lane.
+#[cfg(feature = "geometry")]
+#[macro_export]
+macro_rules! impl_geometry_swizzles {
+    ($($name:ident => [$x:literal, $y:literal, $z:literal]),+ $(,)?) => {
+        $(
+            #[inline]
+            pub fn $name(self) -> $crate::wasm_simd::geometry::Vec3A {
+                $crate::wasm_simd::geometry::Vec3A(
+                    self.0.shuffle::<$x, $y, $z, 4>(&$crate::wasm_simd::F32x4::ZERO),
+                )
+            }
+        )+
     };
 }
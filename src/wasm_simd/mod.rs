@@ -1,11 +1,46 @@
+pub(crate) mod arch;
+pub mod bx16;
 pub mod bx4;
+pub mod bx8;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
+pub mod error;
+pub mod f32x16;
 pub mod f32x4;
+pub mod f32x8;
+#[cfg(feature = "geometry")]
+pub mod geometry;
+pub mod i32x16;
 pub mod i32x4;
+pub mod i32x8;
+pub mod u32x16;
 pub mod u32x4;
+pub mod u32x8;
 
 pub mod macros;
+#[cfg(feature = "num-traits")]
+mod num_impls;
+mod parse;
+pub mod relaxed;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod simd;
 
-pub use bx4::Bx4;
+pub use arch::v128;
+pub use bx16::Bx16;
+pub use bx4::{Bx4, TrueLanes};
+pub use bx8::Bx8;
+pub use error::{LaneIndexError, ParseVectorError};
+pub use f32x16::F32x16;
 pub use f32x4::F32x4;
+pub use f32x8::F32x8;
+pub use i32x16::I32x16;
 pub use i32x4::I32x4;
+pub use i32x8::I32x8;
+pub use simd::{IntegerLanes, Mask, MaskElement, SignedLanes, Simd, SimdElement, SupportedLanes};
+pub use u32x16::U32x16;
 pub use u32x4::U32x4;
+pub use u32x8::U32x8;
+
+#[cfg(feature = "serde")]
+pub use serde_impls::compact;
@@ -0,0 +1,561 @@
+//! [`num-traits`](https://docs.rs/num-traits) implementations, enabled with the `num-traits`
+//! feature, so the vector types can be used in code that is generic over numbers.
+//!
+//! Arithmetic is lane-wise, exactly like the operators. Trait methods that return a single
+//! scalar answer follow these rules:
+//!
+//! - Predicates such as `Zero::is_zero`, `Signed::is_positive` and `Float::is_finite` hold
+//!   only if they hold for every lane. `Float::is_nan`, `Float::is_infinite` and
+//!   `Float::is_subnormal` hold if they hold for any lane, so `is_finite` stays the negation
+//!   of `is_nan || is_infinite`.
+//! - `Float::classify` reports the most exceptional lane, in the order `Nan`, `Infinite`,
+//!   `Subnormal`, `Zero`, `Normal`.
+//! - `ToPrimitive` only succeeds when all four lanes hold the same value, and `NumCast`
+//!   splats the converted scalar.
+//! - `Float::integer_decode` decodes lane 0.
+//!
+//! `Num` requires `Rem`, which WebAssembly SIMD has no instruction for, so `%` between two
+//! vectors is computed one lane at a time and only exists with this feature.
+//!
+//! `Float` requires `PartialOrd`, so with this feature `F32x4` implements it as a lane-wise
+//! product order (see `PartialOrd`): two vectors are only ordered when every lane agrees. Generic code that
+//! branches on `<` or `>` between vectors therefore sees most pairs as unordered. Prefer
+//! `Float::max`, `Float::min` and `Float::clamp`, which are lane-wise.
+//!
+//! Transcendental functions (`sin`, `exp`, `powf`, ...) have no WebAssembly SIMD instruction
+//! and are computed one lane at a time.
+
+use crate::wasm_simd::arch::*;
+use crate::wasm_simd::bx4::Bx4;
+use crate::wasm_simd::error::ParseVectorError;
+use crate::wasm_simd::f32x4::F32x4;
+use crate::wasm_simd::i32x4::I32x4;
+use crate::wasm_simd::parse::parse_lanes_with;
+use crate::wasm_simd::u32x4::U32x4;
+use core::num::FpCategory;
+use core::ops::Rem;
+use num_traits::{
+    Bounded, ConstOne, ConstZero, Float, FloatConst, Num, NumCast, One, Signed, ToPrimitive,
+    Unsigned, Zero,
+};
+
+macro_rules! impl_identities {
+    ($struct_name:ident) => {
+        impl Zero for $struct_name {
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == Self::ZERO
+            }
+        }
+
+        impl ConstZero for $struct_name {
+            const ZERO: Self = Self::ZERO;
+        }
+
+        impl One for $struct_name {
+            #[inline]
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl ConstOne for $struct_name {
+            const ONE: Self = Self::ONE;
+        }
+
+        impl Bounded for $struct_name {
+            #[inline]
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+    };
+}
+
+macro_rules! impl_num {
+    ($struct_name:ident, $type:ty) => {
+        impl Num for $struct_name {
+            type FromStrRadixErr = ParseVectorError;
+
+            /// Parses four lanes in the given radix, accepting the same layouts as `FromStr`.
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                let lanes: [$type; 4] = parse_lanes_with(s, stringify!($struct_name), |token| {
+                    <$type as Num>::from_str_radix(token, radix).ok()
+                })?;
+                Ok(<Self as From<_>>::from(lanes))
+            }
+        }
+    };
+}
+
+// Forwards every `ToPrimitive` method to the scalar type when all lanes are equal.
+macro_rules! impl_to_primitive {
+    ($struct_name:ident, $type:ty, $($method:ident -> $ret:ty),+ $(,)?) => {
+        impl ToPrimitive for $struct_name {
+            $(
+                #[inline]
+                fn $method(&self) -> Option<$ret> {
+                    self.uniform_lane().and_then(|lane| <$type as ToPrimitive>::$method(&lane))
+                }
+            )+
+        }
+
+        impl NumCast for $struct_name {
+            #[inline]
+            fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+                <$type as NumCast>::from(n).map(Self::splat)
+            }
+        }
+    };
+}
+
+macro_rules! impl_cast {
+    ($struct_name:ident, $type:ty) => {
+        impl_to_primitive!(
+            $struct_name, $type,
+            to_isize -> isize, to_i8 -> i8, to_i16 -> i16, to_i32 -> i32, to_i64 -> i64,
+            to_i128 -> i128, to_usize -> usize, to_u8 -> u8, to_u16 -> u16, to_u32 -> u32,
+            to_u64 -> u64, to_u128 -> u128, to_f32 -> f32, to_f64 -> f64,
+        );
+    };
+}
+
+macro_rules! impl_rem {
+    ($struct_name:ident) => {
+        impl Rem for $struct_name {
+            type Output = Self;
+
+            #[inline]
+            fn rem(self, other: Self) -> Self {
+                let (a, b) = (self.to_array(), other.to_array());
+                Self::from_array(core::array::from_fn(|i| a[i] % b[i]))
+            }
+        }
+    };
+}
+
+impl_identities!(I32x4);
+impl_identities!(U32x4);
+impl_identities!(F32x4);
+
+impl_rem!(I32x4);
+impl_rem!(U32x4);
+impl_rem!(F32x4);
+
+impl_num!(I32x4, i32);
+impl_num!(U32x4, u32);
+impl_num!(F32x4, f32);
+
+impl_cast!(I32x4, i32);
+impl_cast!(U32x4, u32);
+impl_cast!(F32x4, f32);
+
+impl I32x4 {
+    fn uniform_lane(&self) -> Option<i32> {
+        let [v1, v2, v3, v4]: [i32; 4] = (*self).into();
+        (v1 == v2 && v2 == v3 && v3 == v4).then_some(v1)
+    }
+}
+
+impl U32x4 {
+    fn uniform_lane(&self) -> Option<u32> {
+        let [v1, v2, v3, v4]: [u32; 4] = (*self).into();
+        (v1 == v2 && v2 == v3 && v3 == v4).then_some(v1)
+    }
+}
+
+impl F32x4 {
+    // Compares bit patterns so a vector of identical NaNs still counts as uniform.
+    fn uniform_lane(&self) -> Option<f32> {
+        self.to_bits().uniform_lane().map(f32::from_bits)
+    }
+
+    fn zip_map<F>(self, other: Self, f: F) -> Self
+    where
+        F: Fn(f32, f32) -> f32,
+    {
+        let [a1, a2, a3, a4]: [f32; 4] = self.into();
+        let [b1, b2, b3, b4]: [f32; 4] = other.into();
+        Self::new(f(a1, b1), f(a2, b2), f(a3, b3), f(a4, b4))
+    }
+
+    fn trunc(self) -> Self {
+        Self::from_v128(f32x4_trunc(self.to_v128()))
+    }
+
+    fn nearest(self) -> Self {
+        Self::from_v128(f32x4_nearest(self.to_v128()))
+    }
+
+    fn nan_mask(self) -> Bx4 {
+        self.ne(&self)
+    }
+
+    fn infinite_mask(self) -> Bx4 {
+        self.abs().eq(&Self::INFINITY)
+    }
+
+    fn subnormal_mask(self) -> Bx4 {
+        let abs = self.abs();
+        abs.lt(&Self::splat(f32::MIN_POSITIVE)) & abs.ne(&Self::ZERO)
+    }
+
+    fn sign_mask(self) -> Bx4 {
+        Bx4::from_v128(i32x4_shr(self.to_v128(), 31))
+    }
+}
+
+impl Signed for I32x4 {
+    /// Lane-wise absolute value. `i32::MIN` wraps to itself, as with `I32x4::abs`.
+    #[inline]
+    fn abs(&self) -> Self {
+        I32x4::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        Self::select(&self.le(other), &Self::ZERO, &(*self - *other))
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        self.s_max(-1).s_min(1)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.s_gt(0).all()
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.s_lt(0).all()
+    }
+}
+
+impl Unsigned for U32x4 {}
+
+impl Signed for F32x4 {
+    #[inline]
+    fn abs(&self) -> Self {
+        F32x4::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        Float::abs_sub(*self, *other)
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        Float::signum(*self)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        Float::is_sign_positive(*self)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Float::is_sign_negative(*self)
+    }
+}
+
+macro_rules! impl_float_const {
+    ($($constant:ident),+ $(,)?) => {
+        impl FloatConst for F32x4 {
+            $(
+                #[inline]
+                fn $constant() -> Self {
+                    Self::splat(<f32 as FloatConst>::$constant())
+                }
+            )+
+        }
+    };
+}
+
+impl_float_const!(
+    E,
+    FRAC_1_PI,
+    FRAC_1_SQRT_2,
+    FRAC_2_PI,
+    FRAC_2_SQRT_PI,
+    FRAC_PI_2,
+    FRAC_PI_3,
+    FRAC_PI_4,
+    FRAC_PI_6,
+    FRAC_PI_8,
+    LN_10,
+    LN_2,
+    LOG10_E,
+    LOG2_E,
+    PI,
+    SQRT_2,
+    TAU,
+    LOG10_2,
+    LOG2_10,
+);
+
+// Forwards unary `Float` methods to the scalar implementation one lane at a time.
+macro_rules! impl_float_lanewise {
+    ($($method:ident),+ $(,)?) => {
+        $(
+            #[inline]
+            fn $method(self) -> Self {
+                self.apply(<f32 as Float>::$method)
+            }
+        )+
+    };
+}
+
+impl Float for F32x4 {
+    #[inline]
+    fn nan() -> Self {
+        Self::NAN
+    }
+
+    #[inline]
+    fn infinity() -> Self {
+        Self::INFINITY
+    }
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Self::NEG_INFINITY
+    }
+
+    #[inline]
+    fn neg_zero() -> Self {
+        Self::splat(-0.0)
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn min_positive_value() -> Self {
+        Self::splat(f32::MIN_POSITIVE)
+    }
+
+    #[inline]
+    fn epsilon() -> Self {
+        Self::splat(f32::EPSILON)
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.nan_mask().any()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.infinite_mask().any()
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.abs().lt(&Self::INFINITY).all()
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        let abs = self.abs();
+        (abs.ge(&Self::splat(f32::MIN_POSITIVE)) & abs.lt(&Self::INFINITY)).all()
+    }
+
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        self.subnormal_mask().any()
+    }
+
+    fn classify(self) -> FpCategory {
+        if self.nan_mask().any() {
+            FpCategory::Nan
+        } else if self.infinite_mask().any() {
+            FpCategory::Infinite
+        } else if self.subnormal_mask().any() {
+            FpCategory::Subnormal
+        } else if self.eq(&Self::ZERO).any() {
+            FpCategory::Zero
+        } else {
+            FpCategory::Normal
+        }
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        F32x4::floor(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        F32x4::ceil(self)
+    }
+
+    /// Rounds half-way cases away from zero, like `f32::round`.
+    #[inline]
+    fn round(self) -> Self {
+        let truncated = self.trunc();
+        let is_half = (self - truncated).abs().eq(&Self::splat(0.5));
+        let away = truncated + Self::ONE.copysign(self);
+        Self::select(&is_half, &away, &self.nearest())
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        F32x4::trunc(self)
+    }
+
+    #[inline]
+    fn fract(self) -> Self {
+        self - self.trunc()
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        F32x4::abs(self)
+    }
+
+    /// Returns `1.0` or `-1.0` with the sign of each lane, and NaN for NaN lanes.
+    #[inline]
+    fn signum(self) -> Self {
+        Self::select(&self.nan_mask(), &self, &Self::ONE.copysign(self))
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.sign_mask().none()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.sign_mask().all()
+    }
+
+    /// Fused multiply-add computed one lane at a time. The inherent `F32x4::mul_add` is
+    /// faster but rounds the product before the addition.
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        let [s1, s2, s3, s4]: [f32; 4] = self.into();
+        let [a1, a2, a3, a4]: [f32; 4] = a.into();
+        let [b1, b2, b3, b4]: [f32; 4] = b.into();
+        Self::new(
+            Float::mul_add(s1, a1, b1),
+            Float::mul_add(s2, a2, b2),
+            Float::mul_add(s3, a3, b3),
+            Float::mul_add(s4, a4, b4),
+        )
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        Self::ONE / self
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        self.apply(|lane| Float::powi(lane, n))
+    }
+
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        self.zip_map(n, Float::powf)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        F32x4::sqrt(self)
+    }
+
+    #[inline]
+    fn log(self, base: Self) -> Self {
+        self.zip_map(base, Float::log)
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Self {
+        self * Self::splat(180.0 / core::f32::consts::PI)
+    }
+
+    #[inline]
+    fn to_radians(self) -> Self {
+        self * Self::splat(core::f32::consts::PI / 180.0)
+    }
+
+    /// Lane-wise maximum. If one lane is NaN the other lane is returned, like `f32::max`.
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        let max = F32x4::max(&self, &other);
+        let max = Self::select(&other.nan_mask(), &self, &max);
+        Self::select(&self.nan_mask(), &other, &max)
+    }
+
+    /// Lane-wise minimum. If one lane is NaN the other lane is returned, like `f32::min`.
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        let min = F32x4::min(&self, &other);
+        let min = Self::select(&other.nan_mask(), &self, &min);
+        Self::select(&self.nan_mask(), &other, &min)
+    }
+
+    /// Lane-wise clamp. NaN lanes in `self` stay NaN.
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        F32x4::min(&F32x4::max(&self, &min), &max)
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Self) -> Self {
+        Self::select(&self.le(&other), &Self::ZERO, &(self - other))
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        self.zip_map(other, Float::hypot)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        self.zip_map(other, Float::atan2)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    /// Decodes lane 0 only; the other lanes are ignored.
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.extract::<0>())
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        let sign_bit = u32x4_splat(0x8000_0000);
+        Self::from_v128(v128_bitselect(sign.to_v128(), self.to_v128(), sign_bit))
+    }
+
+    impl_float_lanewise!(
+        exp, exp2, ln, log2, log10, cbrt, sin, cos, tan, asin, acos, atan, exp_m1, ln_1p, sinh,
+        cosh, tanh, asinh, acosh, atanh,
+    );
+}
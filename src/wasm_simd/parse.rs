@@ -0,0 +1,70 @@
+use crate::wasm_simd::error::ParseVectorError;
+use core::str::FromStr;
+
+pub(crate) fn parse_lanes<T, const N: usize>(
+    s: &str,
+    type_name: &str,
+) -> Result<[T; N], ParseVectorError>
+where
+    T: FromStr + Default + Copy,
+{
+    parse_lanes_with(s, type_name, |token| token.parse().ok())
+}
+
+// Accepts the `Debug` format (`I32x4(1, 2, 3, 4)`), the `Display` format (`[1, 2, 3, 4]`),
+// and bare lists separated by commas or whitespace (`1, 2, 3, 4` or `1 2 3 4`), parsing each
+// lane with `parse`.
+pub(crate) fn parse_lanes_with<T, F, const N: usize>(
+    s: &str,
+    type_name: &str,
+    parse: F,
+) -> Result<[T; N], ParseVectorError>
+where
+    T: Default + Copy,
+    F: Fn(&str) -> Option<T>,
+{
+    let mut body = s.trim();
+    body = body.strip_prefix(type_name).unwrap_or(body).trim_start();
+
+    for (open, close) in [('(', ')'), ('[', ']')] {
+        if let Some(inner) = body.strip_prefix(open).and_then(|b| b.strip_suffix(close)) {
+            body = inner.trim();
+            break;
+        }
+    }
+
+    if body.contains(',') {
+        collect_lanes(body.split(',').map(str::trim), parse)
+    } else {
+        collect_lanes(body.split_whitespace(), parse)
+    }
+}
+
+fn collect_lanes<'a, T, I, F, const N: usize>(
+    tokens: I,
+    parse: F,
+) -> Result<[T; N], ParseVectorError>
+where
+    T: Default + Copy,
+    I: Iterator<Item = &'a str>,
+    F: Fn(&str) -> Option<T>,
+{
+    let mut lanes = [T::default(); N];
+    let mut count = 0;
+
+    for token in tokens {
+        if let Some(lane) = lanes.get_mut(count) {
+            *lane = parse(token).ok_or(ParseVectorError::InvalidLane(count))?;
+        }
+        count += 1;
+    }
+
+    if count == N {
+        Ok(lanes)
+    } else {
+        Err(ParseVectorError::WrongLaneCount {
+            expected: N,
+            found: count,
+        })
+    }
+}
@@ -0,0 +1,178 @@
+//! Support for the WebAssembly [relaxed SIMD] proposal.
+//!
+//! With the `relaxed-simd` feature the vector types gain `relaxed_*` methods that map to the
+//! relaxed instructions. These are faster on most hardware because the engine may pick the
+//! native instruction's behavior for inputs where platforms disagree, so their results are
+//! only partially specified:
+//!
+//! - `relaxed_mul_add` / `relaxed_neg_mul_add` may or may not round the product before the
+//!   addition, so the last bit of a lane can differ between engines.
+//! - `relaxed_min` / `relaxed_max` may return either input when a lane is NaN, and either
+//!   zero when comparing `0.0` with `-0.0`.
+//! - `relaxed_to_i32_trunc` / `relaxed_to_u32_trunc` produce an unspecified value for NaN and
+//!   out-of-range lanes instead of saturating.
+//! - `relaxed_swizzle` produces an unspecified value for out-of-range lane indices.
+//! - `relaxed_select` is deterministic for `Bx4` masks, whose lanes are always all ones or
+//!   all zeros; it only differs from `select` for mixed bit patterns, which `Bx4` rules out.
+//!
+//! An engine picks one behavior and keeps it, but two engines (or the same engine on two
+//! CPUs) may disagree. Running the module requires an engine with relaxed SIMD support.
+//!
+//! The `relaxed-simd-default` feature additionally switches `F32x4::mul_add`, `if_else`,
+//! `select`, and the float to integer conversions (`From<F32x4>`, `to_i32_*`, `to_u32_*`)
+//! to the relaxed forms, so existing code picks them up without changes.
+//!
+//! [relaxed SIMD]: https://github.com/WebAssembly/relaxed-simd
+
+use crate::wasm_simd::arch::*;
+
+#[cfg(feature = "relaxed-simd")]
+use crate::wasm_simd::{F32x16, F32x4, F32x8, I32x16, I32x4, I32x8, U32x16, U32x4, U32x8};
+
+#[inline]
+pub(crate) fn lane_select(if_true: v128, if_false: v128, mask: v128) -> v128 {
+    #[cfg(feature = "relaxed-simd-default")]
+    {
+        i32x4_relaxed_laneselect(if_true, if_false, mask)
+    }
+    #[cfg(not(feature = "relaxed-simd-default"))]
+    {
+        v128_bitselect(if_true, if_false, mask)
+    }
+}
+
+#[inline]
+pub(crate) fn mul_add(a: v128, b: v128, c: v128) -> v128 {
+    #[cfg(feature = "relaxed-simd-default")]
+    {
+        f32x4_relaxed_madd(a, b, c)
+    }
+    #[cfg(not(feature = "relaxed-simd-default"))]
+    {
+        f32x4_add(f32x4_mul(a, b), c)
+    }
+}
+
+#[inline]
+pub(crate) fn trunc_i32(a: v128) -> v128 {
+    #[cfg(feature = "relaxed-simd-default")]
+    {
+        i32x4_relaxed_trunc_f32x4(a)
+    }
+    #[cfg(not(feature = "relaxed-simd-default"))]
+    {
+        i32x4_trunc_sat_f32x4(a)
+    }
+}
+
+#[inline]
+pub(crate) fn trunc_u32(a: v128) -> v128 {
+    #[cfg(feature = "relaxed-simd-default")]
+    {
+        u32x4_relaxed_trunc_f32x4(a)
+    }
+    #[cfg(not(feature = "relaxed-simd-default"))]
+    {
+        u32x4_trunc_sat_f32x4(a)
+    }
+}
+
+#[cfg(feature = "relaxed-simd")]
+impl F32x4 {
+    /// Computes `self * a + b` lane-wise, with or without rounding the product.
+    #[inline]
+    pub fn relaxed_mul_add(self, a: Self, b: Self) -> Self {
+        Self::from_v128(f32x4_relaxed_madd(self.to_v128(), a.to_v128(), b.to_v128()))
+    }
+
+    /// Computes `-(self * a) + b` lane-wise, with or without rounding the product.
+    #[inline]
+    pub fn relaxed_neg_mul_add(self, a: Self, b: Self) -> Self {
+        Self::from_v128(f32x4_relaxed_nmadd(
+            self.to_v128(),
+            a.to_v128(),
+            b.to_v128(),
+        ))
+    }
+
+    /// Lane-wise minimum. NaN lanes and `0.0` against `-0.0` give an unspecified input.
+    #[inline]
+    pub fn relaxed_min(&self, other: &Self) -> Self {
+        Self::from_v128(f32x4_relaxed_min(self.to_v128(), other.to_v128()))
+    }
+
+    /// Lane-wise maximum. NaN lanes and `0.0` against `-0.0` give an unspecified input.
+    #[inline]
+    pub fn relaxed_max(&self, other: &Self) -> Self {
+        Self::from_v128(f32x4_relaxed_max(self.to_v128(), other.to_v128()))
+    }
+
+    /// Truncates each lane to `i32`. NaN and out-of-range lanes give an unspecified value.
+    #[inline]
+    pub fn relaxed_to_i32_trunc(self) -> I32x4 {
+        I32x4::from_v128(i32x4_relaxed_trunc_f32x4(self.to_v128()))
+    }
+
+    /// Truncates each lane to `u32`. NaN and out-of-range lanes give an unspecified value.
+    #[inline]
+    pub fn relaxed_to_u32_trunc(self) -> U32x4 {
+        U32x4::from_v128(u32x4_relaxed_trunc_f32x4(self.to_v128()))
+    }
+}
+
+// The wide vectors apply the `F32x4` relaxed operations to each part.
+#[cfg(feature = "relaxed-simd")]
+macro_rules! impl_wide_relaxed {
+    ($struct_name:ident, $i32_type:ident, $u32_type:ident) => {
+        impl $struct_name {
+            /// Computes `self * a + b` lane-wise, with or without rounding the product.
+            #[inline]
+            pub fn relaxed_mul_add(self, a: Self, b: Self) -> Self {
+                let (parts, a, b) = (self.to_parts(), a.to_parts(), b.to_parts());
+                Self::from_parts(core::array::from_fn(|i| {
+                    parts[i].relaxed_mul_add(a[i], b[i])
+                }))
+            }
+
+            /// Computes `-(self * a) + b` lane-wise, with or without rounding the product.
+            #[inline]
+            pub fn relaxed_neg_mul_add(self, a: Self, b: Self) -> Self {
+                let (parts, a, b) = (self.to_parts(), a.to_parts(), b.to_parts());
+                Self::from_parts(core::array::from_fn(|i| {
+                    parts[i].relaxed_neg_mul_add(a[i], b[i])
+                }))
+            }
+
+            /// Lane-wise minimum. NaN lanes and `0.0` against `-0.0` give an unspecified input.
+            #[inline]
+            pub fn relaxed_min(&self, other: &Self) -> Self {
+                let (parts, other) = (self.to_parts(), other.to_parts());
+                Self::from_parts(core::array::from_fn(|i| parts[i].relaxed_min(&other[i])))
+            }
+
+            /// Lane-wise maximum. NaN lanes and `0.0` against `-0.0` give an unspecified input.
+            #[inline]
+            pub fn relaxed_max(&self, other: &Self) -> Self {
+                let (parts, other) = (self.to_parts(), other.to_parts());
+                Self::from_parts(core::array::from_fn(|i| parts[i].relaxed_max(&other[i])))
+            }
+
+            /// Truncates each lane to `i32`. NaN and out-of-range lanes give an unspecified value.
+            #[inline]
+            pub fn relaxed_to_i32_trunc(self) -> $i32_type {
+                $i32_type::from_parts(self.to_parts().map(F32x4::relaxed_to_i32_trunc))
+            }
+
+            /// Truncates each lane to `u32`. NaN and out-of-range lanes give an unspecified value.
+            #[inline]
+            pub fn relaxed_to_u32_trunc(self) -> $u32_type {
+                $u32_type::from_parts(self.to_parts().map(F32x4::relaxed_to_u32_trunc))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "relaxed-simd")]
+impl_wide_relaxed!(F32x8, I32x8, U32x8);
+#[cfg(feature = "relaxed-simd")]
+impl_wide_relaxed!(F32x16, I32x16, U32x16);
@@ -0,0 +1,190 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use crate::wasm_simd::{Bx4, F32x4, I32x4, U32x4};
+
+// Vectors are serialized as a fixed-length sequence of their four lanes, so `I32x4::new(1, 2,
+// 3, 4)` becomes `[1, 2, 3, 4]` in JSON.
+macro_rules! impl_vec_serde {
+    ($struct_name:ident, $type:ty) => {
+        impl Serialize for $struct_name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut tuple = serializer.serialize_tuple(4)?;
+                for lane in <[$type; 4]>::from(*self) {
+                    tuple.serialize_element(&lane)?;
+                }
+                tuple.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $struct_name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let lanes =
+                    deserializer.deserialize_tuple(4, LanesVisitor::<$type>(PhantomData))?;
+                Ok(Self::from(lanes))
+            }
+        }
+    };
+}
+
+impl_vec_serde!(I32x4, i32);
+impl_vec_serde!(U32x4, u32);
+impl_vec_serde!(F32x4, f32);
+impl_vec_serde!(Bx4, bool);
+
+struct LanesVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for LanesVisitor<T>
+where
+    T: Deserialize<'de> + Default + Copy,
+{
+    type Value = [T; 4];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of exactly 4 lanes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut lanes = [T::default(); 4];
+        for (i, lane) in lanes.iter_mut().enumerate() {
+            *lane = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+
+        let mut extra = 0;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            extra += 1;
+        }
+        if extra > 0 {
+            return Err(de::Error::invalid_length(4 + extra, &self));
+        }
+
+        Ok(lanes)
+    }
+}
+
+/// Compact representation for binary formats, storing a vector as its 16 little-endian
+/// bytes instead of a sequence of lanes. Use it with `#[serde(with = "...")]`:
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct State {
+///     #[serde(with = "wasm_simd::compact")]
+///     position: F32x4,
+/// }
+/// ```
+pub mod compact {
+    use super::*;
+
+    pub trait CompactBytes: Sized + private::Sealed {
+        #[doc(hidden)]
+        fn to_compact_bytes(&self) -> [u8; 16];
+        #[doc(hidden)]
+        fn from_compact_bytes(bytes: [u8; 16]) -> Option<Self>;
+    }
+
+    mod private {
+        pub trait Sealed {}
+    }
+
+    macro_rules! impl_compact_bytes {
+        ($($struct_name:ident),+) => {
+            $(
+                impl private::Sealed for $struct_name {}
+
+                impl CompactBytes for $struct_name {
+                    fn to_compact_bytes(&self) -> [u8; 16] {
+                        self.to_le_bytes()
+                    }
+
+                    fn from_compact_bytes(bytes: [u8; 16]) -> Option<Self> {
+                        Some(Self::from_le_bytes(bytes))
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_compact_bytes!(I32x4, U32x4, F32x4);
+
+    impl private::Sealed for Bx4 {}
+
+    impl CompactBytes for Bx4 {
+        fn to_compact_bytes(&self) -> [u8; 16] {
+            self.to_ne_bytes()
+        }
+
+        // Every lane must be all zeros or all ones to form a valid mask.
+        fn from_compact_bytes(bytes: [u8; 16]) -> Option<Self> {
+            let mut lanes = [false; 4];
+            for (lane, chunk) in lanes.iter_mut().zip(bytes.chunks_exact(4)) {
+                *lane = match chunk {
+                    [0, 0, 0, 0] => false,
+                    [0xff, 0xff, 0xff, 0xff] => true,
+                    _ => return None,
+                };
+            }
+            Some(Self::from(lanes))
+        }
+    }
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: CompactBytes,
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&value.to_compact_bytes())
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: CompactBytes,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    }
+
+    struct BytesVisitor<T>(PhantomData<T>);
+
+    impl<T: CompactBytes> BytesVisitor<T> {
+        fn parse_slice<E: de::Error>(&self, bytes: &[u8]) -> Result<T, E> {
+            let bytes: [u8; 16] = bytes
+                .try_into()
+                .map_err(|_| E::invalid_length(bytes.len(), self))?;
+            T::from_compact_bytes(bytes)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Bytes(&bytes), self))
+        }
+    }
+
+    impl<'de, T: CompactBytes> Visitor<'de> for BytesVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("16 bytes holding a vector")
+        }
+
+        fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<T, E> {
+            self.parse_slice(bytes)
+        }
+
+        // Formats without a native byte type, such as JSON, encode bytes as a sequence.
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+            let mut bytes = [0u8; 16];
+            let mut len = 0;
+            while let Some(byte) = seq.next_element::<u8>()? {
+                if let Some(slot) = bytes.get_mut(len) {
+                    *slot = byte;
+                }
+                len += 1;
+            }
+            if len != 16 {
+                return Err(de::Error::invalid_length(len, &self));
+            }
+            self.parse_slice(&bytes)
+        }
+    }
+}
@@ -0,0 +1,435 @@
+use crate::wasm_simd::macros::{impl_mask_op, impl_simd_fmt, impl_simd_op, impl_wide_lane_counts};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for i32 {}
+    impl Sealed for u32 {}
+    impl Sealed for f32 {}
+}
+
+/// A lane type of `Simd`: `i32`, `u32` or `f32`.
+pub trait SimdElement: Copy + PartialEq + sealed::Sealed {
+    #[doc(hidden)]
+    const ZERO: Self;
+    #[doc(hidden)]
+    const ONE: Self;
+}
+
+impl SimdElement for i32 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
+
+impl SimdElement for u32 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
+
+impl SimdElement for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+}
+
+/// The lane counts `Simd<Self, N>` supports, with the storage and lane-wise arithmetic for
+/// each. Four lanes fill one `v128`; wider vectors hold one 4-lane part per 128 bits.
+pub trait SupportedLanes<const N: usize>: SimdElement {
+    #[doc(hidden)]
+    type Repr: Copy;
+
+    #[doc(hidden)]
+    fn splat(value: Self) -> Self::Repr;
+    #[doc(hidden)]
+    fn from_array(lanes: [Self; N]) -> Self::Repr;
+    #[doc(hidden)]
+    fn to_array(repr: Self::Repr) -> [Self; N];
+
+    #[doc(hidden)]
+    fn add(a: Self::Repr, b: Self::Repr) -> Self::Repr;
+    #[doc(hidden)]
+    fn sub(a: Self::Repr, b: Self::Repr) -> Self::Repr;
+    #[doc(hidden)]
+    fn mul(a: Self::Repr, b: Self::Repr) -> Self::Repr;
+    #[doc(hidden)]
+    fn div(a: Self::Repr, b: Self::Repr) -> Self::Repr;
+
+    #[doc(hidden)]
+    fn all_eq(a: Self::Repr, b: Self::Repr) -> bool;
+    #[doc(hidden)]
+    fn select(mask: Mask<i32, N>, if_true: Self::Repr, if_false: Self::Repr) -> Self::Repr
+    where
+        i32: MaskElement<N>;
+}
+
+/// The lane types `Simd<Self, N>` implements `Neg` for: `i32` and `f32`.
+pub trait SignedLanes<const N: usize>: SupportedLanes<N> {
+    #[doc(hidden)]
+    fn neg(a: Self::Repr) -> Self::Repr;
+}
+
+/// The lane types `Simd<Self, N>` implements the shift operators for: `i32` and `u32`. Shift
+/// amounts are taken modulo 32, and `>>` is arithmetic for `i32` and logical for `u32`.
+pub trait IntegerLanes<const N: usize>: SupportedLanes<N> {
+    #[doc(hidden)]
+    fn shl(a: Self::Repr, amt: u32) -> Self::Repr;
+    #[doc(hidden)]
+    fn shr(a: Self::Repr, amt: u32) -> Self::Repr;
+}
+
+/// The lane type naming a `Mask`. As in `std::simd`, a mask is named after the signed integer
+/// of its lane width, so comparing `I32x4`, `U32x4` or `F32x4` vectors gives a `Mask<i32, 4>`.
+pub trait MaskElement<const N: usize>: SupportedLanes<N> {
+    #[doc(hidden)]
+    type MaskRepr: Copy;
+    #[doc(hidden)]
+    type Bitmask: Copy + Eq + Hash;
+
+    #[doc(hidden)]
+    fn mask_splat(value: bool) -> Self::MaskRepr;
+    #[doc(hidden)]
+    fn mask_from_array(lanes: [bool; N]) -> Self::MaskRepr;
+    #[doc(hidden)]
+    fn mask_to_array(repr: Self::MaskRepr) -> [bool; N];
+
+    #[doc(hidden)]
+    fn mask_and(a: Self::MaskRepr, b: Self::MaskRepr) -> Self::MaskRepr;
+    #[doc(hidden)]
+    fn mask_or(a: Self::MaskRepr, b: Self::MaskRepr) -> Self::MaskRepr;
+    #[doc(hidden)]
+    fn mask_xor(a: Self::MaskRepr, b: Self::MaskRepr) -> Self::MaskRepr;
+    #[doc(hidden)]
+    fn mask_not(a: Self::MaskRepr) -> Self::MaskRepr;
+
+    #[doc(hidden)]
+    fn mask_to_bitmask(repr: Self::MaskRepr) -> Self::Bitmask;
+}
+
+/// A vector of `N` lanes of type `T`. The named vector types are aliases of it, such as
+/// `I32x4 = Simd<i32, 4>` and `F32x8 = Simd<f32, 8>`.
+#[repr(transparent)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+pub struct Simd<T: SupportedLanes<N>, const N: usize>(pub(crate) T::Repr);
+
+/// A mask of `N` lanes selecting between `Simd` vectors of 32-bit lanes. The named mask types
+/// are aliases of it, such as `Bx4 = Mask<i32, 4>`.
+// Only the zerocopy traits that cannot produce a lane other than all ones or all zeros are
+// derived; `FromBytes` and `TryFromBytes` would accept arbitrary bit patterns.
+#[repr(transparent)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
+pub struct Mask<T: MaskElement<N>, const N: usize>(pub(crate) T::MaskRepr);
+
+impl<T: SupportedLanes<N>, const N: usize> Simd<T, N> {
+    pub const LANES: usize = N;
+
+    pub fn from_array(lanes: [T; N]) -> Self {
+        Self(T::from_array(lanes))
+    }
+
+    pub fn to_array(self) -> [T; N] {
+        T::to_array(self.0)
+    }
+
+    pub fn as_array(&self) -> &[T; N] {
+        // SAFETY: the vector is a transparent wrapper around 16-byte blocks holding the lanes
+        // in order, which has the layout of `[T; N]`.
+        unsafe { &*(self as *const Self as *const [T; N]) }
+    }
+}
+
+impl<T: SupportedLanes<N>, const N: usize> Clone for Simd<T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: SupportedLanes<N>, const N: usize> Copy for Simd<T, N> {}
+
+impl<T: SupportedLanes<N>, const N: usize> Default for Simd<T, N> {
+    fn default() -> Self {
+        Self(T::splat(T::ZERO))
+    }
+}
+
+impl<T: SupportedLanes<N>, const N: usize> From<[T; N]> for Simd<T, N> {
+    fn from(lanes: [T; N]) -> Self {
+        Self::from_array(lanes)
+    }
+}
+
+impl<T: SupportedLanes<N>, const N: usize> From<Simd<T, N>> for [T; N] {
+    fn from(val: Simd<T, N>) -> Self {
+        val.to_array()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: SupportedLanes<N>, const N: usize> From<Simd<T, N>> for alloc::vec::Vec<T> {
+    fn from(val: Simd<T, N>) -> Self {
+        val.to_array().to_vec()
+    }
+}
+
+impl<T: SupportedLanes<N>, const N: usize> core::ops::Index<usize> for Simd<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.as_array()[index]
+    }
+}
+
+impl<T: SupportedLanes<N>, const N: usize> IntoIterator for Simd<T, N> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+// Takes the first `N` elements, filling any missing lanes with zero.
+impl<T: SupportedLanes<N>, const N: usize> FromIterator<T> for Simd<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut lanes = [T::ZERO; N];
+        lanes.iter_mut().zip(iter).for_each(|(lane, v)| *lane = v);
+        Self::from_array(lanes)
+    }
+}
+
+impl<T: SupportedLanes<N>, const N: usize> core::iter::Sum for Simd<T, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(T::splat(T::ZERO)), |acc, v| acc + v)
+    }
+}
+
+impl<'a, T: SupportedLanes<N>, const N: usize> core::iter::Sum<&'a Self> for Simd<T, N> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self(T::splat(T::ZERO)), |acc, v| acc + *v)
+    }
+}
+
+impl<T: SupportedLanes<N>, const N: usize> core::iter::Product for Simd<T, N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(T::splat(T::ONE)), |acc, v| acc * v)
+    }
+}
+
+impl<'a, T: SupportedLanes<N>, const N: usize> core::iter::Product<&'a Self> for Simd<T, N> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self(T::splat(T::ONE)), |acc, v| acc * *v)
+    }
+}
+
+impl_simd_op!(Add, add, AddAssign, add_assign);
+impl_simd_op!(Sub, sub, SubAssign, sub_assign);
+impl_simd_op!(Mul, mul, MulAssign, mul_assign);
+impl_simd_op!(Div, div, DivAssign, div_assign);
+
+impl<T: SupportedLanes<N>, const N: usize> PartialEq for Simd<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        T::all_eq(self.0, other.0)
+    }
+}
+
+// `f32` lanes compare with IEEE 754 semantics, so `f32` vectors are neither `Eq` nor `Hash`.
+impl<T: SupportedLanes<N> + Eq, const N: usize> Eq for Simd<T, N> {}
+
+impl<T: SupportedLanes<N> + Hash, const N: usize> Hash for Simd<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_array().hash(state);
+    }
+}
+
+impl<T: SignedLanes<N>, const N: usize> core::ops::Neg for Simd<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(T::neg(self.0))
+    }
+}
+
+impl<T: IntegerLanes<N>, const N: usize> core::ops::Shl<u32> for Simd<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn shl(self, amt: u32) -> Self::Output {
+        Self(T::shl(self.0, amt))
+    }
+}
+
+impl<T: IntegerLanes<N>, const N: usize> core::ops::Shr<u32> for Simd<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn shr(self, amt: u32) -> Self::Output {
+        Self(T::shr(self.0, amt))
+    }
+}
+
+impl<T: IntegerLanes<N>, const N: usize> core::ops::ShlAssign<u32> for Simd<T, N> {
+    #[inline]
+    fn shl_assign(&mut self, amt: u32) {
+        self.0 = T::shl(self.0, amt);
+    }
+}
+
+impl<T: IntegerLanes<N>, const N: usize> core::ops::ShrAssign<u32> for Simd<T, N> {
+    #[inline]
+    fn shr_assign(&mut self, amt: u32) {
+        self.0 = T::shr(self.0, amt);
+    }
+}
+
+impl_simd_fmt!(Display, LowerHex, UpperHex, Binary, LowerExp);
+
+// Formats the lanes as `[v1, v2, v3, v4]`, passing the caller's format spec (width, fill,
+// precision, sign, `#`) through to every lane.
+pub(crate) fn fmt_lanes<T, const N: usize>(
+    lanes: [T; N],
+    f: &mut fmt::Formatter<'_>,
+    fmt_lane: fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    f.write_str("[")?;
+    for (i, lane) in lanes.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        fmt_lane(lane, f)?;
+    }
+    f.write_str("]")
+}
+
+// The `if_else` signatures differ by lane type, matching the 4-lane vectors they started on.
+impl<const N: usize> Simd<i32, N>
+where
+    i32: MaskElement<N>,
+{
+    pub fn if_else(&self, other: &Self, mask: &Mask<i32, N>) -> Self {
+        Self(<i32 as SupportedLanes<N>>::select(*mask, self.0, other.0))
+    }
+}
+
+impl<const N: usize> Simd<u32, N>
+where
+    u32: SupportedLanes<N>,
+    i32: MaskElement<N>,
+{
+    pub fn if_else(self, other: &Self, mask: Mask<i32, N>) -> Self {
+        Self(<u32 as SupportedLanes<N>>::select(mask, self.0, other.0))
+    }
+}
+
+impl<const N: usize> Simd<f32, N>
+where
+    f32: SupportedLanes<N>,
+    i32: MaskElement<N>,
+{
+    pub fn if_else(self, other: &Self, mask: Mask<i32, N>) -> Self {
+        Self(<f32 as SupportedLanes<N>>::select(mask, self.0, other.0))
+    }
+}
+
+impl<T: MaskElement<N>, const N: usize> Mask<T, N> {
+    pub const LANES: usize = N;
+
+    pub fn from_array(lanes: [bool; N]) -> Self {
+        Self(T::mask_from_array(lanes))
+    }
+
+    pub fn to_array(self) -> [bool; N] {
+        T::mask_to_array(self.0)
+    }
+}
+
+impl<T: MaskElement<N>, const N: usize> Clone for Mask<T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: MaskElement<N>, const N: usize> Copy for Mask<T, N> {}
+
+impl<T: MaskElement<N>, const N: usize> Default for Mask<T, N> {
+    fn default() -> Self {
+        Self(T::mask_splat(false))
+    }
+}
+
+impl<T: MaskElement<N>, const N: usize> From<[bool; N]> for Mask<T, N> {
+    fn from(lanes: [bool; N]) -> Self {
+        Self::from_array(lanes)
+    }
+}
+
+impl<T: MaskElement<N>, const N: usize> From<Mask<T, N>> for [bool; N] {
+    fn from(val: Mask<T, N>) -> Self {
+        val.to_array()
+    }
+}
+
+impl<T: MaskElement<N>, const N: usize> IntoIterator for Mask<T, N> {
+    type Item = bool;
+    type IntoIter = core::array::IntoIter<bool, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+// Takes the first `N` elements, filling any missing lanes with `false`.
+impl<T: MaskElement<N>, const N: usize> FromIterator<bool> for Mask<T, N> {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut lanes = [false; N];
+        lanes.iter_mut().zip(iter).for_each(|(lane, v)| *lane = v);
+        Self::from_array(lanes)
+    }
+}
+
+impl<T: MaskElement<N>, const N: usize> core::ops::Not for Mask<T, N> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(T::mask_not(self.0))
+    }
+}
+
+impl_mask_op!(BitAnd, bitand, BitAndAssign, bitand_assign, mask_and);
+impl_mask_op!(BitOr, bitor, BitOrAssign, bitor_assign, mask_or);
+impl_mask_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, mask_xor);
+
+impl<T: MaskElement<N>, const N: usize> PartialEq for Mask<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        T::mask_to_bitmask(self.0) == T::mask_to_bitmask(other.0)
+    }
+}
+
+impl<T: MaskElement<N>, const N: usize> Eq for Mask<T, N> {}
+
+impl<T: MaskElement<N>, const N: usize> Hash for Mask<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        T::mask_to_bitmask(self.0).hash(state);
+    }
+}
+
+impl<T: MaskElement<N>, const N: usize> fmt::Display for Mask<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_lanes(self.to_array(), f, fmt::Display::fmt)
+    }
+}
+
+// Every width that is a multiple of 128 bits gets its storage and operators from the 4-lane
+// parts; adding a width here is enough for `Simd` and `Mask` to support it.
+impl_wide_lane_counts!(8, 2, u8);
+impl_wide_lane_counts!(16, 4, u16);
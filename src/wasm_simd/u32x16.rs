@@ -0,0 +1,16 @@
+use crate::wasm_simd::bx16::Bx16;
+use crate::wasm_simd::f32x16::F32x16;
+use crate::wasm_simd::i32x16::I32x16;
+use crate::wasm_simd::macros::define_wide_type;
+use crate::wasm_simd::u32x4::U32x4;
+
+define_wide_type! {
+    /// A 16-lane `u32` vector made of four `U32x4`, for kernels written for 512-bit vectors.
+    U32x16: u32 {
+        part: U32x4,
+        parts: 4,
+        lanes: 16,
+        mask: Bx16,
+        vectors: [I32x16, U32x16, F32x16],
+    }
+}
@@ -0,0 +1,16 @@
+use crate::wasm_simd::bx8::Bx8;
+use crate::wasm_simd::f32x8::F32x8;
+use crate::wasm_simd::i32x8::I32x8;
+use crate::wasm_simd::macros::define_wide_type;
+use crate::wasm_simd::u32x4::U32x4;
+
+define_wide_type! {
+    /// An 8-lane `u32` vector made of two `U32x4`, for kernels written for 256-bit vectors.
+    U32x8: u32 {
+        part: U32x4,
+        parts: 2,
+        lanes: 8,
+        mask: Bx8,
+        vectors: [I32x8, U32x8, F32x8],
+    }
+}
@@ -0,0 +1,390 @@
+//! Checks the selected backend against the scalar reference bit for bit, so the native
+//! backends give the same answers as WebAssembly for edge cases.
+
+mod reference;
+
+use reference::*;
+use wasm_bindgen_test::*;
+use wasm_simd::{Bx4, F32x4, I32x4, U32x4};
+
+const I32_SPECIALS: [i32; 16] = [
+    0,
+    1,
+    -1,
+    2,
+    -2,
+    3,
+    4,
+    31,
+    32,
+    0x7fff,
+    -0x8000,
+    0x1_0000,
+    i32::MIN,
+    i32::MIN + 1,
+    i32::MAX,
+    i32::MAX - 1,
+];
+
+const F32_SPECIALS: [u32; 30] = [
+    0x0000_0000, // 0.0
+    0x8000_0000, // -0.0
+    0x3f80_0000, // 1.0
+    0xbf80_0000, // -1.0
+    0x3f00_0000, // 0.5
+    0xbf00_0000, // -0.5
+    0x3fc0_0000, // 1.5
+    0x4020_0000, // 2.5
+    0xc020_0000, // -2.5
+    0x3f7f_ffff, // largest value below 1.0
+    0x4afe_0000, // 8323072.0
+    0x4aff_ffff, // 8388607.5
+    0x4b00_0000, // 2^23
+    0xcb00_0001, // -(2^23 + 1)
+    0x4eff_ffff, // largest value below 2^31
+    0x4f00_0000, // 2^31
+    0xcf00_0000, // -2^31
+    0xcf00_0001, // just below -2^31
+    0x4f7f_ffff, // largest value below 2^32
+    0x4f80_0000, // 2^32
+    0x0080_0000, // f32::MIN_POSITIVE
+    0x0000_0001, // smallest subnormal
+    0x807f_ffff, // largest negative subnormal
+    0x7f7f_ffff, // f32::MAX
+    0xff7f_ffff, // f32::MIN
+    0x7f80_0000, // inf
+    0xff80_0000, // -inf
+    0x7fc0_0000, // NaN
+    0xffc0_1234, // negative NaN with payload
+    0x7f80_0001, // signaling NaN
+];
+
+/// Small deterministic generator so failures are reproducible.
+struct XorShift(u32);
+
+impl XorShift {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+}
+
+/// Yields every pair of special values in lane 0 (and rotated pairs in the other lanes),
+/// followed by random bit patterns.
+fn lane_pairs(specials: &[u32]) -> Vec<([u32; 4], [u32; 4])> {
+    let n = specials.len();
+    let lanes = |start: usize| core::array::from_fn(|lane| specials[(start + lane) % n]);
+    let mut pairs: Vec<_> = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .map(|(i, j)| (lanes(i), lanes(j)))
+        .collect();
+
+    let mut rng = XorShift(0x1234_5678);
+    pairs.extend((0..2000).map(|_| {
+        (
+            core::array::from_fn(|_| rng.next()),
+            core::array::from_fn(|_| rng.next()),
+        )
+    }));
+    pairs
+}
+
+fn i32_pairs() -> Vec<([i32; 4], [i32; 4])> {
+    let specials = I32_SPECIALS.map(|x| x as u32);
+    lane_pairs(&specials)
+        .into_iter()
+        .map(|(a, b)| (a.map(|x| x as i32), b.map(|x| x as i32)))
+        .collect()
+}
+
+fn f32_pairs() -> Vec<([f32; 4], [f32; 4])> {
+    lane_pairs(&F32_SPECIALS)
+        .into_iter()
+        .map(|(a, b)| (a.map(f32::from_bits), b.map(f32::from_bits)))
+        .collect()
+}
+
+fn assert_f32_bits(actual: F32x4, expected: [f32; 4], message: &str) {
+    let actual: [f32; 4] = actual.into();
+    assert_eq!(
+        actual.map(f32::to_bits),
+        expected.map(f32::to_bits),
+        "{message}"
+    );
+}
+
+fn assert_mask(actual: Bx4, expected: [bool; 4], message: &str) {
+    let expected = expected.map(mask);
+    assert_eq!(<[i32; 4]>::from(actual.to_i32x4()), expected, "{message}");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_i32x4_arithmetic_matches_reference() {
+    for (a, b) in i32_pairs() {
+        let (va, vb) = (I32x4::from(a), I32x4::from(b));
+        let msg = format!("a = {a:?}, b = {b:?}");
+        assert_eq!(
+            <[i32; 4]>::from(va + vb),
+            zip(a, b, i32::wrapping_add),
+            "add {msg}"
+        );
+        assert_eq!(
+            <[i32; 4]>::from(va - vb),
+            zip(a, b, i32::wrapping_sub),
+            "sub {msg}"
+        );
+        assert_eq!(
+            <[i32; 4]>::from(va * vb),
+            zip(a, b, i32::wrapping_mul),
+            "mul {msg}"
+        );
+        assert_eq!(
+            <[i32; 4]>::from(-va),
+            map(a, i32::wrapping_neg),
+            "neg {msg}"
+        );
+        assert_eq!(
+            <[i32; 4]>::from(va.abs()),
+            map(a, i32::wrapping_abs),
+            "abs {msg}"
+        );
+        assert_eq!(
+            <[i32; 4]>::from(va.min(&vb)),
+            zip(a, b, i32::min),
+            "min {msg}"
+        );
+        assert_eq!(
+            <[i32; 4]>::from(va.max(&vb)),
+            zip(a, b, i32::max),
+            "max {msg}"
+        );
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_u32x4_arithmetic_matches_reference() {
+    for (a, b) in i32_pairs() {
+        let (a, b) = (a.map(|x| x as u32), b.map(|x| x as u32));
+        let (va, vb) = (U32x4::from(a), U32x4::from(b));
+        let msg = format!("a = {a:?}, b = {b:?}");
+        assert_eq!(
+            <[u32; 4]>::from(va + vb),
+            zip(a, b, u32::wrapping_add),
+            "add {msg}"
+        );
+        assert_eq!(
+            <[u32; 4]>::from(va - vb),
+            zip(a, b, u32::wrapping_sub),
+            "sub {msg}"
+        );
+        assert_eq!(
+            <[u32; 4]>::from(va * vb),
+            zip(a, b, u32::wrapping_mul),
+            "mul {msg}"
+        );
+        assert_eq!(
+            <[u32; 4]>::from(va.min(&vb)),
+            zip(a, b, u32::min),
+            "min {msg}"
+        );
+        assert_eq!(
+            <[u32; 4]>::from(va.max(&vb)),
+            zip(a, b, u32::max),
+            "max {msg}"
+        );
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_shifts_match_reference() {
+    for (a, _) in i32_pairs().into_iter().take(200) {
+        for amt in [0, 1, 7, 31, 32, 33, 63, u32::MAX] {
+            let msg = format!("a = {a:?}, amt = {amt}");
+            let va = I32x4::from(a);
+            let vu = U32x4::from(a.map(|x| x as u32));
+            assert_eq!(
+                <[i32; 4]>::from(va << amt),
+                map(a, |x| x.wrapping_shl(amt)),
+                "i32 shl {msg}"
+            );
+            assert_eq!(
+                <[i32; 4]>::from(va >> amt),
+                map(a, |x| x.wrapping_shr(amt)),
+                "i32 shr {msg}"
+            );
+            assert_eq!(
+                <[u32; 4]>::from(vu >> amt),
+                map(a, |x| (x as u32).wrapping_shr(amt)),
+                "u32 shr {msg}"
+            );
+        }
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_integer_comparisons_match_reference() {
+    for (a, b) in i32_pairs() {
+        let (va, vb) = (I32x4::from(a), I32x4::from(b));
+        let (ua, ub) = (a.map(|x| x as u32), b.map(|x| x as u32));
+        let (vua, vub) = (U32x4::from(ua), U32x4::from(ub));
+        let msg = format!("a = {a:?}, b = {b:?}");
+        assert_mask(va.eq(&vb), zip(a, b, |x, y| x == y), &format!("eq {msg}"));
+        assert_mask(va.ne(&vb), zip(a, b, |x, y| x != y), &format!("ne {msg}"));
+        assert_mask(va.lt(&vb), zip(a, b, |x, y| x < y), &format!("lt {msg}"));
+        assert_mask(va.le(&vb), zip(a, b, |x, y| x <= y), &format!("le {msg}"));
+        assert_mask(va.gt(&vb), zip(a, b, |x, y| x > y), &format!("gt {msg}"));
+        assert_mask(va.ge(&vb), zip(a, b, |x, y| x >= y), &format!("ge {msg}"));
+        assert_mask(
+            vua.lt(&vub),
+            zip(ua, ub, |x, y| x < y),
+            &format!("u lt {msg}"),
+        );
+        assert_mask(
+            vua.le(&vub),
+            zip(ua, ub, |x, y| x <= y),
+            &format!("u le {msg}"),
+        );
+        assert_mask(
+            vua.gt(&vub),
+            zip(ua, ub, |x, y| x > y),
+            &format!("u gt {msg}"),
+        );
+        assert_mask(
+            vua.ge(&vub),
+            zip(ua, ub, |x, y| x >= y),
+            &format!("u ge {msg}"),
+        );
+
+        let lt = zip(a, b, |x, y| x < y);
+        let m = va.lt(&vb);
+        assert_eq!(
+            m.to_bitmask(),
+            lt.iter().rev().fold(0, |acc, &x| (acc << 1) | x as u8),
+            "bitmask {msg}"
+        );
+        assert_eq!(m.any(), lt.iter().any(|&x| x), "any {msg}");
+        assert_eq!(m.all(), lt.iter().all(|&x| x), "all {msg}");
+        assert_eq!(
+            <[i32; 4]>::from(I32x4::select(&m, &va, &vb)),
+            zip(a, b, i32::min),
+            "select {msg}"
+        );
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_swizzle_matches_reference() {
+    let mut rng = XorShift(0x9e37_79b9);
+    let mut index_sets: Vec<[i32; 4]> = vec![[3, 2, 1, 0], [0, 0, 0, 0], [4, -1, 3, 16]];
+    index_sets.extend((0..500).map(|_| core::array::from_fn(|_| (rng.next() % 9) as i32 - 2)));
+    index_sets.extend((0..100).map(|_| core::array::from_fn(|_| rng.next() as i32)));
+
+    let a = [0x0403_0201, -0x0807_0605, i32::MIN, i32::MAX];
+    for indices in index_sets {
+        assert_eq!(
+            <[i32; 4]>::from(I32x4::from(a).swizzle(&I32x4::from(indices))),
+            swizzle(a, indices),
+            "swizzle indices = {indices:?}"
+        );
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_f32x4_arithmetic_matches_reference() {
+    for (a, b) in f32_pairs() {
+        let (va, vb) = (F32x4::from(a), F32x4::from(b));
+        let msg = format!(
+            "a = {:x?}, b = {:x?}",
+            a.map(f32::to_bits),
+            b.map(f32::to_bits)
+        );
+        assert_f32_bits(va + vb, zip(a, b, |x, y| x + y), &format!("add {msg}"));
+        assert_f32_bits(va - vb, zip(a, b, |x, y| x - y), &format!("sub {msg}"));
+        assert_f32_bits(va * vb, zip(a, b, |x, y| x * y), &format!("mul {msg}"));
+        assert_f32_bits(va / vb, zip(a, b, |x, y| x / y), &format!("div {msg}"));
+        assert_f32_bits(va.min(&vb), zip(a, b, f32_min), &format!("min {msg}"));
+        assert_f32_bits(va.max(&vb), zip(a, b, f32_max), &format!("max {msg}"));
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_f32x4_unary_matches_reference() {
+    for (a, _) in f32_pairs() {
+        let va = F32x4::from(a);
+        let msg = format!("a = {:x?}", a.map(f32::to_bits));
+        assert_f32_bits(-va, map(a, |x| -x), &format!("neg {msg}"));
+        assert_f32_bits(va.abs(), map(a, f32::abs), &format!("abs {msg}"));
+        assert_f32_bits(va.floor(), map(a, f32_floor), &format!("floor {msg}"));
+        assert_f32_bits(va.ceil(), map(a, f32_ceil), &format!("ceil {msg}"));
+        #[cfg(feature = "num-traits")]
+        {
+            use num_traits::Float;
+            assert_f32_bits(Float::sqrt(va), map(a, f32::sqrt), &format!("sqrt {msg}"));
+            assert_f32_bits(Float::trunc(va), map(a, f32_trunc), &format!("trunc {msg}"));
+        }
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_f32x4_comparisons_match_reference() {
+    for (a, b) in f32_pairs() {
+        let (va, vb) = (F32x4::from(a), F32x4::from(b));
+        let msg = format!(
+            "a = {:x?}, b = {:x?}",
+            a.map(f32::to_bits),
+            b.map(f32::to_bits)
+        );
+        assert_mask(va.eq(&vb), zip(a, b, |x, y| x == y), &format!("eq {msg}"));
+        assert_mask(va.ne(&vb), zip(a, b, |x, y| x != y), &format!("ne {msg}"));
+        assert_mask(va.lt(&vb), zip(a, b, |x, y| x < y), &format!("lt {msg}"));
+        assert_mask(va.le(&vb), zip(a, b, |x, y| x <= y), &format!("le {msg}"));
+        assert_mask(va.gt(&vb), zip(a, b, |x, y| x > y), &format!("gt {msg}"));
+        assert_mask(va.ge(&vb), zip(a, b, |x, y| x >= y), &format!("ge {msg}"));
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_conversions_match_reference() {
+    for (a, b) in f32_pairs() {
+        let va = F32x4::from(a);
+        let msg = format!("a = {:x?}", a.map(f32::to_bits));
+        let bits = b.map(f32::to_bits);
+        assert_f32_bits(
+            F32x4::from(I32x4::from(bits.map(|x| x as i32))),
+            map(bits, |x| x as i32 as f32),
+            &format!("from i32 {bits:x?}"),
+        );
+        assert_f32_bits(
+            F32x4::from(U32x4::from(bits)),
+            map(bits, |x| x as f32),
+            &format!("from u32 {bits:x?}"),
+        );
+
+        // The saturating conversions are only specified without relaxed-simd-default
+        if cfg!(feature = "relaxed-simd-default") {
+            continue;
+        }
+        assert_eq!(
+            <[i32; 4]>::from(I32x4::from(va)),
+            map(a, i32_trunc_sat),
+            "to i32 {msg}"
+        );
+        assert_eq!(
+            <[u32; 4]>::from(U32x4::from(va)),
+            map(a, u32_trunc_sat),
+            "to u32 {msg}"
+        );
+        assert_eq!(
+            <[i32; 4]>::from(va.to_i32_round()),
+            map(a, |x| i32_trunc_sat(f32_nearest(x))),
+            "to i32 round {msg}"
+        );
+        assert_eq!(
+            <[u32; 4]>::from(va.to_u32_floor()),
+            map(a, |x| u32_trunc_sat(f32_floor(x))),
+            "to u32 floor {msg}"
+        );
+    }
+}
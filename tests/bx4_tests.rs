@@ -1,13 +1,13 @@
+#![allow(clippy::bool_assert_comparison, clippy::clone_on_copy)]
+
 use wasm_bindgen_test::*;
 use wasm_simd::Bx4;
 
 fn assert_bx4_eq(a: Bx4, b: Bx4, msg: &str) {
-    let lanes_a = a.extract_lanes();
-    let lanes_b = b.extract_lanes();
-    assert_eq!(lanes_a, lanes_b, "{}", msg);
+    assert_eq!(a, b, "{}", msg);
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_new_and_extract_lanes() {
     let v = Bx4::new(true, false, true, false);
     assert_eq!(
@@ -31,7 +31,7 @@ fn test_new_and_extract_lanes() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_splat() {
     let v_true = Bx4::splat(true);
     assert_eq!(
@@ -48,7 +48,7 @@ fn test_splat() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_extract_lane() {
     let v = Bx4::new(true, false, true, false);
     assert_eq!(v.extract_lane(0), true, "Extract_lane(0) failed");
@@ -57,14 +57,14 @@ fn test_extract_lane() {
     assert_eq!(v.extract_lane(3), false, "Extract_lane(3) failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 #[should_panic(expected = "Index out of bounds for Bx4")]
 fn test_extract_lane_panic() {
     let v = Bx4::splat(false);
     v.extract_lane(4); // Index out of bounds
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_set_lane() {
     let mut v = Bx4::new(false, false, false, false);
     v.set_lane(0, true);
@@ -87,14 +87,45 @@ fn test_set_lane() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 #[should_panic(expected = "Index out of bounds for Bx4")]
 fn test_set_lane_panic() {
     let mut v = Bx4::splat(true);
     v.set_lane(4, false); // Index out of bounds
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_const_and_checked_lane_access() {
+    let mut v = Bx4::new(true, false, true, false);
+    assert!(v.extract::<0>(), "extract::<0> failed");
+    assert!(!v.extract::<1>(), "extract::<1> failed");
+    assert_bx4_eq(
+        v.with_lane::<1>(true),
+        Bx4::new(true, true, true, false),
+        "with_lane failed",
+    );
+
+    v.set::<3>(true);
+    assert_bx4_eq(v, Bx4::new(true, false, true, true), "set failed");
+
+    assert_eq!(v.get(3), Some(true), "get in bounds failed");
+    assert_eq!(v.get(7), None, "get out of bounds failed");
+    assert!(
+        v.try_set_lane(0, false).is_ok(),
+        "try_set_lane in bounds failed"
+    );
+    assert!(
+        v.try_set_lane(4, false).is_err(),
+        "try_set_lane out of bounds failed"
+    );
+    assert_bx4_eq(
+        v,
+        Bx4::new(false, false, true, true),
+        "try_set_lane result mismatch",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
 fn test_to_bitmask() {
     assert_eq!(
         Bx4::new(false, false, false, false).to_bitmask(),
@@ -178,7 +209,129 @@ fn test_to_bitmask() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_to_integer_masks() {
+    let v = Bx4::new(true, false, false, true);
+    assert_eq!(
+        v.to_i32x4().extract_lanes(),
+        (-1, 0, 0, -1),
+        "to_i32x4 failed"
+    );
+    assert_eq!(
+        v.to_u32x4_ones().extract_lanes(),
+        (1, 0, 0, 1),
+        "to_u32x4_ones failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_from_bitmask() {
+    for bitmask in 0u8..16 {
+        assert_eq!(
+            Bx4::from_bitmask(bitmask).to_bitmask(),
+            bitmask,
+            "from_bitmask round trip failed"
+        );
+    }
+    assert_bx4_eq(
+        Bx4::from_bitmask(0b1111_0101),
+        Bx4::new(true, false, true, false),
+        "from_bitmask should ignore the upper bits",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_any_all_none() {
+    let some = Bx4::new(false, true, false, false);
+    assert!(some.any(), "any failed");
+    assert!(!some.all(), "all failed");
+    assert!(!some.none(), "none failed");
+
+    assert!(Bx4::splat(true).all(), "all (all true) failed");
+    assert!(Bx4::splat(false).none(), "none (all false) failed");
+    assert!(!Bx4::splat(false).any(), "any (all false) failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_count_and_first_last_true() {
+    let v = Bx4::new(false, true, false, true);
+    assert_eq!(v.count_true(), 2, "count_true failed");
+    assert_eq!(v.first_true(), Some(1), "first_true failed");
+    assert_eq!(v.last_true(), Some(3), "last_true failed");
+
+    let empty = Bx4::splat(false);
+    assert_eq!(empty.count_true(), 0, "count_true (empty) failed");
+    assert_eq!(empty.first_true(), None, "first_true (empty) failed");
+    assert_eq!(empty.last_true(), None, "last_true (empty) failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_true_lanes() {
+    let v = Bx4::new(true, false, true, true);
+    let lanes: Vec<usize> = v.true_lanes().collect();
+    assert_eq!(lanes, vec![0, 2, 3], "true_lanes failed");
+
+    let reversed: Vec<usize> = v.true_lanes().rev().collect();
+    assert_eq!(reversed, vec![3, 2, 0], "true_lanes rev failed");
+
+    assert_eq!(v.true_lanes().len(), 3, "true_lanes len failed");
+    assert_eq!(
+        Bx4::splat(false).true_lanes().next(),
+        None,
+        "true_lanes (empty) failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_array_conversions_and_eq() {
+    let v = Bx4::from([true, false, false, true]);
+    assert_eq!(
+        v,
+        Bx4::new(true, false, false, true),
+        "From<[bool; 4]> failed"
+    );
+    assert_ne!(v, Bx4::splat(true), "PartialEq failed");
+
+    let arr: [bool; 4] = v.into();
+    assert_eq!(arr, [true, false, false, true], "Into<[bool; 4]> failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_shuffle() {
+    let a = Bx4::new(true, false, false, false);
+    let b = Bx4::new(false, false, false, true);
+    assert_bx4_eq(
+        a.shuffle::<1, 0, 7, 4>(&b),
+        Bx4::new(false, true, true, false),
+        "shuffle failed",
+    );
+    assert_bx4_eq(
+        a.reverse(),
+        Bx4::new(false, false, false, true),
+        "reverse failed",
+    );
+    assert_bx4_eq(
+        a.rotate_lanes_right::<2>(),
+        Bx4::new(false, false, true, false),
+        "rotate_lanes_right failed",
+    );
+    assert_bx4_eq(
+        a.broadcast_lane::<0>(),
+        Bx4::splat(true),
+        "broadcast_lane failed",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_iterators() {
+    let v: Bx4 = [true, false, true].into_iter().collect();
+    assert_bx4_eq(v, Bx4::new(true, false, true, false), "FromIterator failed");
+
+    let lanes: Vec<bool> = v.into_iter().collect();
+    assert_eq!(lanes, vec![true, false, true, false], "IntoIterator failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
 fn test_default() {
     let v_default = Bx4::default();
     assert_eq!(
@@ -188,7 +341,7 @@ fn test_default() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_clone_and_copy() {
     let v1 = Bx4::new(true, false, true, false);
     let v2 = v1.clone();
@@ -210,7 +363,7 @@ fn test_clone_and_copy() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_bit_and() {
     let a = Bx4::new(true, true, false, false);
     let b = Bx4::new(true, false, true, false);
@@ -241,7 +394,7 @@ fn test_bit_and() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_bit_and_assign() {
     let mut a = Bx4::new(true, true, false, false);
     let b = Bx4::new(true, false, true, false);
@@ -269,7 +422,7 @@ fn test_bit_and_assign() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_bit_or() {
     let a = Bx4::new(true, true, false, false);
     let b = Bx4::new(true, false, true, false);
@@ -300,7 +453,7 @@ fn test_bit_or() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_bit_or_assign() {
     let mut a = Bx4::new(true, true, false, false);
     let b = Bx4::new(true, false, true, false);
@@ -328,7 +481,7 @@ fn test_bit_or_assign() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_bit_xor() {
     let a = Bx4::new(true, true, false, false);
     let b = Bx4::new(true, false, true, false);
@@ -359,7 +512,7 @@ fn test_bit_xor() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_bit_xor_assign() {
     let mut a = Bx4::new(true, true, false, false);
     let b = Bx4::new(true, false, true, false);
@@ -387,7 +540,7 @@ fn test_bit_xor_assign() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_not() {
     let a = Bx4::new(true, false, true, false);
     assert_bx4_eq(!a, Bx4::new(false, true, false, true), "Not failed");
@@ -399,7 +552,7 @@ fn test_not() {
     assert_bx4_eq(!c, Bx4::splat(true), "Not all false failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_debug_format() {
     let v = Bx4::new(true, false, true, false);
     let formatted = format!("{:?}", v);
@@ -415,3 +568,37 @@ fn test_debug_format() {
         "Debug format all true incorrect"
     );
 }
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_display_format() {
+    let v = Bx4::new(true, false, true, false);
+    assert_eq!(
+        format!("{}", v),
+        "[true, false, true, false]",
+        "Display format incorrect"
+    );
+    assert_eq!(
+        format!("{:>5}", v),
+        "[ true, false,  true, false]",
+        "Display width incorrect"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_from_str() {
+    let expected = Bx4::new(true, false, true, false);
+    assert_eq!(
+        "Bx4(true, false, true, false)".parse::<Bx4>(),
+        Ok(expected),
+        "Debug format parse failed"
+    );
+    assert_eq!(
+        "true false true false".parse::<Bx4>(),
+        Ok(expected),
+        "space separated parse failed"
+    );
+    assert!(
+        "[true, 1, true, false]".parse::<Bx4>().is_err(),
+        "bad lane accepted"
+    );
+}
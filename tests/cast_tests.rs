@@ -0,0 +1,92 @@
+#![cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+
+use wasm_bindgen_test::*;
+use wasm_simd::{Bx4, F32x4, I32x4};
+
+#[repr(C, align(16))]
+struct Aligned([u8; 48]);
+
+fn aligned_bytes() -> Aligned {
+    let mut bytes = [0u8; 48];
+    for (i, chunk) in bytes.chunks_exact_mut(4).enumerate() {
+        chunk.copy_from_slice(&(i as f32).to_ne_bytes());
+    }
+    Aligned(bytes)
+}
+
+#[cfg(feature = "bytemuck")]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_bytemuck_cast_slice() {
+    let bytes = aligned_bytes();
+    let vectors: &[F32x4] = bytemuck::try_cast_slice(&bytes.0).unwrap();
+    assert_eq!(vectors.len(), 3, "cast_slice length mismatch");
+    assert_eq!(
+        vectors[1].extract_lanes(),
+        (4.0, 5.0, 6.0, 7.0),
+        "cast_slice lanes mismatch"
+    );
+
+    let back: &[u8] = bytemuck::cast_slice(vectors);
+    assert_eq!(back, &bytes.0[..], "cast_slice round trip failed");
+}
+
+#[cfg(feature = "bytemuck")]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_bytemuck_checks_alignment_and_length() {
+    let bytes = aligned_bytes();
+    assert!(
+        bytemuck::try_cast_slice::<u8, I32x4>(&bytes.0[4..36]).is_err(),
+        "misaligned cast should fail"
+    );
+    assert!(
+        bytemuck::try_cast_slice::<u8, I32x4>(&bytes.0[..40]).is_err(),
+        "cast with trailing bytes should fail"
+    );
+}
+
+#[cfg(feature = "bytemuck")]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_bytemuck_checked_bx4() {
+    let valid: [i32; 4] = [-1, 0, 0, -1];
+    let mask: Bx4 = bytemuck::checked::cast(valid);
+    assert_eq!(
+        mask.extract_lanes(),
+        (true, false, false, true),
+        "checked cast to Bx4 failed"
+    );
+
+    let invalid: [i32; 4] = [1, 0, 0, -1];
+    assert!(
+        bytemuck::checked::try_cast::<[i32; 4], Bx4>(invalid).is_err(),
+        "checked cast should reject partial mask lanes"
+    );
+    assert_eq!(
+        bytemuck::cast::<Bx4, [i32; 4]>(mask),
+        valid,
+        "cast from Bx4 failed"
+    );
+}
+
+#[cfg(feature = "zerocopy")]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_zerocopy_ref_from_bytes() {
+    use zerocopy::{FromBytes, IntoBytes};
+
+    let bytes = aligned_bytes();
+    let vectors = <[F32x4]>::ref_from_bytes(&bytes.0).unwrap();
+    assert_eq!(vectors.len(), 3, "ref_from_bytes length mismatch");
+    assert_eq!(
+        vectors[2].extract_lanes(),
+        (8.0, 9.0, 10.0, 11.0),
+        "ref_from_bytes lanes mismatch"
+    );
+    assert!(
+        <[F32x4]>::ref_from_bytes(&bytes.0[4..36]).is_err(),
+        "misaligned ref_from_bytes should fail"
+    );
+    assert_eq!(
+        Bx4::splat(true).as_bytes(),
+        &[255u8; 16][..],
+        "Bx4 as_bytes failed"
+    );
+}
@@ -0,0 +1,73 @@
+//! One exported function per operation under test. Arguments and results go through
+//! references so every export has the same C ABI shape regardless of the vector type.
+
+use wasm_simd::prelude::*;
+
+macro_rules! export {
+    ($(fn $name:ident($($arg:ident: $ty:ty),*) -> $out:ty $body:block)*) => {$(
+        #[no_mangle]
+        pub extern "C" fn $name($($arg: &$ty,)* out: &mut $out) {
+            $(let $arg = *$arg;)*
+            *out = $body;
+        }
+    )*};
+}
+
+export! {
+    fn i32x4_add(a: I32x4, b: I32x4) -> I32x4 { a + b }
+    fn i32x4_sub(a: I32x4, b: I32x4) -> I32x4 { a - b }
+    fn i32x4_mul(a: I32x4, b: I32x4) -> I32x4 { a * b }
+    fn i32x4_add_scalar(a: I32x4, s: i32) -> I32x4 { a + s }
+    fn i32x4_neg(a: I32x4) -> I32x4 { -a }
+    fn i32x4_abs(a: I32x4) -> I32x4 { a.abs() }
+    fn i32x4_min(a: I32x4, b: I32x4) -> I32x4 { a.min(&b) }
+    fn i32x4_max(a: I32x4, b: I32x4) -> I32x4 { a.max(&b) }
+    fn i32x4_shl(a: I32x4, amt: u32) -> I32x4 { a << amt }
+    fn i32x4_shr(a: I32x4, amt: u32) -> I32x4 { a >> amt }
+    fn i32x4_eq(a: I32x4, b: I32x4) -> Bx4 { a.eq(&b) }
+    fn i32x4_lt(a: I32x4, b: I32x4) -> Bx4 { a.lt(&b) }
+    fn i32x4_select(m: Bx4, a: I32x4, b: I32x4) -> I32x4 { I32x4::select(&m, &a, &b) }
+    fn i32x4_masked_add(a: I32x4, m: Bx4, b: I32x4) -> I32x4 { a.masked_add(&m, &b) }
+    fn i32x4_swizzle(a: I32x4, indices: I32x4) -> I32x4 { a.swizzle(&indices) }
+    fn i32x4_shuffle(a: I32x4, b: I32x4) -> I32x4 { a.shuffle::<0, 5, 2, 7>(&b) }
+    fn i32x4_reverse(a: I32x4) -> I32x4 { a.reverse() }
+    fn i32x4_interleave_low(a: I32x4, b: I32x4) -> I32x4 { a.interleave_low(&b) }
+    fn i32x4_to_f32(a: I32x4) -> F32x4 { F32x4::from(a) }
+    fn i32x4_div(a: I32x4, b: I32x4) -> I32x4 { a / b }
+    fn i32x4_apply(a: I32x4, d: i32) -> I32x4 { a.apply(|x| x / d) }
+    fn i32x4_fold(a: I32x4) -> i32 { a.fold(|acc, x| acc ^ x, 0) }
+    fn i32x4_reduce_add(a: I32x4) -> i32 { a.reduce_add() }
+    fn i32x4_reduce_min(a: I32x4) -> i32 { a.reduce_min() }
+
+    fn u32x4_min(a: U32x4, b: U32x4) -> U32x4 { a.min(&b) }
+    fn u32x4_shr(a: U32x4, amt: u32) -> U32x4 { a >> amt }
+    fn u32x4_lt(a: U32x4, b: U32x4) -> Bx4 { a.lt(&b) }
+    fn u32x4_to_f32(a: U32x4) -> F32x4 { F32x4::from(a) }
+    fn u32x4_div(a: U32x4, b: U32x4) -> U32x4 { a / b }
+    fn u32x4_reduce(a: U32x4) -> u32 { a.reduce(|acc, x| acc.max(x), 0) }
+
+    fn f32x4_add(a: F32x4, b: F32x4) -> F32x4 { a + b }
+    fn f32x4_sub(a: F32x4, b: F32x4) -> F32x4 { a - b }
+    fn f32x4_mul(a: F32x4, b: F32x4) -> F32x4 { a * b }
+    fn f32x4_div(a: F32x4, b: F32x4) -> F32x4 { a / b }
+    fn f32x4_mul_add(a: F32x4, b: F32x4, c: F32x4) -> F32x4 { a.mul_add(b, c) }
+    fn f32x4_min(a: F32x4, b: F32x4) -> F32x4 { a.min(&b) }
+    fn f32x4_max(a: F32x4, b: F32x4) -> F32x4 { a.max(&b) }
+    fn f32x4_neg(a: F32x4) -> F32x4 { -a }
+    fn f32x4_abs(a: F32x4) -> F32x4 { a.abs() }
+    fn f32x4_floor(a: F32x4) -> F32x4 { a.floor() }
+    fn f32x4_ceil(a: F32x4) -> F32x4 { a.ceil() }
+    fn f32x4_lt(a: F32x4, b: F32x4) -> Bx4 { a.lt(&b) }
+    fn f32x4_select(m: Bx4, a: F32x4, b: F32x4) -> F32x4 { F32x4::select(&m, &a, &b) }
+    fn f32x4_to_i32(a: F32x4) -> I32x4 { a.to_i32_trunc() }
+    fn f32x4_to_u32(a: F32x4) -> U32x4 { a.to_u32_trunc() }
+    fn f32x4_fold(a: F32x4) -> f32 { a.fold(f32::max, f32::MIN) }
+
+    fn bx4_and(a: Bx4, b: Bx4) -> Bx4 { a & b }
+    fn bx4_or(a: Bx4, b: Bx4) -> Bx4 { a | b }
+    fn bx4_xor(a: Bx4, b: Bx4) -> Bx4 { a ^ b }
+    fn bx4_not(a: Bx4) -> Bx4 { !a }
+    fn bx4_any(a: Bx4) -> bool { a.any() }
+    fn bx4_all(a: Bx4) -> bool { a.all() }
+    fn bx4_to_bitmask(a: Bx4) -> u8 { a.to_bitmask() }
+}
@@ -0,0 +1,293 @@
+//! Compiles the exports in `tests/codegen` to wasm32 with simd128 and checks which
+//! instructions each one lowers to, so operations that quietly fall back to per-lane
+//! scalar code show up in review.
+//!
+//! Building the fixture needs the `wasm32-unknown-unknown` target, so that test is ignored by
+//! default. `make tests-codegen`, which `make tests` runs, runs it with `--ignored` and fails
+//! if the target is missing.
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use std::process::Command;
+use wasmparser::{ExternalKind, Operator, Parser, Payload, TypeRef};
+
+/// How an export in the fixture is expected to lower.
+enum Expect {
+    /// Uses these SIMD instructions and never moves lanes through scalars with
+    /// `extract_lane` / `replace_lane`.
+    Simd(&'static [&'static str]),
+    /// Known to work lane by lane. Checked as an expected failure, so the test fails once the
+    /// export vectorizes and the entry can be tightened to `Simd`.
+    Scalarized,
+}
+
+use Expect::{Scalarized, Simd};
+
+/// Every export in the fixture and how it must lower.
+const EXPECTED: &[(&str, Expect)] = &[
+    ("i32x4_add", Simd(&["I32x4Add"])),
+    ("i32x4_sub", Simd(&["I32x4Sub"])),
+    ("i32x4_mul", Simd(&["I32x4Mul"])),
+    ("i32x4_add_scalar", Simd(&["I32x4Splat", "I32x4Add"])),
+    ("i32x4_neg", Simd(&["I32x4Neg"])),
+    ("i32x4_abs", Simd(&["I32x4Abs"])),
+    ("i32x4_min", Simd(&["I32x4MinS"])),
+    ("i32x4_max", Simd(&["I32x4MaxS"])),
+    ("i32x4_shl", Simd(&["I32x4Shl"])),
+    ("i32x4_shr", Simd(&["I32x4ShrS"])),
+    ("i32x4_eq", Simd(&["I32x4Eq"])),
+    ("i32x4_lt", Simd(&["I32x4LtS"])),
+    ("i32x4_select", Simd(&["V128Bitselect"])),
+    ("i32x4_masked_add", Simd(&["I32x4Add", "V128Bitselect"])),
+    ("i32x4_swizzle", Simd(&["I8x16Swizzle"])),
+    ("i32x4_shuffle", Simd(&["I8x16Shuffle"])),
+    ("i32x4_reverse", Simd(&["I8x16Shuffle"])),
+    ("i32x4_interleave_low", Simd(&["I8x16Shuffle"])),
+    ("i32x4_to_f32", Simd(&["F32x4ConvertI32x4S"])),
+    ("i32x4_div", Scalarized),
+    ("i32x4_apply", Scalarized),
+    ("i32x4_fold", Scalarized),
+    ("i32x4_reduce_add", Scalarized),
+    ("i32x4_reduce_min", Scalarized),
+    ("u32x4_min", Simd(&["I32x4MinU"])),
+    ("u32x4_shr", Simd(&["I32x4ShrU"])),
+    ("u32x4_lt", Simd(&["I32x4LtU"])),
+    ("u32x4_to_f32", Simd(&["F32x4ConvertI32x4U"])),
+    ("u32x4_div", Scalarized),
+    ("u32x4_reduce", Scalarized),
+    ("f32x4_add", Simd(&["F32x4Add"])),
+    ("f32x4_sub", Simd(&["F32x4Sub"])),
+    ("f32x4_mul", Simd(&["F32x4Mul"])),
+    ("f32x4_div", Simd(&["F32x4Div"])),
+    ("f32x4_mul_add", Simd(&["F32x4Mul", "F32x4Add"])),
+    ("f32x4_min", Simd(&["F32x4Min"])),
+    ("f32x4_max", Simd(&["F32x4Max"])),
+    ("f32x4_neg", Simd(&["F32x4Neg"])),
+    ("f32x4_abs", Simd(&["F32x4Abs"])),
+    ("f32x4_floor", Simd(&["F32x4Floor"])),
+    ("f32x4_ceil", Simd(&["F32x4Ceil"])),
+    ("f32x4_lt", Simd(&["F32x4Lt"])),
+    ("f32x4_select", Simd(&["V128Bitselect"])),
+    ("f32x4_to_i32", Simd(&["I32x4TruncSatF32x4S"])),
+    ("f32x4_to_u32", Simd(&["I32x4TruncSatF32x4U"])),
+    ("f32x4_fold", Scalarized),
+    ("bx4_and", Simd(&["V128And"])),
+    ("bx4_or", Simd(&["V128Or"])),
+    ("bx4_xor", Simd(&["V128Xor"])),
+    ("bx4_not", Simd(&["V128Not"])),
+    ("bx4_any", Simd(&["V128AnyTrue"])),
+    ("bx4_all", Simd(&["I32x4AllTrue"])),
+    ("bx4_to_bitmask", Simd(&["I32x4Bitmask"])),
+];
+
+/// The exported functions of a module, with the instructions and callees of every body.
+struct Module {
+    exports: HashMap<String, u32>,
+    bodies: HashMap<u32, Body>,
+}
+
+#[derive(Default)]
+struct Body {
+    ops: BTreeSet<String>,
+    calls: Vec<u32>,
+}
+
+impl Module {
+    fn parse(wasm: &[u8]) -> Self {
+        let mut exports = HashMap::new();
+        let mut bodies = HashMap::new();
+        let mut next_func = 0;
+        for payload in Parser::new(0).parse_all(wasm) {
+            match payload.expect("invalid wasm module") {
+                Payload::ImportSection(imports) => {
+                    for import in imports.into_imports() {
+                        if let TypeRef::Func(_) = import.expect("invalid import").ty {
+                            next_func += 1;
+                        }
+                    }
+                }
+                Payload::ExportSection(section) => {
+                    for export in section {
+                        let export = export.expect("invalid export");
+                        if export.kind == ExternalKind::Func {
+                            exports.insert(export.name.to_string(), export.index);
+                        }
+                    }
+                }
+                Payload::CodeSectionEntry(entry) => {
+                    let mut body = Body::default();
+                    for op in entry.get_operators_reader().expect("invalid body") {
+                        let op = op.expect("invalid instruction");
+                        if let Operator::Call { function_index } = op {
+                            body.calls.push(function_index);
+                        }
+                        body.ops.insert(op_name(&op));
+                    }
+                    bodies.insert(next_func, body);
+                    next_func += 1;
+                }
+                _ => {}
+            }
+        }
+        Self { exports, bodies }
+    }
+
+    /// Instructions of an export and of every function it calls, directly or not.
+    fn reachable_ops(&self, export: &str) -> BTreeSet<String> {
+        let mut stack = vec![self.exports[export]];
+        let mut seen = BTreeSet::new();
+        let mut ops = BTreeSet::new();
+        while let Some(index) = stack.pop() {
+            if !seen.insert(index) {
+                continue;
+            }
+            // Calls to imports have no body to inspect.
+            if let Some(body) = self.bodies.get(&index) {
+                ops.extend(body.ops.iter().cloned());
+                stack.extend(&body.calls);
+            }
+        }
+        ops
+    }
+}
+
+/// The variant name of an instruction, e.g. `I32x4ExtractLane` for `i32x4.extract_lane 0`.
+fn op_name(op: &Operator) -> String {
+    format!("{op:?}")
+        .chars()
+        .take_while(char::is_ascii_alphanumeric)
+        .collect()
+}
+
+fn is_lane_access(op: &str) -> bool {
+    op.contains("ExtractLane") || op.contains("ReplaceLane")
+}
+
+/// Problems with one export, or an empty list if it meets its expectations.
+fn check_export(module: &Module, export: &str, required: &[&str]) -> Vec<String> {
+    let ops = module.reachable_ops(export);
+    let mut problems: Vec<String> = required
+        .iter()
+        .filter(|op| !ops.contains(**op))
+        .map(|op| format!("{export} does not use {op}"))
+        .collect();
+    let scalar: Vec<&String> = ops.iter().filter(|op| is_lane_access(op)).collect();
+    if !scalar.is_empty() {
+        problems.push(format!("{export} moves lanes through scalars: {scalar:?}"));
+    }
+    problems
+}
+
+/// Problems with one export against its `EXPECTED` entry.
+fn check_expectation(module: &Module, export: &str, expect: &Expect) -> Vec<String> {
+    match expect {
+        Simd(required) => check_export(module, export, required),
+        Scalarized if check_export(module, export, &[]).is_empty() => vec![format!(
+            "{export} no longer moves lanes through scalars; list its instructions in EXPECTED"
+        )],
+        Scalarized => Vec::new(),
+    }
+}
+
+/// Whether the active toolchain can build for `wasm32-unknown-unknown`.
+fn has_wasm32_target() -> bool {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let Ok(output) = Command::new(rustc).args(["--print", "sysroot"]).output() else {
+        return false;
+    };
+    let sysroot = String::from_utf8_lossy(&output.stdout);
+    Path::new(sysroot.trim())
+        .join("lib/rustlib/wasm32-unknown-unknown")
+        .is_dir()
+}
+
+fn build_fixture() -> Vec<u8> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = root.join("target/codegen");
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--release", "--target", "wasm32-unknown-unknown"])
+        .arg("--manifest-path")
+        .arg(root.join("tests/codegen/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .env("RUSTFLAGS", "-C target-feature=+simd128")
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "building tests/codegen for wasm32 failed");
+    let wasm = target_dir.join("wasm32-unknown-unknown/release/wasm_simd_codegen.wasm");
+    std::fs::read(&wasm).unwrap_or_else(|e| panic!("failed to read {}: {e}", wasm.display()))
+}
+
+#[test]
+#[ignore = "needs wasm32-unknown-unknown; run with `make tests-codegen`"]
+fn test_exports_use_simd_instructions() {
+    assert!(
+        has_wasm32_target(),
+        "the wasm32-unknown-unknown target is not installed; \
+         run `rustup target add wasm32-unknown-unknown`"
+    );
+    let module = Module::parse(&build_fixture());
+
+    let exported: BTreeSet<&str> = module.exports.keys().map(String::as_str).collect();
+    let expected: BTreeSet<&str> = EXPECTED.iter().map(|(name, _)| *name).collect();
+    assert_eq!(
+        exported, expected,
+        "Every export in tests/codegen needs an entry in EXPECTED"
+    );
+
+    let problems: Vec<String> = EXPECTED
+        .iter()
+        .flat_map(|(export, expect)| check_expectation(&module, export, expect))
+        .collect();
+    assert!(
+        problems.is_empty(),
+        "Codegen regressions:\n{}",
+        problems.join("\n")
+    );
+}
+
+#[test]
+fn test_checker_follows_calls_and_flags_lane_access() {
+    let wasm = wat::parse_str(
+        r#"(module
+            (import "env" "log" (func $log (param i32)))
+            (func $helper (param v128) (result i32)
+                local.get 0
+                i32x4.extract_lane 1)
+            (func (export "vector") (param v128 v128) (result v128)
+                local.get 0
+                local.get 1
+                i32x4.add)
+            (func (export "scalarized") (param v128) (result i32)
+                local.get 0
+                call $helper
+                i32.const 0
+                call $log))"#,
+    )
+    .expect("invalid test module");
+    let module = Module::parse(&wasm);
+
+    assert!(
+        check_export(&module, "vector", &["I32x4Add"]).is_empty(),
+        "Vector export flagged"
+    );
+    assert_eq!(
+        check_export(&module, "scalarized", &["I32x4Add"]),
+        [
+            "scalarized does not use I32x4Add",
+            "scalarized moves lanes through scalars: [\"I32x4ExtractLane\"]",
+        ],
+        "Lane access in a callee missed"
+    );
+
+    assert!(
+        check_expectation(&module, "scalarized", &Scalarized).is_empty(),
+        "Expected failure reported"
+    );
+    assert_eq!(
+        check_expectation(&module, "vector", &Scalarized).len(),
+        1,
+        "Vectorized expected failure missed"
+    );
+}
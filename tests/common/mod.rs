@@ -0,0 +1,8 @@
+//! Helpers shared by the integration tests.
+
+pub fn assert_close(a: &[f32], b: &[f32], msg: &str) {
+    assert_eq!(a.len(), b.len(), "{msg}: length");
+    for (x, y) in a.iter().zip(b) {
+        assert!((x - y).abs() <= 1e-5, "{msg}: {a:?} != {b:?}");
+    }
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "geometry")]
+
+mod common;
+
+use common::assert_close;
+use wasm_bindgen_test::*;
+use wasm_simd::geometry::{Mat3A, Vec3A, Vec4};
+use wasm_simd::F32x4;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_vec4_products() {
+    let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Vec4::new(5.0, 6.0, 7.0, 8.0);
+    assert_eq!(a.dot(b), 70.0, "dot");
+    assert_eq!(a.dot_splat(b), F32x4::splat(70.0), "dot_splat");
+    assert_eq!(a.length_squared(), 30.0, "length_squared");
+    assert_eq!(Vec4::new(2.0, 0.0, 4.0, 4.0).length(), 6.0, "length");
+    assert_eq!(Vec4::X.cross(Vec4::Y), Vec4::Z, "cross");
+    assert_eq!(
+        a.cross(b),
+        Vec4::new(-4.0, 8.0, -4.0, 0.0),
+        "cross ignores and clears w"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_vec3a_products() {
+    let a = Vec3A::new(1.0, 2.0, 3.0);
+    let b = Vec3A::new(4.0, 5.0, 6.0);
+    assert_eq!(a.dot(b), 32.0, "dot");
+    assert_eq!(a.cross(b), Vec3A::new(-3.0, 6.0, -3.0), "cross");
+    assert_eq!(b.cross(a), -a.cross(b), "cross is anti-commutative");
+    assert_eq!(Vec3A::Z.cross(Vec3A::X), Vec3A::Y, "cross of axes");
+    assert_eq!(Vec3A::new(2.0, 3.0, 6.0).length(), 7.0, "length");
+
+    // Lane 3 is padding and must not leak into the results.
+    let padded = Vec3A(F32x4::new(1.0, 2.0, 3.0, 100.0));
+    assert_eq!(padded.dot(b), 32.0, "dot ignores lane 3");
+    assert_eq!(padded, a, "eq ignores lane 3");
+    assert_eq!(padded.extend(1.0), Vec4::new(1.0, 2.0, 3.0, 1.0), "extend");
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 9.0).truncate(), a, "truncate");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_normalize() {
+    let v = Vec3A::new(3.0, 0.0, 4.0);
+    assert_close(&v.normalize().to_array(), &[0.6, 0.0, 0.8], "normalize");
+    assert_eq!(v.try_normalize(), Some(v.normalize()), "try_normalize");
+    assert_eq!(Vec3A::ZERO.try_normalize(), None, "zero vector");
+    assert_eq!(
+        Vec3A::new(f32::INFINITY, 0.0, 0.0).try_normalize(),
+        None,
+        "infinite vector"
+    );
+    assert!(Vec3A::ZERO.normalize().x().is_nan(), "normalize of zero");
+    for (result, msg) in [
+        (v.normalize(), "normalize"),
+        (v.try_normalize().unwrap(), "try_normalize"),
+        (Vec3A::ZERO.normalize(), "normalize of zero"),
+        (Vec3A::X.project(Vec3A::Y), "project"),
+    ] {
+        assert_eq!(
+            result.0.to_array()[3],
+            0.0,
+            "{msg} keeps the padding at zero"
+        );
+    }
+
+    let w = Vec4::new(1.0, 1.0, 1.0, 1.0).normalize();
+    assert_close(&w.to_array(), &[0.5; 4], "Vec4 normalize");
+    assert_eq!(Vec4::ZERO.try_normalize(), None, "Vec4 zero vector");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_reflect_project_lerp() {
+    let v = Vec3A::new(1.0, -1.0, 0.0);
+    assert_eq!(v.reflect(Vec3A::Y), Vec3A::new(1.0, 1.0, 0.0), "reflect");
+    assert_eq!(
+        Vec3A::new(2.0, 3.0, 4.0).project(Vec3A::new(0.0, 2.0, 0.0)),
+        Vec3A::new(0.0, 3.0, 0.0),
+        "project"
+    );
+    assert_eq!(
+        Vec4::ZERO.lerp(Vec4::new(2.0, 4.0, 6.0, 8.0), 0.25),
+        Vec4::new(0.5, 1.0, 1.5, 2.0),
+        "lerp"
+    );
+    assert_eq!(Vec3A::X.lerp(Vec3A::Y, 1.0), Vec3A::Y, "lerp at 1");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_swizzles() {
+    let v = Vec3A::new(1.0, 2.0, 3.0);
+    assert_eq!(v.zyx(), Vec3A::new(3.0, 2.0, 1.0), "zyx");
+    assert_eq!(v.yzx(), Vec3A::new(2.0, 3.0, 1.0), "yzx");
+    assert_eq!(v.xxz(), Vec3A::new(1.0, 1.0, 3.0), "xxz");
+    assert_eq!(v.swizzle::<2, 2, 0>(), v.zzx(), "swizzle");
+
+    let w = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(w.zyx(), v.zyx(), "Vec4 zyx");
+    assert_eq!(w.zzx().0.to_array()[3], 0.0, "Swizzles zero the padding");
+    assert_eq!(
+        w.swizzle::<3, 3, 0, 1>(),
+        Vec4::new(4.0, 4.0, 1.0, 2.0),
+        "swizzle"
+    );
+    assert_eq!(w.xyz(), v, "xyz");
+    assert_eq!(
+        w.truncate().0.to_array()[3],
+        0.0,
+        "truncate zeroes the padding"
+    );
+    assert_eq!(
+        (w.x(), w.y(), w.z(), w.w()),
+        (1.0, 2.0, 3.0, 4.0),
+        "getters"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_operators_and_conversions() {
+    let mut v = Vec3A::new(1.0, 2.0, 3.0);
+    assert_eq!(v + Vec3A::ONE, Vec3A::new(2.0, 3.0, 4.0), "add");
+    assert_eq!(v * 2.0, Vec3A::new(2.0, 4.0, 6.0), "mul scalar");
+    assert_eq!(6.0 / v, Vec3A::new(6.0, 3.0, 2.0), "scalar on the left");
+    v -= 1.0;
+    v *= Vec3A::new(1.0, 2.0, 3.0);
+    assert_eq!(v, Vec3A::new(0.0, 2.0, 6.0), "assign ops");
+
+    assert_eq!(
+        Vec4::from([1.0, 2.0, 3.0, 4.0]).to_array(),
+        [1.0, 2.0, 3.0, 4.0],
+        "Vec4 from array"
+    );
+    assert_eq!(
+        <[f32; 3]>::from(Vec3A::from([1.0, 2.0, 3.0])),
+        [1.0, 2.0, 3.0],
+        "Vec3A array round trip"
+    );
+    assert_eq!(
+        F32x4::from(Vec4::W),
+        F32x4::new(0.0, 0.0, 0.0, 1.0),
+        "into F32x4"
+    );
+    assert_eq!(format!("{:?}", Vec3A::X), "Vec3A(1.0, 0.0, 0.0)", "Debug");
+    assert_eq!(
+        format!("{:?}", Vec4::W),
+        "Vec4(0.0, 0.0, 0.0, 1.0)",
+        "Debug"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_vec3a_padding_stays_zero() {
+    let v = Vec3A::new(1.0, -2.0, 3.0);
+    let mut assigned = v;
+    assigned += 1.0;
+    assigned /= 0.0;
+    for (result, msg) in [
+        (v + 1.0, "add scalar"),
+        (v - 1.0, "sub scalar"),
+        (1.0 - v, "scalar sub"),
+        (v * f32::INFINITY, "mul infinity"),
+        (v / v, "div"),
+        (1.0 / v, "scalar div"),
+        (-v, "neg"),
+        (assigned, "assign ops"),
+        (v.reflect(Vec3A::Y), "reflect"),
+        (v.lerp(Vec3A::ONE, f32::INFINITY), "lerp"),
+        (
+            Mat3A::IDENTITY.mul_vec3a(Vec3A::splat(f32::INFINITY)),
+            "mul_vec3a",
+        ),
+    ] {
+        assert_eq!(
+            result.0.to_array()[3].to_bits(),
+            0,
+            "{msg} keeps the padding at +0.0"
+        );
+    }
+}
@@ -1,20 +1,23 @@
+#![allow(
+    clippy::clone_on_copy,
+    clippy::identity_op,
+    clippy::legacy_numeric_constants
+)]
+
+use std::collections::HashSet;
 use std::i32;
 use wasm_bindgen_test::*;
-use wasm_simd::{Bx4, F32x4, I32x4};
+use wasm_simd::prelude::*;
 
 fn assert_i32x4_eq(a: I32x4, b: I32x4, msg: &str) {
-    let lanes_a = a.extract_lanes();
-    let lanes_b = b.extract_lanes();
-    assert_eq!(lanes_a, lanes_b, "{}", msg);
+    assert_eq!(a, b, "{}", msg);
 }
 
 fn assert_bx4_eq(a: Bx4, b: Bx4, msg: &str) {
-    let lanes_a = a.extract_lanes();
-    let lanes_b = b.extract_lanes();
-    assert_eq!(lanes_a, lanes_b, "{}", msg);
+    assert_eq!(a, b, "{}", msg);
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_new_and_extract_lanes() {
     let v = I32x4::new(1, 2, 3, 4);
     assert_eq!(
@@ -24,19 +27,48 @@ fn test_new_and_extract_lanes() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_const_constructors() {
+    const TABLE: [I32x4; 2] = [I32x4::new(1, 2, 3, 4), I32x4::splat(9)];
+    static OFFSETS: I32x4 = I32x4::new(-1, 0, 1, 2);
+
+    assert_i32x4_eq(TABLE[0], I32x4::new(1, 2, 3, 4), "const new failed");
+    assert_i32x4_eq(TABLE[1], I32x4::splat(9), "const splat failed");
+    assert_i32x4_eq(
+        OFFSETS + I32x4::ONE,
+        I32x4::new(0, 1, 2, 3),
+        "static failed",
+    );
+
+    assert_i32x4_eq(I32x4::ZERO, I32x4::splat(0), "ZERO failed");
+    assert_i32x4_eq(I32x4::MIN, I32x4::splat(i32::MIN), "MIN failed");
+    assert_i32x4_eq(I32x4::MAX, I32x4::splat(i32::MAX), "MAX failed");
+    assert_eq!(U32x4::MAX, U32x4::splat(u32::MAX), "U32x4 MAX failed");
+    assert_eq!(
+        F32x4::INFINITY,
+        F32x4::splat(f32::INFINITY),
+        "INFINITY failed"
+    );
+    assert!(
+        F32x4::NAN.ne(&F32x4::NAN).all(),
+        "NAN lanes should compare unequal"
+    );
+    assert_bx4_eq(Bx4::ALL_TRUE, Bx4::splat(true), "ALL_TRUE failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
 fn test_splat() {
     let v = I32x4::splat(7);
     assert_eq!(v.extract_lanes(), (7, 7, 7, 7), "Splat mismatch");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_new_from_fn() {
     let v = I32x4::new_from_fn(|i| (i as i32 + 1) * 10);
     assert_eq!(v.extract_lanes(), (10, 20, 30, 40), "New_from_fn mismatch");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_extract_lane() {
     let v = I32x4::new(10, 20, 30, 40);
     assert_eq!(v.extract_lane(0), 10, "Extract_lane(0) failed");
@@ -45,14 +77,14 @@ fn test_extract_lane() {
     assert_eq!(v.extract_lane(3), 40, "Extract_lane(3) failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 #[should_panic(expected = "Index out of bounds")]
 fn test_extract_lane_panic() {
     let v = I32x4::splat(0);
     v.extract_lane(4);
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_set_lane() {
     let mut v = I32x4::new(1, 2, 3, 4);
     v.set_lane(0, 11);
@@ -60,21 +92,56 @@ fn test_set_lane() {
     assert_eq!(v.extract_lanes(), (11, 2, 33, 4), "Set_lane failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 #[should_panic(expected = "Index out of bounds")]
 fn test_set_lane_panic() {
     let mut v = I32x4::splat(0);
     v.set_lane(4, 100);
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_const_lane_access() {
+    let mut v = I32x4::new(1, 2, 3, 4);
+    assert_eq!(v.extract::<0>(), 1, "extract::<0> failed");
+    assert_eq!(v.extract::<3>(), 4, "extract::<3> failed");
+
+    let w = v.with_lane::<1>(20);
+    assert_i32x4_eq(w, I32x4::new(1, 20, 3, 4), "with_lane failed");
+    assert_i32x4_eq(v, I32x4::new(1, 2, 3, 4), "with_lane modified the original");
+
+    v.set::<2>(30);
+    assert_i32x4_eq(v, I32x4::new(1, 2, 30, 4), "set failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_get_and_try_set_lane() {
+    let mut v = I32x4::new(1, 2, 3, 4);
+    assert_eq!(v.get(2), Some(3), "get in bounds failed");
+    assert_eq!(v.get(4), None, "get out of bounds failed");
+
+    assert_eq!(
+        v.try_set_lane(3, 40),
+        Ok(()),
+        "try_set_lane in bounds failed"
+    );
+    assert_i32x4_eq(v, I32x4::new(1, 2, 3, 40), "try_set_lane did not set");
+    let err = v.try_set_lane(4, 50).unwrap_err();
+    assert_eq!(err.index, 4, "try_set_lane error index mismatch");
+    assert_i32x4_eq(
+        v,
+        I32x4::new(1, 2, 3, 40),
+        "failed try_set_lane modified vector",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
 fn test_apply() {
     let v = I32x4::new(1, -2, 3, -4);
     let applied = v.apply(|x| x * x);
     assert_i32x4_eq(applied, I32x4::new(1, 4, 9, 16), "Apply failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_fold() {
     let v = I32x4::new(1, 2, 3, 4);
     let sum = v.fold(|acc, x| acc + x, 10);
@@ -83,23 +150,43 @@ fn test_fold() {
     assert_eq!(product, 2 * 1 * 2 * 3 * 4, "Fold product failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_reduce_add() {
     let v = I32x4::new(1, 2, 3, 4);
     assert_eq!(v.reduce_add(), 10, "Reduce_add failed");
     let v_neg = I32x4::new(-1, -2, -3, -4);
     assert_eq!(v_neg.reduce_add(), -10, "Reduce_add with negatives failed");
+    assert_eq!(
+        I32x4::new(i32::MAX, 1, 0, 0).reduce_add(),
+        i32::MIN,
+        "Reduce_add should wrap on overflow"
+    );
+    assert_eq!(
+        U32x4::new(u32::MAX, 2, 0, 0).reduce_add(),
+        1,
+        "Unsigned reduce_add should wrap on overflow"
+    );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_reduce_mul() {
     let v = I32x4::new(1, 2, 3, 4);
     assert_eq!(v.reduce_mul(), 24, "Reduce_mul failed");
     let v_zero = I32x4::new(1, 0, 3, 4);
     assert_eq!(v_zero.reduce_mul(), 0, "Reduce_mul with zero failed");
+    assert_eq!(
+        I32x4::new(i32::MIN, -1, 1, 1).reduce_mul(),
+        i32::MIN,
+        "Reduce_mul should wrap on overflow"
+    );
+    assert_eq!(
+        U32x4::splat(1 << 16).reduce_mul(),
+        0,
+        "Unsigned reduce_mul should wrap on overflow"
+    );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_reduce_min() {
     let v = I32x4::new(5, 1, 9, 3);
     assert_eq!(v.reduce_min(), 1, "Reduce_min failed");
@@ -107,7 +194,7 @@ fn test_reduce_min() {
     assert_eq!(v_neg.reduce_min(), -9, "Reduce_min with negatives failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_reduce_max() {
     let v = I32x4::new(5, 1, 9, 3);
     assert_eq!(v.reduce_max(), 9, "Reduce_max failed");
@@ -115,7 +202,7 @@ fn test_reduce_max() {
     assert_eq!(v_neg.reduce_max(), -1, "Reduce_max with negatives failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_if_else() {
     let a = I32x4::new(1, 2, 3, 4);
     let b = I32x4::new(10, 20, 30, 40);
@@ -128,7 +215,7 @@ fn test_if_else() {
     assert_i32x4_eq(result2, I32x4::new(10, 2, 30, 4), "If_else ftft failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_all_nonzero() {
     let v1 = I32x4::new(1, 2, 3, 4);
     assert!(v1.all_nonzero(), "All_nonzero for (1,2,3,4) failed");
@@ -140,7 +227,62 @@ fn test_all_nonzero() {
     assert!(v4.all_nonzero(), "All_nonzero for (-1,-2,-3,-4) failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_nonzero_mask() {
+    let v = I32x4::new(0, -3, 0, 7);
+    assert_bx4_eq(
+        v.nonzero_mask(),
+        Bx4::new(false, true, false, true),
+        "nonzero_mask failed",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_masked_ops() {
+    let a = I32x4::new(1, 2, 3, 4);
+    let b = I32x4::new(10, 20, 30, 40);
+    let mask = Bx4::new(true, false, true, false);
+
+    assert_i32x4_eq(
+        a.masked_add(&mask, &b),
+        I32x4::new(11, 2, 33, 4),
+        "masked_add failed",
+    );
+    assert_i32x4_eq(
+        a.masked_sub(&mask, &b),
+        I32x4::new(-9, 2, -27, 4),
+        "masked_sub failed",
+    );
+    assert_i32x4_eq(
+        a.masked_mul(&mask, &b),
+        I32x4::new(10, 2, 90, 4),
+        "masked_mul failed",
+    );
+    assert_i32x4_eq(
+        I32x4::select(&mask, &a, &b),
+        I32x4::new(1, 20, 3, 40),
+        "select failed",
+    );
+
+    let mut c = a;
+    c.masked_assign(&mask, &b);
+    assert_i32x4_eq(c, I32x4::new(10, 2, 30, 4), "masked_assign failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_masked_counter() {
+    let values = I32x4::new(5, -1, 8, 0);
+    let mut counter = I32x4::splat(0);
+    counter -= values.s_gt(2).to_i32x4();
+    counter = counter.masked_add(&values.s_lt(0), &I32x4::splat(10));
+    assert_i32x4_eq(
+        counter,
+        I32x4::new(1, 10, 1, 0),
+        "branchless counter failed",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
 fn test_shuffle() {
     let a = I32x4::new(1, 2, 3, 4);
     let b = I32x4::new(10, 20, 30, 40);
@@ -153,7 +295,77 @@ fn test_shuffle() {
     assert_i32x4_eq(reversed_a, I32x4::new(4, 3, 2, 1), "Shuffle reverse failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_shuffle_helpers() {
+    let a = I32x4::new(1, 2, 3, 4);
+    let b = I32x4::new(10, 20, 30, 40);
+
+    assert_i32x4_eq(a.reverse(), I32x4::new(4, 3, 2, 1), "reverse failed");
+    assert_i32x4_eq(
+        a.rotate_lanes_left::<1>(),
+        I32x4::new(2, 3, 4, 1),
+        "rotate_lanes_left failed",
+    );
+    assert_i32x4_eq(
+        a.rotate_lanes_right::<1>(),
+        I32x4::new(4, 1, 2, 3),
+        "rotate_lanes_right failed",
+    );
+    assert_i32x4_eq(
+        a.rotate_lanes_left::<6>(),
+        I32x4::new(3, 4, 1, 2),
+        "rotate_lanes_left wrap-around failed",
+    );
+    assert_i32x4_eq(
+        a.broadcast_lane::<2>(),
+        I32x4::splat(3),
+        "broadcast_lane failed",
+    );
+    assert_i32x4_eq(
+        a.interleave_low(&b),
+        I32x4::new(1, 10, 2, 20),
+        "interleave_low failed",
+    );
+    assert_i32x4_eq(
+        a.interleave_high(&b),
+        I32x4::new(3, 30, 4, 40),
+        "interleave_high failed",
+    );
+    assert_i32x4_eq(
+        a.concat_low_high(&b),
+        I32x4::new(1, 2, 30, 40),
+        "concat_low_high failed",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_swizzle() {
+    let a = I32x4::new(1, 2, 3, 4);
+    assert_i32x4_eq(
+        a.swizzle(&I32x4::new(3, 3, 0, 1)),
+        I32x4::new(4, 4, 1, 2),
+        "swizzle failed",
+    );
+    assert_i32x4_eq(
+        a.swizzle(&I32x4::new(4, -1, i32::MIN, 2)),
+        I32x4::new(0, 0, 0, 3),
+        "swizzle out-of-range lanes should be zero",
+    );
+
+    let f = F32x4::new(1.5, 2.5, 3.5, 4.5);
+    assert_eq!(
+        f.swizzle(&I32x4::new(1, 0, 3, 7)).extract_lanes(),
+        (2.5, 1.5, 4.5, 0.0),
+        "F32x4 swizzle failed"
+    );
+    assert_eq!(
+        f.shuffle::<0, 4, 3, 7>(&F32x4::splat(9.0)).extract_lanes(),
+        (1.5, 9.0, 4.5, 9.0),
+        "F32x4 shuffle failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
 fn test_comparisons() {
     let a = I32x4::new(1, 2, 3, 4);
     let b = I32x4::new(1, 0, 5, 4);
@@ -225,7 +437,7 @@ fn test_comparisons() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_min_max_binary_ops() {
     let a = I32x4::new(1, 5, 2, 8);
     let b = I32x4::new(3, 2, 7, 8);
@@ -237,7 +449,7 @@ fn test_min_max_binary_ops() {
     assert_i32x4_eq(a.s_max(4), I32x4::new(4, 5, 4, 8), "s_max scalar failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_abs() {
     let v = I32x4::new(1, -2, 0, -i32::MAX);
     let v_abs = v.abs();
@@ -252,14 +464,14 @@ fn test_abs() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_default() {
     let v1 = I32x4::default();
 
     assert_eq!(v1.extract_lanes(), (0, 0, 0, 0), "default failed")
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_clone() {
     let v1 = I32x4::new(1, 2, 3, 4);
     let v2 = v1.clone();
@@ -280,21 +492,24 @@ fn test_clone() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_into_array() {
     let v = I32x4::new(10, 20, 30, 40);
     let arr: [i32; 4] = v.into();
     assert_eq!(arr, [10, 20, 30, 40], "Into<[i32; 4]> failed");
 }
 
-#[wasm_bindgen_test]
+#[cfg(feature = "alloc")]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_into_vec() {
     let v = I32x4::new(11, 22, 33, 44);
     let vec: Vec<i32> = v.into();
     assert_eq!(vec, vec![11, 22, 33, 44], "Into<Vec<i32>> failed");
 }
 
-#[wasm_bindgen_test]
+// Checks saturation of out-of-range lanes, which the relaxed conversions leave unspecified.
+#[cfg(not(feature = "relaxed-simd-default"))]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_from_f32x4() {
     let fv = F32x4::new(1.1, -2.9, 3.5, -4.0001);
     let iv = I32x4::from(fv); // i32x4_trunc_sat_f32x4
@@ -313,21 +528,358 @@ fn test_from_f32x4() {
     );
 }
 
-#[wasm_bindgen_test]
+// Checks saturation of out-of-range lanes, which the relaxed conversions leave unspecified.
+#[cfg(not(feature = "relaxed-simd-default"))]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_f32x4_to_i32_rounding() {
+    let fv = F32x4::new(1.5, -1.5, 2.5, -2.7);
+    assert_i32x4_eq(
+        fv.to_i32_round(),
+        I32x4::new(2, -2, 2, -3),
+        "to_i32_round failed",
+    );
+    assert_i32x4_eq(
+        fv.to_i32_floor(),
+        I32x4::new(1, -2, 2, -3),
+        "to_i32_floor failed",
+    );
+    assert_i32x4_eq(
+        fv.to_i32_ceil(),
+        I32x4::new(2, -1, 3, -2),
+        "to_i32_ceil failed",
+    );
+    assert_i32x4_eq(
+        fv.to_i32_trunc(),
+        I32x4::new(1, -1, 2, -2),
+        "to_i32_trunc failed",
+    );
+
+    let special = F32x4::new(f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 3e9);
+    assert_i32x4_eq(
+        special.to_i32_round(),
+        I32x4::new(0, i32::MAX, i32::MIN, i32::MAX),
+        "to_i32_round saturation failed",
+    );
+    assert_i32x4_eq(
+        special.to_i32_floor(),
+        I32x4::new(0, i32::MAX, i32::MIN, i32::MAX),
+        "to_i32_floor saturation failed",
+    );
+}
+
+// Checks saturation of out-of-range lanes, which the relaxed conversions leave unspecified.
+#[cfg(not(feature = "relaxed-simd-default"))]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_f32x4_to_u32_rounding() {
+    let fv = F32x4::new(1.5, 2.5, 2.2, -0.7);
+    assert_eq!(
+        fv.to_u32_round().extract_lanes(),
+        (2, 2, 2, 0),
+        "to_u32_round failed"
+    );
+    assert_eq!(
+        fv.to_u32_floor().extract_lanes(),
+        (1, 2, 2, 0),
+        "to_u32_floor failed"
+    );
+    assert_eq!(
+        fv.to_u32_ceil().extract_lanes(),
+        (2, 3, 3, 0),
+        "to_u32_ceil failed"
+    );
+    assert_eq!(
+        fv.to_u32_trunc().extract_lanes(),
+        (1, 2, 2, 0),
+        "to_u32_trunc failed"
+    );
+
+    let special = F32x4::new(f32::NAN, f32::INFINITY, -5.0, 5e9);
+    assert_eq!(
+        special.to_u32_ceil().extract_lanes(),
+        (0, u32::MAX, 0, u32::MAX),
+        "to_u32_ceil saturation failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_f32x4_from_u32x4() {
+    let uv = U32x4::new(0, 1, 3_000_000_000, u32::MAX);
+    let fv = F32x4::from(uv);
+    assert_eq!(
+        fv.extract_lanes(),
+        (0.0, 1.0, 3_000_000_000.0, u32::MAX as f32),
+        "From<U32x4> failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_bitcast() {
+    let f = F32x4::new(1.0, -0.0, f32::INFINITY, 0.5);
+    let bits: U32x4 = f.bitcast();
+    assert_eq!(
+        bits.extract_lanes(),
+        (0x3f80_0000, 0x8000_0000, 0x7f80_0000, 0x3f00_0000),
+        "bitcast F32x4 -> U32x4 failed"
+    );
+    assert_eq!(
+        f.to_bits().extract_lanes(),
+        bits.extract_lanes(),
+        "to_bits failed"
+    );
+    assert_eq!(
+        F32x4::from_bits(bits).extract_lanes(),
+        f.extract_lanes(),
+        "from_bits failed"
+    );
+
+    let i = I32x4::new(-1, 0, 1, i32::MIN);
+    let u: U32x4 = i.bitcast();
+    assert_eq!(
+        u.extract_lanes(),
+        (u32::MAX, 0, 1, 0x8000_0000),
+        "bitcast I32x4 -> U32x4 failed"
+    );
+    assert_i32x4_eq(u.bitcast(), i, "bitcast round trip failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_bytes() {
+    let v = I32x4::new(1, 0x0102_0304, -1, 0);
+    let bytes = v.to_le_bytes();
+    assert_eq!(
+        bytes,
+        [1, 0, 0, 0, 4, 3, 2, 1, 255, 255, 255, 255, 0, 0, 0, 0],
+        "to_le_bytes failed"
+    );
+    assert_i32x4_eq(I32x4::from_le_bytes(bytes), v, "from_le_bytes failed");
+    assert_i32x4_eq(
+        I32x4::from_ne_bytes(v.to_ne_bytes()),
+        v,
+        "ne bytes round trip failed",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
 fn test_from_array() {
     let arr = [5, 6, 7, 8];
     let v = I32x4::from(arr);
     assert_i32x4_eq(v, I32x4::new(5, 6, 7, 8), "From<[i32; 4]> failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_eq_and_hash() {
+    let a = I32x4::new(1, 2, 3, 4);
+    assert_eq!(a, I32x4::new(1, 2, 3, 4), "PartialEq failed");
+    assert_ne!(a, I32x4::new(1, 2, 3, 5), "PartialEq (ne) failed");
+
+    let set: HashSet<I32x4> = [a, I32x4::new(1, 2, 3, 4), I32x4::splat(0)]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2, "Hash failed");
+
+    let f = F32x4::new(0.0, 1.0, 2.0, 3.0);
+    assert_eq!(
+        f,
+        F32x4::new(-0.0, 1.0, 2.0, 3.0),
+        "F32x4 -0.0 == 0.0 failed"
+    );
+    let nan = F32x4::new(f32::NAN, 1.0, 2.0, 3.0);
+    assert_ne!(nan, nan, "F32x4 NaN should not equal itself");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_sum_and_product() {
+    let vectors = [
+        I32x4::new(1, 2, 3, 4),
+        I32x4::new(10, 20, 30, 40),
+        I32x4::new(-1, -1, -1, -1),
+    ];
+    assert_i32x4_eq(
+        vectors.iter().sum(),
+        I32x4::new(10, 21, 32, 43),
+        "Sum failed",
+    );
+    assert_i32x4_eq(
+        vectors.into_iter().product(),
+        I32x4::new(-10, -40, -90, -160),
+        "Product failed",
+    );
+    assert_i32x4_eq(
+        std::iter::empty::<I32x4>().sum(),
+        I32x4::splat(0),
+        "Sum of empty iterator failed",
+    );
+
+    let floats = [F32x4::splat(0.5), F32x4::new(1.0, 2.0, 3.0, 4.0)];
+    assert_eq!(
+        floats.iter().product::<F32x4>(),
+        F32x4::new(0.5, 1.0, 1.5, 2.0),
+        "F32x4 Product failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_from_iter_and_into_iter() {
+    let v: I32x4 = (1..).collect();
+    assert_i32x4_eq(v, I32x4::new(1, 2, 3, 4), "FromIterator failed");
+
+    let padded: I32x4 = [7, 8].into_iter().collect();
+    assert_i32x4_eq(
+        padded,
+        I32x4::new(7, 8, 0, 0),
+        "FromIterator padding failed",
+    );
+
+    let lanes: Vec<i32> = v.into_iter().map(|x| x * 2).collect();
+    assert_eq!(lanes, vec![2, 4, 6, 8], "IntoIterator failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_index() {
+    let v = I32x4::new(10, 20, 30, 40);
+    assert_eq!(v[0], 10, "Index 0 failed");
+    assert_eq!(v[3], 40, "Index 3 failed");
+
+    let f = F32x4::new(1.5, 2.5, 3.5, 4.5);
+    assert_eq!(f[2], 3.5, "F32x4 Index failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic]
+fn test_index_panic() {
+    let v = I32x4::splat(0);
+    let _ = v[4];
+}
+
+#[wasm_bindgen_test(unsupported = test)]
 fn test_debug_format() {
     let v = I32x4::new(1, -2, 3, -4);
     let formatted = format!("{:?}", v);
     assert_eq!(formatted, "I32x4(1, -2, 3, -4)", "Debug format incorrect");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_display_and_numeric_formats() {
+    let v = I32x4::new(1, -2, 255, 4096);
+    assert_eq!(format!("{}", v), "[1, -2, 255, 4096]", "Display incorrect");
+    assert_eq!(
+        format!("{:>4}", v),
+        "[   1,   -2,  255, 4096]",
+        "Display width incorrect"
+    );
+    assert_eq!(
+        format!("{:+}", v),
+        "[+1, -2, +255, +4096]",
+        "Display sign incorrect"
+    );
+    assert_eq!(
+        format!("{:08x}", v),
+        "[00000001, fffffffe, 000000ff, 00001000]",
+        "LowerHex incorrect"
+    );
+    assert_eq!(
+        format!("{:#X}", v),
+        "[0x1, 0xFFFFFFFE, 0xFF, 0x1000]",
+        "UpperHex incorrect"
+    );
+    assert_eq!(
+        format!("{:b}", I32x4::new(0, 1, 2, 5)),
+        "[0, 1, 10, 101]",
+        "Binary incorrect"
+    );
+
+    let f = F32x4::new(1.0, -2.5, 1234.5, 0.125);
+    assert_eq!(
+        format!("{:.2}", f),
+        "[1.00, -2.50, 1234.50, 0.12]",
+        "F32x4 Display precision incorrect"
+    );
+    assert_eq!(
+        format!("{:e}", f),
+        "[1e0, -2.5e0, 1.2345e3, 1.25e-1]",
+        "F32x4 LowerExp incorrect"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_from_str() {
+    let expected = I32x4::new(1, -2, 3, 4);
+    for input in [
+        "[1, -2, 3, 4]",
+        "I32x4(1, -2, 3, 4)",
+        "1,-2,3,4",
+        "  1 -2   3 4 ",
+        "(1, -2, 3, 4)",
+    ] {
+        assert_eq!(
+            input.parse::<I32x4>(),
+            Ok(expected),
+            "parse of {:?} failed",
+            input
+        );
+    }
+
+    let debug = format!("{:?}", expected);
+    assert_eq!(
+        debug.parse::<I32x4>(),
+        Ok(expected),
+        "Debug round trip failed"
+    );
+    let display = format!("{}", expected);
+    assert_eq!(
+        display.parse::<I32x4>(),
+        Ok(expected),
+        "Display round trip failed"
+    );
+
+    let f = "1.5 2 3 4".parse::<F32x4>().unwrap();
+    assert_eq!(f, F32x4::new(1.5, 2.0, 3.0, 4.0), "F32x4 parse failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_from_str_errors() {
+    assert_eq!(
+        "[1, 2, 3]".parse::<I32x4>(),
+        Err(ParseVectorError::WrongLaneCount {
+            expected: 4,
+            found: 3
+        }),
+        "too few lanes not reported"
+    );
+    assert_eq!(
+        "1 2 3 4 5".parse::<I32x4>(),
+        Err(ParseVectorError::WrongLaneCount {
+            expected: 4,
+            found: 5
+        }),
+        "too many lanes not reported"
+    );
+    assert_eq!(
+        "".parse::<I32x4>(),
+        Err(ParseVectorError::WrongLaneCount {
+            expected: 4,
+            found: 0
+        }),
+        "empty input not reported"
+    );
+    assert_eq!(
+        "[1, 2, x, 4]".parse::<I32x4>(),
+        Err(ParseVectorError::InvalidLane(2)),
+        "bad scalar not reported"
+    );
+    assert_eq!(
+        "1, , 3, 4".parse::<I32x4>(),
+        Err(ParseVectorError::InvalidLane(1)),
+        "empty lane not reported"
+    );
+    assert_eq!(
+        "1.5, 2, 3, 4".parse::<I32x4>(),
+        Err(ParseVectorError::InvalidLane(0)),
+        "float in integer vector not reported"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
 fn test_neg() {
     let v = I32x4::new(1, -2, 0, i32::MIN);
     let neg_v = -v;
@@ -335,7 +887,7 @@ fn test_neg() {
     assert_i32x4_eq(neg_v, I32x4::new(-1, 2, 0, i32::MIN), "Negation failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_add() {
     let a = I32x4::new(1, 2, 3, 100);
     let b = I32x4::new(10, 20, 30, -50);
@@ -356,7 +908,7 @@ fn test_add() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_sub() {
     let a = I32x4::new(10, 20, 30, 100);
     let b = I32x4::new(1, 2, 5, -50);
@@ -377,7 +929,7 @@ fn test_sub() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_mul() {
     let a = I32x4::new(1, 2, -3, 10);
     let b = I32x4::new(5, -4, 2, 100);
@@ -398,7 +950,7 @@ fn test_mul() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_div() {
     let a = I32x4::new(10, 21, -9, 100);
     let b = I32x4::new(2, 7, 3, -10);
@@ -419,7 +971,7 @@ fn test_div() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 #[should_panic]
 fn test_div_by_zero_vector() {
     let a = I32x4::new(10, 20, 30, 40);
@@ -427,14 +979,14 @@ fn test_div_by_zero_vector() {
     let _ = a / b;
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 #[should_panic]
 fn test_div_by_zero_scalar() {
     let a = I32x4::new(10, 20, 30, 40);
     let _ = a / 0;
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_shl() {
     let a = I32x4::new(1, 2, 3, 4);
     assert_i32x4_eq(a.clone() << 1u32, I32x4::new(2, 4, 6, 8), "Shl by 1 failed");
@@ -446,7 +998,7 @@ fn test_shl() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_shr() {
     // This will be arithmetic shift right (SAR) due to i32
     let a = I32x4::new(8, -8, 7, -7);
@@ -464,7 +1016,7 @@ fn test_shr() {
     );
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_add_assign() {
     let mut a = I32x4::new(1, 2, 3, 10);
     a += I32x4::new(10, 20, 30, -5);
@@ -475,7 +1027,7 @@ fn test_add_assign() {
     assert_i32x4_eq(b, I32x4::new(6, 7, 8, 15), "AddAssign scalar failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_sub_assign() {
     let mut a = I32x4::new(10, 20, 30, 10);
     a -= I32x4::new(1, 2, 5, -5);
@@ -486,7 +1038,7 @@ fn test_sub_assign() {
     assert_i32x4_eq(b, I32x4::new(5, 15, 25, 5), "SubAssign scalar failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_mul_assign() {
     let mut a = I32x4::new(1, 2, -3, 10);
     a *= I32x4::new(5, -4, 2, 2);
@@ -497,7 +1049,7 @@ fn test_mul_assign() {
     assert_i32x4_eq(b, I32x4::new(3, 6, -9, 30), "MulAssign scalar failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_div_assign() {
     let mut a = I32x4::new(10, 21, -9, 100);
     a /= I32x4::new(2, 7, 3, -10);
@@ -508,30 +1060,114 @@ fn test_div_assign() {
     assert_i32x4_eq(b, I32x4::new(5, 10, -4, 50), "DivAssign scalar failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 #[should_panic]
 fn test_div_assign_by_zero_vector() {
     let mut a = I32x4::new(10, 20, 30, 40);
     a /= I32x4::new(1, 0, 2, 3);
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 #[should_panic]
 fn test_div_assign_by_zero_scalar() {
     let mut a = I32x4::new(10, 20, 30, 40);
     a /= 0;
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_shl_assign() {
     let mut a = I32x4::new(1, 2, 3, 4);
     a <<= 2u32;
     assert_i32x4_eq(a, I32x4::new(4, 8, 12, 16), "ShlAssign failed");
 }
 
-#[wasm_bindgen_test]
+#[wasm_bindgen_test(unsupported = test)]
 fn test_shr_assign() {
     let mut a = I32x4::new(8, -8, 7, -7);
     a >>= 1u32;
     assert_i32x4_eq(a, I32x4::new(4, -4, 3, -4), "ShrAssign failed");
 }
+
+#[cfg(feature = "num-traits")]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_partial_ord() {
+    use std::cmp::Ordering;
+
+    let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(
+        a.partial_cmp(&a),
+        Some(Ordering::Equal),
+        "Equal order failed"
+    );
+    assert_eq!(
+        a.partial_cmp(&F32x4::new(1.0, 2.0, 3.0, 5.0)),
+        Some(Ordering::Less),
+        "Less order failed"
+    );
+    assert_eq!(
+        a.partial_cmp(&F32x4::new(0.0, 2.0, 3.0, 5.0)),
+        None,
+        "Mixed lanes should be unordered"
+    );
+
+    let nan = F32x4::new(1.0, f32::NAN, 0.0, 0.0);
+    assert_eq!(nan.partial_cmp(&nan), None, "NaN lanes should be unordered");
+    assert!(
+        F32x4::new(-0.0, 1.0, 2.0, 3.0) <= F32x4::new(0.0, 1.0, 2.0, 3.0),
+        "F32x4 <= failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_f32x4_mul_add() {
+    assert_eq!(
+        F32x4::splat(2.0).mul_add(F32x4::new(1.0, 2.0, 3.0, 4.0), F32x4::ONE),
+        F32x4::new(3.0, 5.0, 7.0, 9.0),
+        "mul_add failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_f32x4_neg() {
+    let v = -F32x4::new(1.5, -2.0, 0.0, f32::INFINITY);
+    assert_eq!(
+        v,
+        F32x4::new(-1.5, 2.0, -0.0, f32::NEG_INFINITY),
+        "Float negation failed"
+    );
+    assert!(
+        v.extract::<2>().is_sign_negative(),
+        "Negated zero should be -0.0"
+    );
+
+    // Negation only flips the sign bit. Negating the bits as integers, as `i32x4_neg` does,
+    // would turn 1.0 into a NaN and 0.0 into itself.
+    let bits = F32x4::from_bits(U32x4::new(0x3f80_0000, 0, 0x7fc0_0001, 0x8000_0001));
+    assert_eq!(
+        (-bits).to_bits(),
+        U32x4::new(0xbf80_0000, 0x8000_0000, 0xffc0_0001, 0x0000_0001),
+        "Float negation should only flip the sign bit"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_f32x4_rounding_edge_cases() {
+    let v = F32x4::new(-0.5, 0.5, 1.5, -2.5);
+    assert_eq!(v.floor(), F32x4::new(-1.0, 0.0, 1.0, -3.0), "floor failed");
+    assert_eq!(v.ceil(), F32x4::new(-0.0, 1.0, 2.0, -2.0), "ceil failed");
+    assert!(
+        v.ceil().extract::<0>().is_sign_negative(),
+        "ceil(-0.5) should be -0.0"
+    );
+
+    let large = F32x4::new(8388609.0, -16777216.0, f32::INFINITY, f32::MIN_POSITIVE);
+    assert_eq!(
+        large.floor(),
+        F32x4::new(8388609.0, -16777216.0, f32::INFINITY, 0.0),
+        "floor of large values failed"
+    );
+    assert!(
+        F32x4::splat(f32::NAN).floor().extract::<0>().is_nan(),
+        "floor(NaN) should be NaN"
+    );
+}
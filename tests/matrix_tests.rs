@@ -0,0 +1,249 @@
+#![cfg(feature = "geometry")]
+
+mod common;
+
+use common::assert_close;
+use core::f32::consts::FRAC_PI_2;
+use wasm_bindgen_test::*;
+use wasm_simd::geometry::{Mat3A, Mat4, Vec3A, Vec4};
+
+// An invertible matrix with no zero components.
+fn sample() -> Mat4 {
+    Mat4::from_cols_array([
+        2.0, 1.0, 0.5, 0.25, //
+        -1.0, 3.0, 1.0, 0.5, //
+        0.5, -2.0, 4.0, 1.0, //
+        3.0, 1.0, -1.0, 1.0,
+    ])
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_mat4_layout() {
+    let m = Mat4::from_cols_array(core::array::from_fn(|i| i as f32));
+    assert_eq!(m.col(1), Vec4::new(4.0, 5.0, 6.0, 7.0), "Columns");
+    assert_eq!(
+        m.to_cols_array(),
+        core::array::from_fn(|i| i as f32),
+        "to_cols_array"
+    );
+    assert_eq!(
+        m.transpose().to_cols_array(),
+        core::array::from_fn(|i| ((i % 4) * 4 + i / 4) as f32),
+        "transpose"
+    );
+    assert_eq!(m.transpose().transpose(), m, "transpose twice");
+    assert_eq!(Mat4::default(), Mat4::IDENTITY, "Default");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_mat4_products() {
+    let m = sample();
+    assert_eq!(m * Mat4::IDENTITY, m, "Identity on the right");
+    assert_eq!(Mat4::IDENTITY * m, m, "Identity on the left");
+    assert_eq!(
+        m * Vec4::new(1.0, 0.0, 0.0, 0.0),
+        m.col(0),
+        "Matrix times axis"
+    );
+    assert_eq!(
+        m * Vec4::new(1.0, 2.0, 3.0, 4.0),
+        m.col(0) + m.col(1) * 2.0 + m.col(2) * 3.0 + m.col(3) * 4.0,
+        "Matrix times vector"
+    );
+
+    let n = Mat4::from_translation(Vec3A::new(1.0, 2.0, 3.0)) * Mat4::from_scale(Vec3A::splat(2.0));
+    let mut product = m;
+    product *= n;
+    let v = Vec4::new(1.0, -1.0, 2.0, 1.0);
+    assert_close(
+        &(product * v).to_array(),
+        &(m * (n * v)).to_array(),
+        "Product is composition",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_mat4_inverse() {
+    assert_eq!(Mat4::IDENTITY.determinant(), 1.0, "Identity determinant");
+    assert_eq!(
+        Mat4::from_scale(Vec3A::new(2.0, 3.0, 4.0)).determinant(),
+        24.0,
+        "Scale determinant"
+    );
+
+    let m = sample();
+    assert!(
+        (m.determinant() - m.transpose().determinant()).abs() < 1e-4,
+        "det(Mᵀ) = det(M)"
+    );
+    assert_close(
+        &(m * m.inverse()).to_cols_array(),
+        &Mat4::IDENTITY.to_cols_array(),
+        "M * M⁻¹",
+    );
+    assert_close(
+        &(m.inverse() * m).to_cols_array(),
+        &Mat4::IDENTITY.to_cols_array(),
+        "M⁻¹ * M",
+    );
+    assert!(
+        (m.inverse().determinant() * m.determinant() - 1.0).abs() < 1e-4,
+        "det(M⁻¹) = 1 / det(M)"
+    );
+
+    let singular = Mat4::from_scale(Vec3A::new(1.0, 0.0, 1.0));
+    assert_eq!(singular.determinant(), 0.0, "Singular determinant");
+    assert!(
+        !singular.inverse().col(1).x().is_finite(),
+        "Singular inverse is not finite"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_mat4_transforms() {
+    let t = Mat4::from_translation(Vec3A::new(1.0, 2.0, 3.0));
+    assert_eq!(
+        t.transform_point3(Vec3A::ONE),
+        Vec3A::new(2.0, 3.0, 4.0),
+        "Translation moves points"
+    );
+    assert_eq!(
+        t.transform_vector3(Vec3A::ONE),
+        Vec3A::ONE,
+        "Translation keeps directions"
+    );
+
+    let r = Mat4::from_rotation_z(FRAC_PI_2);
+    assert_close(
+        &r.transform_vector3(Vec3A::X).to_array(),
+        &[0.0, 1.0, 0.0],
+        "Rotation around z",
+    );
+    assert_close(
+        &Mat4::from_rotation_x(FRAC_PI_2)
+            .transform_vector3(Vec3A::Y)
+            .to_array(),
+        &[0.0, 0.0, 1.0],
+        "Rotation around x",
+    );
+    assert_close(
+        &Mat4::from_rotation_y(FRAC_PI_2)
+            .transform_vector3(Vec3A::Z)
+            .to_array(),
+        &[1.0, 0.0, 0.0],
+        "Rotation around y",
+    );
+    assert_close(
+        &Mat4::from_axis_angle(Vec3A::Z, FRAC_PI_2).to_cols_array(),
+        &r.to_cols_array(),
+        "Axis-angle matches rotation around z",
+    );
+    let axis = Vec3A::new(1.0, 2.0, 2.0).normalize();
+    assert_close(
+        &Mat4::from_axis_angle(axis, 1.0)
+            .transform_vector3(axis)
+            .to_array(),
+        &axis.to_array(),
+        "Rotation keeps its axis",
+    );
+    assert_close(
+        &(Mat4::from_axis_angle(axis, 0.7) * Mat4::from_axis_angle(axis, -0.7)).to_cols_array(),
+        &Mat4::IDENTITY.to_cols_array(),
+        "Opposite rotations cancel",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_mat4_projections() {
+    let view = Mat4::look_at(Vec3A::new(0.0, 0.0, 5.0), Vec3A::ZERO, Vec3A::Y);
+    assert_close(
+        &view.transform_point3(Vec3A::ZERO).to_array(),
+        &[0.0, 0.0, -5.0],
+        "look_at puts the target down -z",
+    );
+    assert_close(
+        &view.transform_point3(Vec3A::new(0.0, 0.0, 5.0)).to_array(),
+        &[0.0, 0.0, 0.0],
+        "look_at puts the eye at the origin",
+    );
+    let side = Mat4::look_at(Vec3A::ZERO, Vec3A::X, Vec3A::Y);
+    assert_close(
+        &side.transform_vector3(Vec3A::X).to_array(),
+        &[0.0, 0.0, -1.0],
+        "look_at forward",
+    );
+    assert_close(
+        &side.transform_vector3(Vec3A::Z).to_array(),
+        &[1.0, 0.0, 0.0],
+        "look_at right",
+    );
+
+    let proj = Mat4::perspective(FRAC_PI_2, 2.0, 1.0, 10.0);
+    let clip = |p: Vec3A| {
+        let v = proj * p.extend(1.0);
+        (v * (1.0 / v.w())).to_array()
+    };
+    assert_close(
+        &clip(Vec3A::new(0.0, 0.0, -1.0)),
+        &[0.0, 0.0, -1.0, 1.0],
+        "Near plane",
+    );
+    assert_close(
+        &clip(Vec3A::new(0.0, 0.0, -10.0)),
+        &[0.0, 0.0, 1.0, 1.0],
+        "Far plane",
+    );
+    assert_close(
+        &clip(Vec3A::new(2.0, 1.0, -1.0)),
+        &[1.0, 1.0, -1.0, 1.0],
+        "Field of view and aspect",
+    );
+
+    let ortho = Mat4::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+    assert_close(
+        &(ortho * Vec4::new(2.0, -1.0, -1.0, 1.0)).to_array(),
+        &[1.0, -1.0, -1.0, 1.0],
+        "Orthographic near corner",
+    );
+    assert_close(
+        &(ortho * Vec4::new(-2.0, 1.0, -3.0, 1.0)).to_array(),
+        &[-1.0, 1.0, 1.0, 1.0],
+        "Orthographic far corner",
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_mat3a() {
+    let m = Mat3A::from_cols_array([2.0, 1.0, 0.5, -1.0, 3.0, 1.0, 0.5, -2.0, 4.0]);
+    assert_eq!(m.col(2), Vec3A::new(0.5, -2.0, 4.0), "Columns");
+    assert_eq!(
+        m.transpose().to_cols_array(),
+        [2.0, -1.0, 0.5, 1.0, 3.0, -2.0, 0.5, 1.0, 4.0],
+        "transpose"
+    );
+    assert_eq!(
+        m * Vec3A::new(1.0, 2.0, 3.0),
+        Vec3A::new(1.5, 1.0, 14.5),
+        "Matrix times vector"
+    );
+    assert_eq!(m * Mat3A::IDENTITY, m, "Identity");
+    assert_eq!(m.determinant(), 32.75, "determinant");
+    assert_close(
+        &(m * m.inverse()).to_cols_array(),
+        &Mat3A::IDENTITY.to_cols_array(),
+        "M * M⁻¹",
+    );
+
+    let rotation = Mat3A::from_rotation_z(0.3);
+    assert_close(
+        &rotation.inverse().to_cols_array(),
+        &rotation.transpose().to_cols_array(),
+        "Rotation inverse is its transpose",
+    );
+    assert_eq!(Mat3A::from_mat4(Mat4::from_mat3a(m)), m, "Mat4 round trip");
+    assert_eq!(
+        Mat4::from_scale(Vec3A::splat(2.0)),
+        Mat4::from_mat3a(Mat3A::from_scale(Vec3A::splat(2.0))),
+        "from_scale"
+    );
+}
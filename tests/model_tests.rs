@@ -0,0 +1,855 @@
+//! Property tests checking every public method of the vector types against the scalar
+//! reference model in `reference`, with generators biased towards edge cases.
+#![cfg(not(target_arch = "wasm32"))]
+
+mod reference;
+
+use proptest::prelude::*;
+use reference::*;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use wasm_simd::{Bx4, F32x4, I32x4, LaneIndexError, U32x4};
+
+const I32_SPECIALS: [i32; 10] = [
+    0,
+    1,
+    -1,
+    2,
+    31,
+    32,
+    i32::MIN,
+    i32::MIN + 1,
+    i32::MAX,
+    i32::MAX - 1,
+];
+
+const U32_SPECIALS: [u32; 8] = [0, 1, 2, 31, 32, 0x8000_0000, u32::MAX - 1, u32::MAX];
+
+const F32_SPECIALS: [u32; 22] = [
+    0x0000_0000, // 0.0
+    0x8000_0000, // -0.0
+    0x3f80_0000, // 1.0
+    0xbf80_0000, // -1.0
+    0x3f00_0000, // 0.5
+    0xc020_0000, // -2.5
+    0x4b00_0000, // 2^23
+    0x4eff_ffff, // largest value below 2^31
+    0x4f00_0000, // 2^31
+    0xcf00_0000, // -2^31
+    0x4f80_0000, // 2^32
+    0x0080_0000, // f32::MIN_POSITIVE
+    0x0000_0001, // smallest subnormal
+    0x807f_ffff, // largest negative subnormal
+    0x7f7f_ffff, // f32::MAX
+    0xff7f_ffff, // f32::MIN
+    0x7f80_0000, // inf
+    0xff80_0000, // -inf
+    0x7fc0_0000, // NaN
+    0xffc0_1234, // negative NaN with payload
+    0x7f80_0001, // signaling NaN
+    0x7fbf_ffff, // signaling NaN with a full payload
+];
+
+fn i32_lane() -> impl Strategy<Value = i32> {
+    prop_oneof![
+        prop::sample::select(&I32_SPECIALS[..]),
+        -8..8i32,
+        any::<i32>(),
+    ]
+}
+
+fn u32_lane() -> impl Strategy<Value = u32> {
+    prop_oneof![
+        prop::sample::select(&U32_SPECIALS[..]),
+        0..8u32,
+        any::<u32>(),
+    ]
+}
+
+fn f32_lane() -> impl Strategy<Value = f32> {
+    prop_oneof![
+        prop::sample::select(&F32_SPECIALS[..]).prop_map(f32::from_bits),
+        // Halves, to hit rounding ties.
+        (-64..64i32).prop_map(|x| x as f32 / 2.0),
+        -1.0e6..1.0e6f32,
+        any::<u32>().prop_map(f32::from_bits),
+    ]
+}
+
+fn i32_lanes() -> impl Strategy<Value = [i32; 4]> {
+    prop::array::uniform4(i32_lane())
+}
+
+fn u32_lanes() -> impl Strategy<Value = [u32; 4]> {
+    prop::array::uniform4(u32_lane())
+}
+
+fn f32_lanes() -> impl Strategy<Value = [f32; 4]> {
+    prop::array::uniform4(f32_lane())
+}
+
+fn mask_lanes() -> impl Strategy<Value = [bool; 4]> {
+    any::<[bool; 4]>()
+}
+
+fn shift_amount() -> impl Strategy<Value = u32> {
+    prop_oneof![0..40u32, any::<u32>()]
+}
+
+fn lane_indices() -> impl Strategy<Value = [i32; 4]> {
+    prop::array::uniform4(prop_oneof![-2..6i32, any::<i32>()])
+}
+
+fn assert_bits(actual: F32x4, expected: [f32; 4], message: &str) {
+    assert_eq!(
+        f32x4::to_bits(actual.into()),
+        f32x4::to_bits(expected),
+        "{message}"
+    );
+}
+
+fn assert_mask(actual: Bx4, expected: [bool; 4], message: &str) {
+    assert_eq!(<[bool; 4]>::from(actual), expected, "{message}");
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Checks the const-generic lane accessors for one lane index.
+macro_rules! check_lane_access {
+    ($vec:expr, $lanes:expr, $value:expr, $($n:literal),+) => {$(
+        assert_eq!($vec.extract::<$n>(), $lanes[$n], "extract::<{}>", $n);
+        assert_eq!(
+            <[_; 4]>::from($vec.with_lane::<$n>($value)),
+            with_lane($lanes, $n, $value),
+            "with_lane::<{}>",
+            $n
+        );
+        let mut v = $vec;
+        v.set::<$n>($value);
+        assert_eq!(<[_; 4]>::from(v), with_lane($lanes, $n, $value), "set::<{}>", $n);
+    )+};
+}
+
+// Checks the shuffle family, comparing lane bits with `bits`.
+macro_rules! check_shuffles {
+    ($a:expr, $b:expr, $bits:expr) => {{
+        let (a, b) = ($a, $b);
+        let (la, lb) = ($bits(a), $bits(b));
+        assert_eq!(
+            $bits(a.shuffle::<0, 5, 2, 7>(&b)),
+            shuffle(la, lb, [0, 5, 2, 7]),
+            "shuffle"
+        );
+        assert_eq!(
+            $bits(a.shuffle::<7, 3, 3, 4>(&b)),
+            shuffle(la, lb, [7, 3, 3, 4]),
+            "shuffle"
+        );
+        assert_eq!($bits(a.reverse()), shuffle(la, la, [3, 2, 1, 0]), "reverse");
+        assert_eq!(
+            $bits(a.rotate_lanes_left::<1>()),
+            rotate_left(la, 1),
+            "rotate left 1"
+        );
+        assert_eq!(
+            $bits(a.rotate_lanes_left::<2>()),
+            rotate_left(la, 2),
+            "rotate left 2"
+        );
+        assert_eq!(
+            $bits(a.rotate_lanes_left::<7>()),
+            rotate_left(la, 7),
+            "rotate left 7"
+        );
+        assert_eq!(
+            $bits(a.rotate_lanes_right::<1>()),
+            rotate_right(la, 1),
+            "rotate right 1"
+        );
+        assert_eq!(
+            $bits(a.rotate_lanes_right::<4>()),
+            rotate_right(la, 4),
+            "rotate right 4"
+        );
+        assert_eq!(
+            $bits(a.rotate_lanes_right::<3>()),
+            rotate_right(la, 3),
+            "rotate right 3"
+        );
+        assert_eq!($bits(a.broadcast_lane::<0>()), [la[0]; 4], "broadcast 0");
+        assert_eq!($bits(a.broadcast_lane::<3>()), [la[3]; 4], "broadcast 3");
+        assert_eq!(
+            $bits(a.interleave_low(&b)),
+            shuffle(la, lb, [0, 4, 1, 5]),
+            "interleave_low"
+        );
+        assert_eq!(
+            $bits(a.interleave_high(&b)),
+            shuffle(la, lb, [2, 6, 3, 7]),
+            "interleave_high"
+        );
+        assert_eq!(
+            $bits(a.concat_low_high(&b)),
+            shuffle(la, lb, [0, 1, 6, 7]),
+            "concat_low_high"
+        );
+    }};
+}
+
+macro_rules! int_vector_tests {
+    ($test_module:ident, $module:ident, $vec:ident, $type:ty, $lanes:ident, $lane:ident) => {
+        mod $test_module {
+            use super::*;
+
+            fn bits(v: $vec) -> [u32; 4] {
+                $module::to_bits(v.into())
+            }
+
+            proptest! {
+                #![proptest_config(ProptestConfig::with_cases(512))]
+
+                #[test]
+                fn construction_and_lane_access(
+                    a in $lanes(),
+                    value in $lane(),
+                    index in 0..6usize,
+                ) {
+                    let v = $vec::from(a);
+                    assert_eq!(<[$type; 4]>::from($vec::new(a[0], a[1], a[2], a[3])), a, "new");
+                    assert_eq!(<[$type; 4]>::from($vec::splat(value)), [value; 4], "splat");
+                    assert_eq!(<[$type; 4]>::from($vec::new_from_fn(|i| a[i])), a, "new_from_fn");
+                    assert_eq!(v.extract_lanes(), (a[0], a[1], a[2], a[3]), "extract_lanes");
+                    assert_eq!(v.get(index), a.get(index).copied(), "get");
+                    #[cfg(feature = "alloc")]
+                    assert_eq!(Vec::from(v), a.to_vec(), "Vec::from");
+                    assert_eq!(v.into_iter().collect::<Vec<_>>(), a.to_vec(), "into_iter");
+                    assert_eq!(<[$type; 4]>::from($vec::default()), [0; 4], "default");
+
+                    let mut set = v;
+                    let result = set.try_set_lane(index, value);
+                    if index < 4 {
+                        assert_eq!(v.extract_lane(index), a[index], "extract_lane");
+                        assert_eq!(v[index], a[index], "index");
+                        assert_eq!(result, Ok(()), "try_set_lane");
+                        assert_eq!(<[$type; 4]>::from(set), with_lane(a, index, value), "try_set_lane");
+                        let mut set = v;
+                        set.set_lane(index, value);
+                        assert_eq!(<[$type; 4]>::from(set), with_lane(a, index, value), "set_lane");
+                    } else {
+                        assert_eq!(result, Err(LaneIndexError { index, lanes: 4 }), "try_set_lane out of range");
+                        assert_eq!(<[$type; 4]>::from(set), a, "try_set_lane out of range");
+                    }
+                    check_lane_access!(v, a, value, 0, 1, 2, 3);
+
+                    let items: Vec<$type> = a.iter().copied().take(index).collect();
+                    assert_eq!(
+                        <[$type; 4]>::from(items.iter().copied().collect::<$vec>()),
+                        from_iter(&items),
+                        "from_iter"
+                    );
+                }
+
+                #[test]
+                fn arithmetic(a in $lanes(), b in $lanes(), s in $lane()) {
+                    let (va, vb) = ($vec::from(a), $vec::from(b));
+                    let splat = [s; 4];
+                    assert_eq!(<[$type; 4]>::from(va + vb), $module::add(a, b), "add");
+                    assert_eq!(<[$type; 4]>::from(va - vb), $module::sub(a, b), "sub");
+                    assert_eq!(<[$type; 4]>::from(va * vb), $module::mul(a, b), "mul");
+                    assert_eq!(<[$type; 4]>::from(va + s), $module::add(a, splat), "add scalar");
+                    assert_eq!(<[$type; 4]>::from(s - va), $module::sub(splat, a), "scalar sub");
+                    assert_eq!(<[$type; 4]>::from(va * s), $module::mul(a, splat), "mul scalar");
+                    assert_eq!(<[$type; 4]>::from(s * va), $module::mul(splat, a), "scalar mul");
+                    assert_eq!(<[$type; 4]>::from(va.min(&vb)), $module::min(a, b), "min");
+                    assert_eq!(<[$type; 4]>::from(va.max(&vb)), $module::max(a, b), "max");
+                    assert_eq!(<[$type; 4]>::from(va.s_min(s)), $module::min(a, splat), "s_min");
+                    assert_eq!(<[$type; 4]>::from(va.s_max(s)), $module::max(a, splat), "s_max");
+
+                    let mut assigned = va;
+                    assigned += vb;
+                    assigned -= s;
+                    assigned *= vb;
+                    assert_eq!(
+                        <[$type; 4]>::from(assigned),
+                        $module::mul($module::sub($module::add(a, b), splat), b),
+                        "assign ops"
+                    );
+
+                    let vectors = [va, vb, $vec::splat(s)];
+                    let sum = $module::add($module::add(a, b), splat);
+                    let product = $module::mul($module::mul(a, b), splat);
+                    assert_eq!(<[$type; 4]>::from(vectors.iter().sum::<$vec>()), sum, "sum");
+                    assert_eq!(<[$type; 4]>::from(vectors.into_iter().sum::<$vec>()), sum, "sum");
+                    assert_eq!(<[$type; 4]>::from(vectors.iter().product::<$vec>()), product, "product");
+                    assert_eq!(<[$type; 4]>::from(vectors.into_iter().product::<$vec>()), product, "product");
+                }
+
+                #[test]
+                fn division(a in $lanes(), b in $lanes(), s in $lane()) {
+                    let (va, vb) = ($vec::from(a), $vec::from(b));
+                    let splat = [s; 4];
+                    if let Some(expected) = $module::div(a, b) {
+                        assert_eq!(<[$type; 4]>::from(va / vb), expected, "div");
+                        let mut assigned = va;
+                        assigned /= vb;
+                        assert_eq!(<[$type; 4]>::from(assigned), expected, "div_assign");
+                    }
+                    #[cfg(feature = "num-traits")]
+                    if let Some(expected) = $module::rem(a, b) {
+                        assert_eq!(<[$type; 4]>::from(va % vb), expected, "rem");
+                    }
+                    if let Some(expected) = $module::div(a, splat) {
+                        assert_eq!(<[$type; 4]>::from(va / s), expected, "div scalar");
+                    }
+                    if let Some(expected) = $module::div(splat, a) {
+                        assert_eq!(<[$type; 4]>::from(s / va), expected, "scalar div");
+                    }
+                }
+
+                #[test]
+                fn shifts(a in $lanes(), amt in shift_amount()) {
+                    let va = $vec::from(a);
+                    assert_eq!(<[$type; 4]>::from(va << amt), $module::shl(a, amt), "shl");
+                    assert_eq!(<[$type; 4]>::from(va >> amt), $module::shr(a, amt), "shr");
+                    let mut assigned = va;
+                    assigned <<= amt;
+                    assigned >>= amt;
+                    assert_eq!(
+                        <[$type; 4]>::from(assigned),
+                        $module::shr($module::shl(a, amt), amt),
+                        "shift assign"
+                    );
+                }
+
+                #[test]
+                fn comparisons(a in $lanes(), b in $lanes(), s in any::<i32>()) {
+                    let (va, vb) = ($vec::from(a), $vec::from(b));
+                    assert_mask(va.eq(&vb), $module::eq(a, b), "eq");
+                    assert_mask(va.ne(&vb), $module::ne(a, b), "ne");
+                    assert_mask(va.lt(&vb), $module::lt(a, b), "lt");
+                    assert_mask(va.le(&vb), $module::le(a, b), "le");
+                    assert_mask(va.gt(&vb), $module::gt(a, b), "gt");
+                    assert_mask(va.ge(&vb), $module::ge(a, b), "ge");
+
+                    // The scalar comparisons splat the bits of an `i32`.
+                    let splat = [s as $type; 4];
+                    assert_mask(va.s_eq(s), $module::eq(a, splat), "s_eq");
+                    assert_mask(va.s_ne(s), $module::ne(a, splat), "s_ne");
+                    assert_mask(va.s_lt(s), $module::lt(a, splat), "s_lt");
+                    assert_mask(va.s_le(s), $module::le(a, splat), "s_le");
+                    assert_mask(va.s_gt(s), $module::gt(a, splat), "s_gt");
+                    assert_mask(va.s_ge(s), $module::ge(a, splat), "s_ge");
+
+                    assert_eq!(va == vb, a == b, "PartialEq");
+                    assert_eq!(hash_of(&va), hash_of(&a), "Hash");
+                    assert_mask(va.nonzero_mask(), $module::nonzero_mask(a), "nonzero_mask");
+                    assert_eq!(va.all_nonzero(), a.iter().all(|&x| x != 0), "all_nonzero");
+                }
+
+                #[test]
+                fn selection(a in $lanes(), b in $lanes(), m in mask_lanes()) {
+                    let (va, vb, mask) = ($vec::from(a), $vec::from(b), Bx4::from(m));
+                    assert_eq!(<[$type; 4]>::from($vec::select(&mask, &va, &vb)), select(m, a, b), "select");
+                    let mut assigned = va;
+                    assigned.masked_assign(&mask, &vb);
+                    assert_eq!(<[$type; 4]>::from(assigned), select(m, b, a), "masked_assign");
+                    assert_eq!(<[$type; 4]>::from(va.masked_add(&mask, &vb)), select(m, $module::add(a, b), a), "masked_add");
+                    assert_eq!(<[$type; 4]>::from(va.masked_sub(&mask, &vb)), select(m, $module::sub(a, b), a), "masked_sub");
+                    assert_eq!(<[$type; 4]>::from(va.masked_mul(&mask, &vb)), select(m, $module::mul(a, b), a), "masked_mul");
+                    #[cfg(feature = "relaxed-simd")]
+                    assert_eq!(<[$type; 4]>::from($vec::relaxed_select(&mask, &va, &vb)), select(m, a, b), "relaxed_select");
+                }
+
+                #[test]
+                fn shuffles(a in $lanes(), b in $lanes(), indices in lane_indices()) {
+                    check_shuffles!($vec::from(a), $vec::from(b), bits);
+                    let swizzled = $vec::from(a).swizzle(&I32x4::from(indices));
+                    assert_eq!(<[$type; 4]>::from(swizzled), swizzle(a, indices), "swizzle");
+                    #[cfg(feature = "relaxed-simd")]
+                    {
+                        let relaxed = <[$type; 4]>::from($vec::from(a).relaxed_swizzle(&I32x4::from(indices)));
+                        for i in (0..4).filter(|&i| (0..4).contains(&indices[i])) {
+                            assert_eq!(relaxed[i], a[indices[i] as usize], "relaxed_swizzle lane {}", i);
+                        }
+                    }
+                }
+
+                #[test]
+                fn reductions(a in $lanes()) {
+                    let va = $vec::from(a);
+                    assert_eq!(va.reduce_add(), $module::reduce_add(a), "reduce_add");
+                    assert_eq!(va.reduce_mul(), $module::reduce_mul(a), "reduce_mul");
+                    assert_eq!(va.reduce_min(), $module::reduce_min(a), "reduce_min");
+                    assert_eq!(va.reduce_max(), $module::reduce_max(a), "reduce_max");
+                    assert_eq!(
+                        <[$type; 4]>::from(va.apply(|x| x.wrapping_mul(3) ^ 5)),
+                        a.map(|x| x.wrapping_mul(3) ^ 5),
+                        "apply"
+                    );
+                }
+
+                #[test]
+                fn conversions(a in $lanes(), bytes in any::<[u8; 16]>()) {
+                    let va = $vec::from(a);
+                    let lane_bits = $module::to_bits(a);
+                    assert_eq!(f32x4::to_bits(F32x4::from(va).into()), f32x4::to_bits($module::to_f32(a)), "F32x4::from");
+                    assert_eq!(<[u32; 4]>::from(va.bitcast::<U32x4>()), lane_bits, "bitcast");
+                    assert_eq!(va.to_ne_bytes(), to_ne_bytes(lane_bits), "to_ne_bytes");
+                    assert_eq!(va.to_le_bytes(), to_le_bytes(lane_bits), "to_le_bytes");
+                    assert_eq!($vec::from_ne_bytes(bytes).to_ne_bytes(), bytes, "from_ne_bytes");
+                    assert_eq!($vec::from_le_bytes(bytes).to_le_bytes(), bytes, "from_le_bytes");
+                    assert_eq!(bits($vec::from_le_bytes(to_le_bytes(lane_bits))), lane_bits, "from_le_bytes lanes");
+                }
+
+                #[test]
+                fn formatting(a in $lanes()) {
+                    let va = $vec::from(a);
+                    assert_eq!(va.to_string(), display(a, |x| x.to_string()), "Display");
+                    assert_eq!(format!("{va:>4}"), display(a, |x| format!("{x:>4}")), "Display with width");
+                    assert_eq!(format!("{va:x}"), display(a, |x| format!("{x:x}")), "LowerHex");
+                    assert_eq!(format!("{va:#X}"), display(a, |x| format!("{x:#X}")), "UpperHex");
+                    assert_eq!(format!("{va:b}"), display(a, |x| format!("{x:b}")), "Binary");
+                    assert_eq!(
+                        format!("{va:?}"),
+                        format!("{}({}, {}, {}, {})", stringify!($vec), a[0], a[1], a[2], a[3]),
+                        "Debug"
+                    );
+                    assert_eq!(va.to_string().parse::<$vec>(), Ok(va), "FromStr Display");
+                    assert_eq!(format!("{va:?}").parse::<$vec>(), Ok(va), "FromStr Debug");
+                }
+            }
+        }
+    };
+}
+
+int_vector_tests!(i32x4_model, i32x4, I32x4, i32, i32_lanes, i32_lane);
+int_vector_tests!(u32x4_model, u32x4, U32x4, u32, u32_lanes, u32_lane);
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+    #[test]
+    fn i32x4_signed_ops(a in i32_lanes()) {
+        let va = I32x4::from(a);
+        assert_eq!(<[i32; 4]>::from(-va), i32x4_signed::neg(a), "neg");
+        assert_eq!(<[i32; 4]>::from(va.abs()), i32x4_signed::abs(a), "abs");
+        assert_eq!(va.fold(|x, y| x.wrapping_sub(y), 7), fold(a, 7, i32::wrapping_sub), "fold");
+        let m = Bx4::from([true, false, true, false]);
+        assert_eq!(
+            <[i32; 4]>::from(va.if_else(&I32x4::ZERO, &m)),
+            select(bx4::from_bitmask(m.to_bitmask()), a, [0; 4]),
+            "if_else"
+        );
+        assert_eq!(<[i32; 4]>::from(I32x4::MIN), [i32::MIN; 4], "MIN");
+        assert_eq!(<[i32; 4]>::from(I32x4::MAX), [i32::MAX; 4], "MAX");
+    }
+
+    #[test]
+    fn u32x4_unsigned_ops(a in u32_lanes(), m in mask_lanes()) {
+        let va = U32x4::from(a);
+        assert_eq!(va.reduce(|x, y| x ^ y.rotate_left(3), 9), fold(a, 9, |x, y| x ^ y.rotate_left(3)), "reduce");
+        assert_eq!(<[u32; 4]>::from(va.if_else(&U32x4::MAX, Bx4::from(m))), select(m, a, [u32::MAX; 4]), "if_else");
+        assert_eq!(<[u32; 4]>::from(U32x4::ONE), [1; 4], "ONE");
+    }
+}
+
+mod f32x4_model {
+    use super::*;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1024))]
+
+        #[test]
+        fn construction_and_lane_access(a in f32_lanes(), value in f32_lane(), index in 0..6usize) {
+            let v = F32x4::from(a);
+            let bits = f32x4::to_bits(a);
+            let value_bits = value.to_bits();
+            assert_bits(F32x4::new(a[0], a[1], a[2], a[3]), a, "new");
+            assert_bits(F32x4::splat(value), [value; 4], "splat");
+            assert_bits(F32x4::new_from_fn(|i| a[i]), a, "new_from_fn");
+            assert_bits(F32x4::default(), [0.0; 4], "default");
+            let (l0, l1, l2, l3) = v.extract_lanes();
+            assert_eq!(f32x4::to_bits([l0, l1, l2, l3]), bits, "extract_lanes");
+            assert_eq!(v.get(index).map(f32::to_bits), bits.get(index).copied(), "get");
+            #[cfg(feature = "alloc")]
+            assert_eq!(Vec::from(v).iter().map(|x| x.to_bits()).collect::<Vec<_>>(), bits.to_vec(), "Vec::from");
+            assert_eq!(v.into_iter().map(f32::to_bits).collect::<Vec<_>>(), bits.to_vec(), "into_iter");
+
+            let mut set = v;
+            let result = set.try_set_lane(index, value);
+            if index < 4 {
+                assert_eq!(v.extract_lane(index).to_bits(), bits[index], "extract_lane");
+                assert_eq!(v[index].to_bits(), bits[index], "index");
+                assert_eq!(result, Ok(()), "try_set_lane");
+                assert_bits(set, with_lane(a, index, value), "try_set_lane");
+                let mut set = v;
+                set.set_lane(index, value);
+                assert_bits(set, with_lane(a, index, value), "set_lane");
+            } else {
+                assert_eq!(result, Err(LaneIndexError { index, lanes: 4 }), "try_set_lane out of range");
+            }
+            for (n, lane) in [
+                v.extract::<0>(),
+                v.extract::<1>(),
+                v.extract::<2>(),
+                v.extract::<3>(),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                assert_eq!(lane.to_bits(), bits[n], "extract::<{}>", n);
+            }
+            assert_bits(v.with_lane::<0>(value), with_lane(a, 0, value), "with_lane::<0>");
+            assert_bits(v.with_lane::<3>(value), with_lane(a, 3, value), "with_lane::<3>");
+            let mut set = v;
+            set.set::<2>(value);
+            assert_eq!(set.extract::<2>().to_bits(), value_bits, "set::<2>");
+
+            let items: Vec<f32> = a.iter().copied().take(index).collect();
+            assert_bits(items.iter().copied().collect(), from_iter(&items), "from_iter");
+        }
+
+        #[test]
+        fn arithmetic(a in f32_lanes(), b in f32_lanes(), c in f32_lanes(), s in f32_lane()) {
+            let (va, vb, vc) = (F32x4::from(a), F32x4::from(b), F32x4::from(c));
+            let splat = [s; 4];
+            assert_bits(va + vb, f32x4::add(a, b), "add");
+            assert_bits(va - vb, f32x4::sub(a, b), "sub");
+            assert_bits(va * vb, f32x4::mul(a, b), "mul");
+            assert_bits(va / vb, f32x4::div(a, b), "div");
+            #[cfg(feature = "num-traits")]
+            assert_bits(va % vb, f32x4::rem(a, b), "rem");
+            assert_bits(va + s, f32x4::add(a, splat), "add scalar");
+            assert_bits(s - va, f32x4::sub(splat, a), "scalar sub");
+            assert_bits(va * s, f32x4::mul(a, splat), "mul scalar");
+            assert_bits(s / va, f32x4::div(splat, a), "scalar div");
+            assert_bits(-va, f32x4::neg(a), "neg");
+            assert_bits(va.abs(), f32x4::abs(a), "abs");
+            assert_bits(va.min(&vb), f32x4::min(a, b), "min");
+            assert_bits(va.max(&vb), f32x4::max(a, b), "max");
+            assert_bits(va.s_min(s), f32x4::min(a, splat), "s_min");
+            assert_bits(va.s_max(s), f32x4::max(a, splat), "s_max");
+
+            let mut assigned = va;
+            assigned += vb;
+            assigned -= s;
+            assigned *= vb;
+            assigned /= s;
+            let expected = f32x4::div(f32x4::mul(f32x4::sub(f32x4::add(a, b), splat), b), splat);
+            assert_bits(assigned, expected, "assign ops");
+
+            let vectors = [va, vb, vc];
+            let sum = f32x4::add(f32x4::add(f32x4::add([0.0; 4], a), b), c);
+            let product = f32x4::mul(f32x4::mul(f32x4::mul([1.0; 4], a), b), c);
+            assert_bits(vectors.iter().sum(), sum, "sum");
+            assert_bits(vectors.into_iter().sum(), sum, "sum");
+            assert_bits(vectors.iter().product(), product, "product");
+            assert_bits(vectors.into_iter().product(), product, "product");
+
+            let mul_add = va.mul_add(vb, vc);
+            if cfg!(feature = "relaxed-simd-default") {
+                let (unfused, fused) = (f32x4::mul_add(a, b, c), f32x4::mul_add_fused(a, b, c));
+                let actual = f32x4::to_bits(mul_add.into());
+                for i in 0..4 {
+                    let allowed = [unfused[i], fused[i]];
+                    assert!(
+                        allowed.iter().any(|x| x.to_bits() == actual[i] || (x.is_nan() && f32::from_bits(actual[i]).is_nan())),
+                        "mul_add lane {i}"
+                    );
+                }
+            } else {
+                assert_bits(mul_add, f32x4::mul_add(a, b, c), "mul_add");
+            }
+        }
+
+        #[test]
+        fn rounding(a in f32_lanes()) {
+            let va = F32x4::from(a);
+            assert_bits(va.floor(), f32x4::floor(a), "floor");
+            assert_bits(va.ceil(), f32x4::ceil(a), "ceil");
+            #[cfg(feature = "num-traits")]
+            {
+                use num_traits::Float;
+                assert_bits(Float::sqrt(va), f32x4::sqrt(a), "sqrt");
+                assert_bits(Float::trunc(va), f32x4::trunc(a), "trunc");
+            }
+            assert_bits(va.apply(|x| x * 2.0), f32x4::mul(a, [2.0; 4]), "apply");
+        }
+
+        #[test]
+        fn comparisons(a in f32_lanes(), b in f32_lanes(), s in any::<i32>()) {
+            let (va, vb) = (F32x4::from(a), F32x4::from(b));
+            assert_mask(va.eq(&vb), f32x4::eq(a, b), "eq");
+            assert_mask(va.ne(&vb), f32x4::ne(a, b), "ne");
+            assert_mask(va.lt(&vb), f32x4::lt(a, b), "lt");
+            assert_mask(va.le(&vb), f32x4::le(a, b), "le");
+            assert_mask(va.gt(&vb), f32x4::gt(a, b), "gt");
+            assert_mask(va.ge(&vb), f32x4::ge(a, b), "ge");
+
+            // The scalar comparisons splat the bits of an `i32`.
+            let splat = [f32::from_bits(s as u32); 4];
+            assert_mask(va.s_eq(s), f32x4::eq(a, splat), "s_eq");
+            assert_mask(va.s_ne(s), f32x4::ne(a, splat), "s_ne");
+            assert_mask(va.s_lt(s), f32x4::lt(a, splat), "s_lt");
+            assert_mask(va.s_le(s), f32x4::le(a, splat), "s_le");
+            assert_mask(va.s_gt(s), f32x4::gt(a, splat), "s_gt");
+            assert_mask(va.s_ge(s), f32x4::ge(a, splat), "s_ge");
+
+            assert_eq!(va == vb, a == b, "PartialEq");
+            #[cfg(feature = "num-traits")]
+            assert_eq!(
+                va.partial_cmp(&vb),
+                partial_cmp(f32x4::le(a, b), f32x4::ge(a, b)),
+                "PartialOrd"
+            );
+        }
+
+        #[test]
+        fn selection(a in f32_lanes(), b in f32_lanes(), m in mask_lanes()) {
+            let (va, vb, mask) = (F32x4::from(a), F32x4::from(b), Bx4::from(m));
+            assert_bits(F32x4::select(&mask, &va, &vb), select(m, a, b), "select");
+            assert_bits(va.if_else(&vb, mask), select(m, a, b), "if_else");
+            let mut assigned = va;
+            assigned.masked_assign(&mask, &vb);
+            assert_bits(assigned, select(m, b, a), "masked_assign");
+            assert_bits(va.masked_add(&mask, &vb), select(m, f32x4::add(a, b), a), "masked_add");
+            assert_bits(va.masked_sub(&mask, &vb), select(m, f32x4::sub(a, b), a), "masked_sub");
+            assert_bits(va.masked_mul(&mask, &vb), select(m, f32x4::mul(a, b), a), "masked_mul");
+            #[cfg(feature = "relaxed-simd")]
+            assert_bits(F32x4::relaxed_select(&mask, &va, &vb), select(m, a, b), "relaxed_select");
+        }
+
+        #[test]
+        fn shuffles(a in f32_lanes(), b in f32_lanes(), indices in lane_indices()) {
+            let bits = |v: F32x4| f32x4::to_bits(v.into());
+            check_shuffles!(F32x4::from(a), F32x4::from(b), bits);
+            assert_bits(F32x4::from(a).swizzle(&I32x4::from(indices)), swizzle(a, indices), "swizzle");
+        }
+
+        #[test]
+        fn conversions(a in f32_lanes(), bytes in any::<[u8; 16]>()) {
+            let va = F32x4::from(a);
+            let bits = f32x4::to_bits(a);
+            assert_eq!(<[u32; 4]>::from(va.to_bits()), bits, "to_bits");
+            assert_bits(F32x4::from_bits(U32x4::from(bits)), a, "from_bits");
+            assert_eq!(<[u32; 4]>::from(va.bitcast::<U32x4>()), bits, "bitcast");
+            assert_eq!(va.to_ne_bytes(), to_ne_bytes(bits), "to_ne_bytes");
+            assert_eq!(va.to_le_bytes(), to_le_bytes(bits), "to_le_bytes");
+            assert_eq!(F32x4::from_ne_bytes(bytes).to_ne_bytes(), bytes, "from_ne_bytes");
+            assert_eq!(F32x4::from_le_bytes(bytes).to_le_bytes(), bytes, "from_le_bytes");
+
+            // With relaxed-simd-default only lanes that convert exactly are specified.
+            let relaxed = cfg!(feature = "relaxed-simd-default");
+            let check_i32 = |actual: I32x4, rounded: [f32; 4], message: &str| {
+                let actual = <[i32; 4]>::from(actual);
+                let expected = f32x4::to_i32_trunc(rounded);
+                let in_range = f32x4::i32_in_range(rounded);
+                for i in (0..4).filter(|&i| !relaxed || in_range[i]) {
+                    assert_eq!(actual[i], expected[i], "{} lane {}", message, i);
+                }
+            };
+            let check_u32 = |actual: U32x4, rounded: [f32; 4], message: &str| {
+                let actual = <[u32; 4]>::from(actual);
+                let expected = f32x4::to_u32_trunc(rounded);
+                let in_range = f32x4::u32_in_range(rounded);
+                for i in (0..4).filter(|&i| !relaxed || in_range[i]) {
+                    assert_eq!(actual[i], expected[i], "{} lane {}", message, i);
+                }
+            };
+            check_i32(I32x4::from(va), a, "I32x4::from");
+            check_i32(va.to_i32_trunc(), a, "to_i32_trunc");
+            check_i32(va.to_i32_round(), f32x4::nearest(a), "to_i32_round");
+            check_i32(va.to_i32_floor(), f32x4::floor(a), "to_i32_floor");
+            check_i32(va.to_i32_ceil(), f32x4::ceil(a), "to_i32_ceil");
+            check_u32(U32x4::from(va), a, "U32x4::from");
+            check_u32(va.to_u32_trunc(), a, "to_u32_trunc");
+            check_u32(va.to_u32_round(), f32x4::nearest(a), "to_u32_round");
+            check_u32(va.to_u32_floor(), f32x4::floor(a), "to_u32_floor");
+            check_u32(va.to_u32_ceil(), f32x4::ceil(a), "to_u32_ceil");
+        }
+
+        #[test]
+        fn formatting(a in f32_lanes()) {
+            let va = F32x4::from(a);
+            assert_eq!(va.to_string(), display(a, |x| x.to_string()), "Display");
+            assert_eq!(format!("{va:.2}"), display(a, |x| format!("{x:.2}")), "Display with precision");
+            assert_eq!(format!("{va:e}"), display(a, |x| format!("{x:e}")), "LowerExp");
+            assert_eq!(
+                format!("{va:?}"),
+                format!("F32x4({:?}, {:?}, {:?}, {:?})", a[0], a[1], a[2], a[3]),
+                "Debug"
+            );
+
+            // Display is exact, but NaN loses its sign and payload.
+            let parsed: [f32; 4] = va.to_string().parse::<F32x4>().unwrap().into();
+            for i in 0..4 {
+                if a[i].is_nan() {
+                    assert!(parsed[i].is_nan(), "FromStr lane {i}");
+                } else {
+                    assert_eq!(parsed[i].to_bits(), a[i].to_bits(), "FromStr lane {i}");
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "relaxed-simd")]
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1024))]
+
+        #[test]
+        fn relaxed(a in f32_lanes(), b in f32_lanes(), c in f32_lanes()) {
+            let (va, vb, vc) = (F32x4::from(a), F32x4::from(b), F32x4::from(c));
+            let same = |x: f32, y: f32| x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan());
+
+            let madd: [f32; 4] = va.relaxed_mul_add(vb, vc).into();
+            let nmadd: [f32; 4] = va.relaxed_neg_mul_add(vb, vc).into();
+            let (unfused, fused) = (f32x4::mul_add(a, b, c), f32x4::mul_add_fused(a, b, c));
+            let neg_a = f32x4::neg(a);
+            let (neg_unfused, neg_fused) = (f32x4::mul_add(neg_a, b, c), f32x4::mul_add_fused(neg_a, b, c));
+            let min: [f32; 4] = va.relaxed_min(&vb).into();
+            let max: [f32; 4] = va.relaxed_max(&vb).into();
+            let to_i32 = <[i32; 4]>::from(va.relaxed_to_i32_trunc());
+            let to_u32 = <[u32; 4]>::from(va.relaxed_to_u32_trunc());
+            let (i32_in_range, u32_in_range) = (f32x4::i32_in_range(a), f32x4::u32_in_range(a));
+
+            for i in 0..4 {
+                assert!(same(madd[i], unfused[i]) || same(madd[i], fused[i]), "relaxed_mul_add lane {i}");
+                assert!(same(nmadd[i], neg_unfused[i]) || same(nmadd[i], neg_fused[i]), "relaxed_neg_mul_add lane {i}");
+
+                // NaN lanes and signed zeros may give either input (or a NaN).
+                let special = a[i].is_nan() || b[i].is_nan() || (a[i] == 0.0 && b[i] == 0.0);
+                if special {
+                    assert!(min[i].is_nan() || same(min[i], a[i]) || same(min[i], b[i]), "relaxed_min lane {i}");
+                    assert!(max[i].is_nan() || same(max[i], a[i]) || same(max[i], b[i]), "relaxed_max lane {i}");
+                } else {
+                    assert!(same(min[i], f32_min(a[i], b[i])), "relaxed_min lane {i}");
+                    assert!(same(max[i], f32_max(a[i], b[i])), "relaxed_max lane {i}");
+                }
+
+                if i32_in_range[i] {
+                    assert_eq!(to_i32[i], i32_trunc_sat(a[i]), "relaxed_to_i32_trunc lane {i}");
+                }
+                if u32_in_range[i] {
+                    assert_eq!(to_u32[i], u32_trunc_sat(a[i]), "relaxed_to_u32_trunc lane {i}");
+                }
+            }
+        }
+    }
+}
+
+mod bx4_model {
+    use super::*;
+
+    fn bits(m: Bx4) -> [bool; 4] {
+        m.into()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(256))]
+
+        #[test]
+        fn construction_and_lane_access(a in mask_lanes(), value in any::<bool>(), index in 0..6usize) {
+            let m = Bx4::from(a);
+            assert_mask(Bx4::new(a[0], a[1], a[2], a[3]), a, "new");
+            assert_mask(Bx4::splat(value), [value; 4], "splat");
+            assert_mask(Bx4::default(), [false; 4], "default");
+            assert_mask(Bx4::ALL_TRUE, [true; 4], "ALL_TRUE");
+            assert_mask(Bx4::ALL_FALSE, [false; 4], "ALL_FALSE");
+            assert_eq!(m.extract_lanes(), (a[0], a[1], a[2], a[3]), "extract_lanes");
+            assert_eq!(m.get(index), a.get(index).copied(), "get");
+            assert_eq!(m.into_iter().collect::<Vec<_>>(), a.to_vec(), "into_iter");
+            check_lane_access!(m, a, value, 0, 1, 2, 3);
+
+            let mut set = m;
+            let result = set.try_set_lane(index, value);
+            if index < 4 {
+                assert_eq!(m.extract_lane(index), a[index], "extract_lane");
+                assert_eq!(result, Ok(()), "try_set_lane");
+                assert_mask(set, with_lane(a, index, value), "try_set_lane");
+                let mut set = m;
+                set.set_lane(index, value);
+                assert_mask(set, with_lane(a, index, value), "set_lane");
+            } else {
+                assert_eq!(result, Err(LaneIndexError { index, lanes: 4 }), "try_set_lane out of range");
+                assert_mask(set, a, "try_set_lane out of range");
+            }
+
+            let items: Vec<bool> = a.iter().copied().take(index).collect();
+            assert_mask(items.iter().copied().collect(), from_iter(&items), "from_iter");
+        }
+
+        #[test]
+        fn queries(a in mask_lanes(), bitmask in any::<u8>()) {
+            let m = Bx4::from(a);
+            let lanes = bx4::true_lanes(a);
+            assert_eq!(m.to_bitmask(), bx4::to_bitmask(a), "to_bitmask");
+            assert_mask(Bx4::from_bitmask(bitmask), bx4::from_bitmask(bitmask), "from_bitmask");
+            assert_eq!(<[i32; 4]>::from(m.to_i32x4()), bx4::to_i32(a), "to_i32x4");
+            assert_eq!(<[u32; 4]>::from(m.to_u32x4_ones()), bx4::to_u32_ones(a), "to_u32x4_ones");
+            assert_eq!(m.to_ne_bytes(), bx4::to_ne_bytes(a), "to_ne_bytes");
+            assert_eq!(m.any(), !lanes.is_empty(), "any");
+            assert_eq!(m.all(), lanes.len() == 4, "all");
+            assert_eq!(m.none(), lanes.is_empty(), "none");
+            assert_eq!(m.count_true(), lanes.len(), "count_true");
+            assert_eq!(m.first_true(), lanes.first().copied(), "first_true");
+            assert_eq!(m.last_true(), lanes.last().copied(), "last_true");
+            assert_eq!(m.true_lanes().collect::<Vec<_>>(), lanes, "true_lanes");
+            assert_eq!(m.true_lanes().len(), lanes.len(), "true_lanes len");
+            assert_eq!(
+                m.true_lanes().rev().collect::<Vec<_>>(),
+                lanes.iter().rev().copied().collect::<Vec<_>>(),
+                "true_lanes rev"
+            );
+        }
+
+        #[test]
+        fn bit_ops(a in mask_lanes(), b in mask_lanes(), s in any::<bool>()) {
+            let (ma, mb) = (Bx4::from(a), Bx4::from(b));
+            let splat = [s; 4];
+            assert_mask(ma & mb, bx4::and(a, b), "and");
+            assert_mask(ma | mb, bx4::or(a, b), "or");
+            assert_mask(ma ^ mb, bx4::xor(a, b), "xor");
+            assert_mask(!ma, bx4::not(a), "not");
+            assert_mask(ma & s, bx4::and(a, splat), "and bool");
+            assert_mask(s & ma, bx4::and(splat, a), "bool and");
+            assert_mask(ma | s, bx4::or(a, splat), "or bool");
+            assert_mask(s | ma, bx4::or(splat, a), "bool or");
+            assert_mask(ma ^ s, bx4::xor(a, splat), "xor bool");
+            assert_mask(s ^ ma, bx4::xor(splat, a), "bool xor");
+
+            let mut assigned = ma;
+            assigned &= mb;
+            assigned |= s;
+            assigned ^= mb;
+            assigned &= s;
+            assigned ^= s;
+            assigned |= mb;
+            let expected = bx4::or(
+                bx4::xor(bx4::and(bx4::xor(bx4::or(bx4::and(a, b), splat), b), splat), splat),
+                b,
+            );
+            assert_mask(assigned, expected, "assign ops");
+
+            assert_eq!(ma == mb, a == b, "PartialEq");
+            assert_eq!(hash_of(&ma), hash_of(&bx4::to_bitmask(a)), "Hash");
+        }
+
+        #[test]
+        fn shuffles(a in mask_lanes(), b in mask_lanes(), indices in lane_indices()) {
+            check_shuffles!(Bx4::from(a), Bx4::from(b), bits);
+            assert_mask(Bx4::from(a).swizzle(&I32x4::from(indices)), swizzle(a, indices), "swizzle");
+        }
+
+        #[test]
+        fn formatting(a in mask_lanes()) {
+            let m = Bx4::from(a);
+            assert_eq!(m.to_string(), display(a, |x| x.to_string()), "Display");
+            assert_eq!(format!("{m:?}"), format!("Bx4({}, {}, {}, {})", a[0], a[1], a[2], a[3]), "Debug");
+            assert_eq!(m.to_string().parse::<Bx4>(), Ok(m), "FromStr");
+        }
+    }
+}
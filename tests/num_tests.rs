@@ -0,0 +1,250 @@
+#![cfg(feature = "num-traits")]
+
+use num_traits::{Bounded, Float, FloatConst, Num, NumCast, One, Signed, ToPrimitive, Zero};
+use std::num::FpCategory;
+use wasm_bindgen_test::*;
+use wasm_simd::{F32x4, I32x4, ParseVectorError, U32x4};
+
+fn sum_of_squares<T: Num + Copy>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, &v| acc + v * v)
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_generic_num_code() {
+    let values = [I32x4::new(1, 2, 3, 4), I32x4::splat(2)];
+    assert_eq!(
+        sum_of_squares(&values),
+        I32x4::new(5, 8, 13, 20),
+        "Generic sum of squares failed"
+    );
+    let values = [F32x4::new(0.5, 1.0, 2.0, 3.0)];
+    assert_eq!(
+        sum_of_squares(&values),
+        F32x4::new(0.25, 1.0, 4.0, 9.0),
+        "Generic float sum of squares failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_identities_and_bounds() {
+    assert!(I32x4::zero().is_zero(), "zero should be zero");
+    assert!(
+        !I32x4::new(0, 0, 1, 0).is_zero(),
+        "One lane set is not zero"
+    );
+    assert!(U32x4::one().is_one(), "one should be one");
+    assert_eq!(F32x4::one(), F32x4::ONE, "F32x4 one failed");
+    assert_eq!(
+        <I32x4 as Bounded>::min_value(),
+        I32x4::splat(i32::MIN),
+        "Bounded min failed"
+    );
+    assert_eq!(
+        <U32x4 as Bounded>::max_value(),
+        U32x4::splat(u32::MAX),
+        "Bounded max failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_from_str_radix() {
+    assert_eq!(
+        I32x4::from_str_radix("[ff, -10, 7, 0]", 16),
+        Ok(I32x4::new(255, -16, 7, 0)),
+        "Hex parse failed"
+    );
+    assert_eq!(
+        U32x4::from_str_radix("101 1 0 11", 2),
+        Ok(U32x4::new(5, 1, 0, 3)),
+        "Binary parse failed"
+    );
+    assert_eq!(
+        I32x4::from_str_radix("1, 2, 9, 4", 8),
+        Err(ParseVectorError::InvalidLane(2)),
+        "Invalid digit should be reported"
+    );
+    assert_eq!(
+        F32x4::from_str_radix("1, 2", 10),
+        Err(ParseVectorError::WrongLaneCount {
+            expected: 4,
+            found: 2
+        }),
+        "Lane count should be reported"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_signed() {
+    let v = I32x4::new(-5, 0, 7, i32::MIN);
+    assert_eq!(
+        Signed::abs(&v),
+        I32x4::new(5, 0, 7, i32::MIN),
+        "Signed abs failed"
+    );
+    assert_eq!(v.signum(), I32x4::new(-1, 0, 1, -1), "Signed signum failed");
+    assert_eq!(
+        v.abs_sub(&I32x4::new(-7, 1, 2, 0)),
+        I32x4::new(2, 0, 5, 0),
+        "Signed abs_sub failed"
+    );
+    assert!(I32x4::new(1, 2, 3, 4).is_positive(), "All lanes positive");
+    assert!(!v.is_negative(), "Mixed lanes are not negative");
+
+    let f = F32x4::new(-2.0, 0.0, -0.0, f32::NAN);
+    let signum = Signed::signum(&f);
+    assert_eq!(
+        &<[f32; 4]>::from(signum)[..3],
+        &[-1.0, 1.0, -1.0],
+        "Float signum failed"
+    );
+    assert!(signum.extract::<3>().is_nan(), "NaN signum should be NaN");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_casts() {
+    assert_eq!(I32x4::splat(7).to_i64(), Some(7), "Uniform cast failed");
+    assert_eq!(
+        I32x4::new(7, 7, 7, 8).to_i64(),
+        None,
+        "Mixed lanes should not cast"
+    );
+    assert_eq!(I32x4::splat(-1).to_u32(), None, "Out of range cast failed");
+    assert_eq!(F32x4::splat(2.5).to_f64(), Some(2.5), "Float cast failed");
+    assert_eq!(
+        <F32x4 as NumCast>::from(3u8),
+        Some(F32x4::splat(3.0)),
+        "NumCast failed"
+    );
+    assert_eq!(
+        <U32x4 as NumCast>::from(-1i32),
+        None,
+        "NumCast out of range failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_float_rounding() {
+    let v = F32x4::new(2.5, -2.5, 0.49999997, -1.2);
+    assert_eq!(
+        Float::round(v),
+        F32x4::new(3.0, -3.0, 0.0, -1.0),
+        "round should go away from zero"
+    );
+    assert_eq!(
+        Float::fract(F32x4::new(1.25, -1.25, 3.0, 0.5)),
+        F32x4::new(0.25, -0.25, 0.0, 0.5),
+        "fract failed"
+    );
+    assert_eq!(
+        Float::round(F32x4::new(8388609.0, f32::INFINITY, -0.4, 0.5)),
+        F32x4::new(8388609.0, f32::INFINITY, -0.0, 1.0),
+        "round edge cases failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_float_min_max_ignore_nan() {
+    let a = F32x4::new(1.0, f32::NAN, 5.0, f32::NAN);
+    let b = F32x4::new(2.0, 3.0, f32::NAN, f32::NAN);
+    let max: [f32; 4] = Float::max(a, b).into();
+    let min: [f32; 4] = Float::min(a, b).into();
+    assert_eq!(&max[..3], &[2.0, 3.0, 5.0], "Float max failed");
+    assert_eq!(&min[..3], &[1.0, 3.0, 5.0], "Float min failed");
+    assert!(
+        max[3].is_nan() && min[3].is_nan(),
+        "Both NaN should stay NaN"
+    );
+
+    assert_eq!(
+        Float::clamp(
+            F32x4::new(-5.0, 0.5, 5.0, 1.0),
+            F32x4::ZERO,
+            F32x4::splat(2.0)
+        ),
+        F32x4::new(0.0, 0.5, 2.0, 1.0),
+        "clamp failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_float_predicates() {
+    let v = F32x4::new(1.0, f32::NAN, 0.0, 1e-40);
+    assert!(v.is_nan(), "Any NaN lane makes is_nan true");
+    assert!(!v.is_finite(), "NaN lane is not finite");
+    assert!(v.is_subnormal(), "Any subnormal lane");
+    assert_eq!(v.classify(), FpCategory::Nan, "NaN classify failed");
+    assert_eq!(
+        F32x4::new(1.0, 0.0, 2.0, 3.0).classify(),
+        FpCategory::Zero,
+        "Zero classify failed"
+    );
+    assert!(F32x4::new(1.0, 2.0, 3.0, 4.0).is_normal(), "All normal");
+    assert!(
+        !F32x4::new(1.0, -0.0, 3.0, 4.0).is_sign_positive(),
+        "-0.0 is sign negative"
+    );
+    assert!(
+        Float::is_sign_negative(F32x4::splat(-1.0)),
+        "All lanes negative"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_float_lanewise_functions() {
+    let v = F32x4::new(0.0, 1.0, 2.0, 4.0);
+    let expected = [1.0, 1.0f32.exp(), 2.0f32.exp(), 4.0f32.exp()];
+    for (lane, want) in <[f32; 4]>::from(Float::exp(v)).into_iter().zip(expected) {
+        assert!((lane - want).abs() <= want * 1e-6, "exp failed");
+    }
+    assert_eq!(
+        Float::powi(v, 2),
+        F32x4::new(0.0, 1.0, 4.0, 16.0),
+        "powi failed"
+    );
+    assert_eq!(
+        Float::powf(v, F32x4::splat(0.5)),
+        F32x4::new(0.0, 1.0, 2.0f32.sqrt(), 2.0),
+        "powf failed"
+    );
+    assert_eq!(
+        Float::copysign(F32x4::splat(3.0), F32x4::new(-1.0, 1.0, -0.0, 0.0)),
+        F32x4::new(-3.0, 3.0, -3.0, 3.0),
+        "copysign failed"
+    );
+    assert_eq!(
+        Float::mul_add(F32x4::splat(2.0), F32x4::splat(3.0), F32x4::ONE),
+        F32x4::splat(7.0),
+        "mul_add failed"
+    );
+    assert_eq!(F32x4::PI(), F32x4::splat(std::f32::consts::PI), "PI failed");
+    assert_eq!(
+        Float::to_degrees(F32x4::PI()),
+        F32x4::splat(180.0),
+        "to_degrees failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_rem() {
+    assert_eq!(
+        I32x4::new(10, -7, 9, 5) % I32x4::new(3, 2, 9, 7),
+        I32x4::new(1, -1, 0, 5),
+        "Rem by vector failed"
+    );
+    assert_eq!(
+        U32x4::new(10, 11, 12, 13) % U32x4::splat(4),
+        U32x4::new(2, 3, 0, 1),
+        "Unsigned rem failed"
+    );
+    assert_eq!(
+        F32x4::new(5.5, -5.5, 1.0, 7.0) % F32x4::splat(2.0),
+        F32x4::new(1.5, -1.5, 1.0, 1.0),
+        "Float rem failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic]
+fn test_rem_by_zero() {
+    let _ = I32x4::new(1, 2, 3, 4) % I32x4::new(1, 0, 1, 1);
+}
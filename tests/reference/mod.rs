@@ -0,0 +1,442 @@
+//! Lane-by-lane scalar reference for the WebAssembly SIMD semantics the vector types promise.
+//!
+//! Every backend has to reproduce these results bit for bit, including NaN payloads.
+
+#![allow(dead_code)]
+
+/// The NaN that wasm `f32x4.min`/`f32x4.max` produce for a NaN lane.
+pub const CANONICAL_NAN: u32 = 0x7fc0_0000;
+
+pub fn map<T: Copy, U>(a: [T; 4], f: impl Fn(T) -> U) -> [U; 4] {
+    a.map(f)
+}
+
+pub fn zip<T: Copy, U>(a: [T; 4], b: [T; 4], f: impl Fn(T, T) -> U) -> [U; 4] {
+    [f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])]
+}
+
+pub fn f32_min(a: f32, b: f32) -> f32 {
+    if a.is_nan() || b.is_nan() {
+        f32::from_bits(CANONICAL_NAN)
+    } else if a == b {
+        // -0.0 is smaller than 0.0
+        f32::from_bits(a.to_bits() | b.to_bits())
+    } else if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+pub fn f32_max(a: f32, b: f32) -> f32 {
+    if a.is_nan() || b.is_nan() {
+        f32::from_bits(CANONICAL_NAN)
+    } else if a == b {
+        f32::from_bits(a.to_bits() & b.to_bits())
+    } else if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Rounding keeps the NaN payload but sets the quiet bit.
+fn round_with(a: f32, f: impl Fn(f32) -> f32) -> f32 {
+    if a.is_nan() {
+        f32::from_bits(a.to_bits() | 0x0040_0000)
+    } else {
+        f(a)
+    }
+}
+
+pub fn f32_floor(a: f32) -> f32 {
+    round_with(a, f32::floor)
+}
+
+pub fn f32_ceil(a: f32) -> f32 {
+    round_with(a, f32::ceil)
+}
+
+pub fn f32_trunc(a: f32) -> f32 {
+    round_with(a, f32::trunc)
+}
+
+pub fn f32_nearest(a: f32) -> f32 {
+    round_with(a, f32::round_ties_even)
+}
+
+/// `as` casts saturate and map NaN to 0, like `i32x4.trunc_sat_f32x4_s`.
+pub fn i32_trunc_sat(a: f32) -> i32 {
+    a as i32
+}
+
+pub fn u32_trunc_sat(a: f32) -> u32 {
+    a as u32
+}
+
+pub fn mask(b: bool) -> i32 {
+    if b {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Lane swizzle: indices outside `0..4` (including negative ones) select zero.
+pub fn swizzle<T: Copy + Default>(a: [T; 4], indices: [i32; 4]) -> [T; 4] {
+    indices.map(|i| a.get(i as u32 as usize).copied().unwrap_or_default())
+}
+
+pub fn select<T: Copy>(mask: [bool; 4], if_true: [T; 4], if_false: [T; 4]) -> [T; 4] {
+    core::array::from_fn(|i| if mask[i] { if_true[i] } else { if_false[i] })
+}
+
+/// Lanes `0..4` come from `a` and `4..8` from `b`.
+pub fn shuffle<T: Copy>(a: [T; 4], b: [T; 4], indices: [usize; 4]) -> [T; 4] {
+    indices.map(|i| if i < 4 { a[i] } else { b[i - 4] })
+}
+
+pub fn rotate_left<T: Copy>(a: [T; 4], n: usize) -> [T; 4] {
+    core::array::from_fn(|i| a[(i + n) % 4])
+}
+
+pub fn rotate_right<T: Copy>(a: [T; 4], n: usize) -> [T; 4] {
+    rotate_left(a, 4 - n % 4)
+}
+
+pub fn with_lane<T: Copy>(mut a: [T; 4], index: usize, value: T) -> [T; 4] {
+    a[index] = value;
+    a
+}
+
+pub fn fold<T: Copy>(a: [T; 4], init: T, f: impl Fn(T, T) -> T) -> T {
+    a.into_iter().fold(init, f)
+}
+
+/// The lane-wise product order used by `PartialOrd`.
+pub fn partial_cmp(le: [bool; 4], ge: [bool; 4]) -> Option<core::cmp::Ordering> {
+    use core::cmp::Ordering;
+    match (le.iter().all(|&x| x), ge.iter().all(|&x| x)) {
+        (true, true) => Some(Ordering::Equal),
+        (true, false) => Some(Ordering::Less),
+        (false, true) => Some(Ordering::Greater),
+        (false, false) => None,
+    }
+}
+
+/// The first four items, padding missing lanes with the default value.
+pub fn from_iter<T: Copy + Default>(items: &[T]) -> [T; 4] {
+    core::array::from_fn(|i| items.get(i).copied().unwrap_or_default())
+}
+
+pub fn to_ne_bytes(bits: [u32; 4]) -> [u8; 16] {
+    let mut bytes = [0; 16];
+    for (chunk, lane) in bytes.chunks_exact_mut(4).zip(bits) {
+        chunk.copy_from_slice(&lane.to_ne_bytes());
+    }
+    bytes
+}
+
+pub fn to_le_bytes(bits: [u32; 4]) -> [u8; 16] {
+    let mut bytes = [0; 16];
+    for (chunk, lane) in bytes.chunks_exact_mut(4).zip(bits) {
+        chunk.copy_from_slice(&lane.to_le_bytes());
+    }
+    bytes
+}
+
+/// `[v1, v2, v3, v4]` with every lane formatted by `fmt`.
+pub fn display<T: Copy>(a: [T; 4], fmt: impl Fn(T) -> String) -> String {
+    format!("[{}]", a.map(fmt).join(", "))
+}
+
+macro_rules! int_model {
+    ($module:ident, $type:ty) => {
+        pub mod $module {
+            use super::*;
+
+            pub fn add(a: [$type; 4], b: [$type; 4]) -> [$type; 4] {
+                zip(a, b, <$type>::wrapping_add)
+            }
+
+            pub fn sub(a: [$type; 4], b: [$type; 4]) -> [$type; 4] {
+                zip(a, b, <$type>::wrapping_sub)
+            }
+
+            pub fn mul(a: [$type; 4], b: [$type; 4]) -> [$type; 4] {
+                zip(a, b, <$type>::wrapping_mul)
+            }
+
+            /// `None` when a lane would panic (division by zero or overflow).
+            pub fn div(a: [$type; 4], b: [$type; 4]) -> Option<[$type; 4]> {
+                let lanes = zip(a, b, <$type>::checked_div);
+                lanes
+                    .iter()
+                    .all(Option::is_some)
+                    .then(|| lanes.map(Option::unwrap))
+            }
+
+            pub fn rem(a: [$type; 4], b: [$type; 4]) -> Option<[$type; 4]> {
+                let lanes = zip(a, b, <$type>::checked_rem);
+                lanes
+                    .iter()
+                    .all(Option::is_some)
+                    .then(|| lanes.map(Option::unwrap))
+            }
+
+            pub fn min(a: [$type; 4], b: [$type; 4]) -> [$type; 4] {
+                zip(a, b, Ord::min)
+            }
+
+            pub fn max(a: [$type; 4], b: [$type; 4]) -> [$type; 4] {
+                zip(a, b, Ord::max)
+            }
+
+            /// Shift amounts are taken modulo 32.
+            pub fn shl(a: [$type; 4], amt: u32) -> [$type; 4] {
+                map(a, |x| x.wrapping_shl(amt))
+            }
+
+            pub fn shr(a: [$type; 4], amt: u32) -> [$type; 4] {
+                map(a, |x| x.wrapping_shr(amt))
+            }
+
+            pub fn eq(a: [$type; 4], b: [$type; 4]) -> [bool; 4] {
+                zip(a, b, |x, y| x == y)
+            }
+
+            pub fn ne(a: [$type; 4], b: [$type; 4]) -> [bool; 4] {
+                zip(a, b, |x, y| x != y)
+            }
+
+            pub fn lt(a: [$type; 4], b: [$type; 4]) -> [bool; 4] {
+                zip(a, b, |x, y| x < y)
+            }
+
+            pub fn le(a: [$type; 4], b: [$type; 4]) -> [bool; 4] {
+                zip(a, b, |x, y| x <= y)
+            }
+
+            pub fn gt(a: [$type; 4], b: [$type; 4]) -> [bool; 4] {
+                zip(a, b, |x, y| x > y)
+            }
+
+            pub fn ge(a: [$type; 4], b: [$type; 4]) -> [bool; 4] {
+                zip(a, b, |x, y| x >= y)
+            }
+
+            pub fn nonzero_mask(a: [$type; 4]) -> [bool; 4] {
+                map(a, |x| x != 0)
+            }
+
+            pub fn reduce_add(a: [$type; 4]) -> $type {
+                fold(a, 0, <$type>::wrapping_add)
+            }
+
+            pub fn reduce_mul(a: [$type; 4]) -> $type {
+                fold(a, 1, <$type>::wrapping_mul)
+            }
+
+            pub fn reduce_min(a: [$type; 4]) -> $type {
+                fold(a, <$type>::MAX, Ord::min)
+            }
+
+            pub fn reduce_max(a: [$type; 4]) -> $type {
+                fold(a, <$type>::MIN, Ord::max)
+            }
+
+            /// Round to nearest, ties to even, like `f32x4.convert_i32x4_s/u`.
+            pub fn to_f32(a: [$type; 4]) -> [f32; 4] {
+                map(a, |x| x as f32)
+            }
+
+            pub fn to_bits(a: [$type; 4]) -> [u32; 4] {
+                map(a, |x| x as u32)
+            }
+
+            pub fn from_bits(bits: [u32; 4]) -> [$type; 4] {
+                map(bits, |x| x as $type)
+            }
+        }
+    };
+}
+
+int_model!(i32x4, i32);
+int_model!(u32x4, u32);
+
+pub mod i32x4_signed {
+    use super::*;
+
+    pub fn neg(a: [i32; 4]) -> [i32; 4] {
+        map(a, i32::wrapping_neg)
+    }
+
+    pub fn abs(a: [i32; 4]) -> [i32; 4] {
+        map(a, i32::wrapping_abs)
+    }
+}
+
+pub mod f32x4 {
+    use super::*;
+
+    pub fn add(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        zip(a, b, |x, y| x + y)
+    }
+
+    pub fn sub(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        zip(a, b, |x, y| x - y)
+    }
+
+    pub fn mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        zip(a, b, |x, y| x * y)
+    }
+
+    pub fn div(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        zip(a, b, |x, y| x / y)
+    }
+
+    pub fn rem(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        zip(a, b, |x, y| x % y)
+    }
+
+    /// `self * a + b` with the product rounded first.
+    pub fn mul_add(v: [f32; 4], a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        add(mul(v, a), b)
+    }
+
+    /// `self * a + b` with a single rounding.
+    pub fn mul_add_fused(v: [f32; 4], a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        core::array::from_fn(|i| v[i].mul_add(a[i], b[i]))
+    }
+
+    pub fn min(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        zip(a, b, f32_min)
+    }
+
+    pub fn max(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        zip(a, b, f32_max)
+    }
+
+    /// Sign-bit operations, which leave NaN payloads untouched.
+    pub fn neg(a: [f32; 4]) -> [f32; 4] {
+        map(a, |x| -x)
+    }
+
+    pub fn abs(a: [f32; 4]) -> [f32; 4] {
+        map(a, f32::abs)
+    }
+
+    pub fn sqrt(a: [f32; 4]) -> [f32; 4] {
+        map(a, f32::sqrt)
+    }
+
+    pub fn floor(a: [f32; 4]) -> [f32; 4] {
+        map(a, f32_floor)
+    }
+
+    pub fn ceil(a: [f32; 4]) -> [f32; 4] {
+        map(a, f32_ceil)
+    }
+
+    pub fn trunc(a: [f32; 4]) -> [f32; 4] {
+        map(a, f32_trunc)
+    }
+
+    pub fn nearest(a: [f32; 4]) -> [f32; 4] {
+        map(a, f32_nearest)
+    }
+
+    pub fn eq(a: [f32; 4], b: [f32; 4]) -> [bool; 4] {
+        zip(a, b, |x, y| x == y)
+    }
+
+    pub fn ne(a: [f32; 4], b: [f32; 4]) -> [bool; 4] {
+        zip(a, b, |x, y| x != y)
+    }
+
+    pub fn lt(a: [f32; 4], b: [f32; 4]) -> [bool; 4] {
+        zip(a, b, |x, y| x < y)
+    }
+
+    pub fn le(a: [f32; 4], b: [f32; 4]) -> [bool; 4] {
+        zip(a, b, |x, y| x <= y)
+    }
+
+    pub fn gt(a: [f32; 4], b: [f32; 4]) -> [bool; 4] {
+        zip(a, b, |x, y| x > y)
+    }
+
+    pub fn ge(a: [f32; 4], b: [f32; 4]) -> [bool; 4] {
+        zip(a, b, |x, y| x >= y)
+    }
+
+    pub fn to_i32_trunc(a: [f32; 4]) -> [i32; 4] {
+        map(a, i32_trunc_sat)
+    }
+
+    pub fn to_u32_trunc(a: [f32; 4]) -> [u32; 4] {
+        map(a, u32_trunc_sat)
+    }
+
+    /// Lanes the relaxed truncations convert exactly: not NaN and in range after truncation.
+    pub fn i32_in_range(a: [f32; 4]) -> [bool; 4] {
+        map(a, |x| x > -2_147_483_904.0 && x < 2_147_483_648.0)
+    }
+
+    pub fn u32_in_range(a: [f32; 4]) -> [bool; 4] {
+        map(a, |x| x > -1.0 && x < 4_294_967_296.0)
+    }
+
+    pub fn to_bits(a: [f32; 4]) -> [u32; 4] {
+        map(a, f32::to_bits)
+    }
+
+    pub fn from_bits(bits: [u32; 4]) -> [f32; 4] {
+        map(bits, f32::from_bits)
+    }
+}
+
+pub mod bx4 {
+    use super::*;
+
+    pub fn and(a: [bool; 4], b: [bool; 4]) -> [bool; 4] {
+        zip(a, b, |x, y| x & y)
+    }
+
+    pub fn or(a: [bool; 4], b: [bool; 4]) -> [bool; 4] {
+        zip(a, b, |x, y| x | y)
+    }
+
+    pub fn xor(a: [bool; 4], b: [bool; 4]) -> [bool; 4] {
+        zip(a, b, |x, y| x ^ y)
+    }
+
+    pub fn not(a: [bool; 4]) -> [bool; 4] {
+        map(a, |x| !x)
+    }
+
+    /// Bit `i` holds lane `i`.
+    pub fn to_bitmask(a: [bool; 4]) -> u8 {
+        a.iter().rev().fold(0, |acc, &x| (acc << 1) | x as u8)
+    }
+
+    pub fn from_bitmask(bitmask: u8) -> [bool; 4] {
+        core::array::from_fn(|i| bitmask & (1 << i) != 0)
+    }
+
+    pub fn to_i32(a: [bool; 4]) -> [i32; 4] {
+        map(a, mask)
+    }
+
+    pub fn to_u32_ones(a: [bool; 4]) -> [u32; 4] {
+        map(a, u32::from)
+    }
+
+    pub fn true_lanes(a: [bool; 4]) -> Vec<usize> {
+        (0..4).filter(|&i| a[i]).collect()
+    }
+
+    pub fn to_ne_bytes(a: [bool; 4]) -> [u8; 16] {
+        super::to_ne_bytes(map(a, |x| mask(x) as u32))
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "relaxed-simd")]
+
+// Only inputs where every relaxed behavior agrees are checked here.
+
+use wasm_bindgen_test::*;
+use wasm_simd::{Bx4, F32x4, I32x4, U32x4};
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_relaxed_mul_add() {
+    let v = F32x4::new(1.0, 2.0, -3.0, 0.5);
+    assert_eq!(
+        v.relaxed_mul_add(F32x4::splat(2.0), F32x4::ONE),
+        F32x4::new(3.0, 5.0, -5.0, 2.0),
+        "relaxed_mul_add failed"
+    );
+    assert_eq!(
+        v.relaxed_neg_mul_add(F32x4::splat(2.0), F32x4::ONE),
+        F32x4::new(-1.0, -3.0, 7.0, 0.0),
+        "relaxed_neg_mul_add failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_relaxed_min_max() {
+    let a = F32x4::new(1.0, -2.0, 3.0, f32::INFINITY);
+    let b = F32x4::new(0.5, 4.0, 3.0, -1.0);
+    assert_eq!(
+        a.relaxed_min(&b),
+        F32x4::new(0.5, -2.0, 3.0, -1.0),
+        "relaxed_min failed"
+    );
+    assert_eq!(
+        a.relaxed_max(&b),
+        F32x4::new(1.0, 4.0, 3.0, f32::INFINITY),
+        "relaxed_max failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_relaxed_trunc() {
+    let v = F32x4::new(1.9, -1.9, 0.0, 1000.5);
+    assert_eq!(
+        v.relaxed_to_i32_trunc(),
+        I32x4::new(1, -1, 0, 1000),
+        "relaxed_to_i32_trunc failed"
+    );
+    assert_eq!(
+        F32x4::new(1.9, 0.0, 3e9, 7.0).relaxed_to_u32_trunc(),
+        U32x4::new(1, 0, 3_000_000_000, 7),
+        "relaxed_to_u32_trunc failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_relaxed_select_and_swizzle() {
+    let a = I32x4::new(1, 2, 3, 4);
+    let b = I32x4::new(5, 6, 7, 8);
+    let mask = Bx4::new(true, false, false, true);
+    assert_eq!(
+        I32x4::relaxed_select(&mask, &a, &b),
+        I32x4::select(&mask, &a, &b),
+        "relaxed_select should match select for Bx4 masks"
+    );
+    assert_eq!(
+        a.relaxed_swizzle(&I32x4::new(3, 0, 0, 2)),
+        I32x4::new(4, 1, 1, 3),
+        "relaxed_swizzle failed"
+    );
+    assert_eq!(
+        F32x4::new(1.0, 2.0, 3.0, 4.0).relaxed_swizzle(&I32x4::new(1, 1, 2, 3)),
+        F32x4::new(2.0, 2.0, 3.0, 4.0),
+        "F32x4 relaxed_swizzle failed"
+    );
+}
+
+#[cfg(feature = "relaxed-simd-default")]
+#[wasm_bindgen_test(unsupported = test)]
+fn test_relaxed_default_mode() {
+    let v = F32x4::new(1.5, -1.5, 2.0, 100.0);
+    assert_eq!(I32x4::from(v), I32x4::new(1, -1, 2, 100), "From failed");
+    assert_eq!(
+        v.mul_add(F32x4::splat(2.0), F32x4::ONE),
+        F32x4::new(4.0, -2.0, 5.0, 201.0),
+        "mul_add failed"
+    );
+    let mask = Bx4::new(false, true, false, true);
+    assert_eq!(
+        v.if_else(&F32x4::ZERO, mask),
+        F32x4::new(0.0, -1.5, 0.0, 100.0),
+        "if_else failed"
+    );
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_test::*;
+use wasm_simd::{Bx4, F32x4, I32x4, U32x4};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct State {
+    position: F32x4,
+    ids: I32x4,
+    flags: Bx4,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct CompactState {
+    #[serde(with = "wasm_simd::compact")]
+    position: F32x4,
+    #[serde(with = "wasm_simd::compact")]
+    flags: Bx4,
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_json_round_trip() {
+    let state = State {
+        position: F32x4::new(1.5, -2.0, 0.0, 4.25),
+        ids: I32x4::new(1, 2, 3, -4),
+        flags: Bx4::new(true, false, false, true),
+    };
+    let json = serde_json::to_string(&state).unwrap();
+    assert_eq!(
+        json,
+        r#"{"position":[1.5,-2.0,0.0,4.25],"ids":[1,2,3,-4],"flags":[true,false,false,true]}"#,
+        "JSON serialization mismatch"
+    );
+    let back: State = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, state, "JSON round trip failed");
+
+    let u: U32x4 = serde_json::from_str("[1, 2, 3, 4294967295]").unwrap();
+    assert_eq!(
+        u,
+        U32x4::new(1, 2, 3, u32::MAX),
+        "U32x4 deserialization failed"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_wrong_length() {
+    let short = serde_json::from_str::<I32x4>("[1, 2, 3]").unwrap_err();
+    assert!(
+        short.to_string().contains("invalid length 3"),
+        "unexpected error for short input: {}",
+        short
+    );
+
+    let long = serde_json::from_str::<I32x4>("[1, 2, 3, 4, 5, 6]").unwrap_err();
+    assert!(
+        long.to_string().contains("invalid length 6"),
+        "unexpected error for long input: {}",
+        long
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_bincode_round_trip() {
+    let v = I32x4::new(1, -2, 3, i32::MIN);
+    let bytes = bincode::serialize(&v).unwrap();
+    assert_eq!(
+        bytes.len(),
+        16,
+        "bincode should store lanes without a length prefix"
+    );
+    let back: I32x4 = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back, v, "bincode round trip failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_compact_representation() {
+    let state = CompactState {
+        position: F32x4::new(1.0, 2.0, 3.0, 4.0),
+        flags: Bx4::new(false, true, true, false),
+    };
+    let bytes = bincode::serialize(&state).unwrap();
+    let back: CompactState = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back, state, "compact bincode round trip failed");
+
+    let json = serde_json::to_string(&state).unwrap();
+    let back: CompactState = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, state, "compact JSON round trip failed");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_compact_rejects_invalid_input() {
+    let bad_len = r#"{"position":[0,0,0,0,0,0,0,0],"flags":[]}"#;
+    let err = serde_json::from_str::<CompactState>(bad_len).unwrap_err();
+    assert!(
+        err.to_string().contains("invalid length 8"),
+        "unexpected error for wrong byte count: {}",
+        err
+    );
+
+    let bad_mask = format!(
+        r#"{{"position":{:?},"flags":{:?}}}"#,
+        [0u8; 16],
+        [1u8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert!(
+        serde_json::from_str::<CompactState>(&bad_mask).is_err(),
+        "partial mask lane should be rejected"
+    );
+}
@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+use wasm_bindgen_test::*;
+use wasm_simd::prelude::*;
+
+// Written once against the generic types, so it runs unchanged for every lane type and width.
+fn scale_and_offset<T, const N: usize>(v: Simd<T, N>, scale: T, offset: T) -> Simd<T, N>
+where
+    T: SupportedLanes<N>,
+{
+    let mut result = v * scale;
+    result += offset;
+    result
+}
+
+fn negate_and_shift<T, const N: usize>(v: Simd<T, N>) -> Simd<T, N>
+where
+    T: SignedLanes<N> + IntegerLanes<N>,
+{
+    let mut result = -v << 2;
+    result >>= 1;
+    result
+}
+
+fn lane_sum<T, const N: usize>(vectors: &[Simd<T, N>]) -> [T; N]
+where
+    T: SupportedLanes<N>,
+{
+    vectors.iter().sum::<Simd<T, N>>().to_array()
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_aliases_are_simd() {
+    let v: Simd<i32, 4> = I32x4::new(1, 2, 3, 4);
+    assert_eq!(v, I32x4::from_array([1, 2, 3, 4]), "I32x4 is Simd<i32, 4>");
+
+    let w: F32x8 = Simd::<f32, 8>::from_array([0.5; 8]);
+    assert_eq!(w, F32x8::splat(0.5), "F32x8 is Simd<f32, 8>");
+
+    let mask: Mask<i32, 4> = I32x4::new(1, 0, 3, 0).nonzero_mask();
+    assert_eq!(
+        mask,
+        Bx4::new(true, false, true, false),
+        "Bx4 is Mask<i32, 4>"
+    );
+
+    assert_eq!(I32x4::LANES, 4, "I32x4 lane count");
+    assert_eq!(U32x16::LANES, 16, "U32x16 lane count");
+    assert_eq!(Bx8::LANES, 8, "Bx8 lane count");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_generic_operators() {
+    assert_eq!(
+        scale_and_offset(I32x4::new(1, -2, 3, -4), 3, 1).to_array(),
+        [4, -5, 10, -11],
+        "Generic ops on I32x4"
+    );
+    assert_eq!(
+        scale_and_offset(U32x8::from_iter(0..8), 2, 5).to_array(),
+        [5, 7, 9, 11, 13, 15, 17, 19],
+        "Generic ops on U32x8"
+    );
+    assert_eq!(
+        scale_and_offset(F32x16::splat(1.5), 2.0, -0.5),
+        F32x16::splat(2.5),
+        "Generic ops on F32x16"
+    );
+
+    assert_eq!(
+        (10 - I32x8::from_iter(0..8)).to_array(),
+        [10, 9, 8, 7, 6, 5, 4, 3],
+        "Scalar on the left"
+    );
+    assert_eq!(
+        (U32x4::splat(17) - 5) / U32x4::new(1, 2, 3, 4),
+        U32x4::new(12, 6, 4, 3),
+        "Lane-wise subtraction and division"
+    );
+
+    let mut v = F32x4::splat(8.0);
+    v /= 2.0;
+    v -= F32x4::ONE;
+    v *= 0.5;
+    assert_eq!(v, F32x4::splat(1.5), "Assign ops");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_generic_conversions() {
+    let v = Simd::<i32, 8>::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(v.as_array(), &[1, 2, 3, 4, 5, 6, 7, 8], "as_array");
+    assert_eq!(v[6], 7, "Index");
+    assert_eq!(
+        v.into_iter().rev().collect::<Vec<_>>(),
+        [8, 7, 6, 5, 4, 3, 2, 1],
+        "IntoIterator"
+    );
+    assert_eq!(
+        lane_sum(&[I32x4::ONE, I32x4::new(1, 2, 3, 4)]),
+        [2, 3, 4, 5],
+        "Sum"
+    );
+    assert_eq!(
+        [F32x4::splat(2.0), F32x4::splat(3.0)]
+            .into_iter()
+            .product::<F32x4>(),
+        F32x4::splat(6.0),
+        "Product"
+    );
+    assert_eq!(Simd::<u32, 16>::default(), U32x16::ZERO, "Default is zero");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_generic_masks() {
+    let a = Mask::<i32, 8>::from_array([true, true, false, false, true, false, true, false]);
+    let b = Bx8::from_bitmask(0b1111_0000);
+    assert_eq!((a & b).to_bitmask(), 0b0101_0000, "and");
+    assert_eq!((a | b).to_bitmask(), 0b1111_0011, "or");
+    assert_eq!((a ^ b).to_bitmask(), 0b1010_0011, "xor");
+    assert_eq!((!a).to_bitmask(), 0b1010_1100, "not");
+    assert_eq!((true & a), a, "bool on the left");
+
+    let mut c = Mask::<i32, 4>::default();
+    c |= true;
+    c ^= Bx4::new(false, true, false, false);
+    assert_eq!(c.to_array(), [true, false, true, true], "Assign ops");
+    assert_eq!(
+        Bx16::from_iter([true, false, true]).to_bitmask(),
+        0b101,
+        "FromIterator"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_generated_types_are_consistent() {
+    let mask = Bx4::new(true, false, true, false);
+    assert_eq!(
+        I32x4::new(1, 2, 3, 4).if_else(&I32x4::ZERO, &mask),
+        I32x4::new(1, 0, 3, 0),
+        "I32x4::if_else"
+    );
+    assert_eq!(
+        U32x4::new(1, 2, 3, 4).if_else(&U32x4::ZERO, mask),
+        U32x4::new(1, 0, 3, 0),
+        "U32x4::if_else"
+    );
+    assert_eq!(
+        F32x4::new(1.0, 2.0, 3.0, 4.0).if_else(&F32x4::ZERO, mask),
+        F32x4::new(1.0, 0.0, 3.0, 0.0),
+        "F32x4::if_else"
+    );
+
+    assert_eq!(
+        I32x4::new(1, 2, 3, 4).fold(|a, b| a * b, 1),
+        24,
+        "I32x4::fold"
+    );
+    assert_eq!(
+        U32x4::new(1, 2, 3, 4).fold(|a, b| a * b, 1),
+        24,
+        "U32x4::fold"
+    );
+    assert_eq!(
+        F32x4::new(1.0, 2.0, 3.0, 4.0).fold(f32::max, f32::MIN),
+        4.0,
+        "F32x4::fold"
+    );
+    assert_eq!(
+        U32x4::new(1, 2, 3, 4).reduce(|a, b| a + b, 0),
+        10,
+        "U32x4::reduce"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_generic_lane_traits() {
+    assert_eq!(
+        negate_and_shift(I32x4::new(1, -2, 3, -4)),
+        I32x4::new(-2, 4, -6, 8),
+        "Neg and shifts on I32x4"
+    );
+    assert_eq!(
+        negate_and_shift(I32x16::from_iter(0..16)),
+        I32x16::from_iter((0..16).map(|x| -2 * x)),
+        "Neg and shifts on I32x16"
+    );
+    assert_eq!(-F32x8::ONE, F32x8::splat(-1.0), "Neg on F32x8");
+    assert_eq!(U32x8::MAX >> 28, U32x8::splat(15), "Logical shift on U32x8");
+
+    let mask = Bx8::from_bitmask(0b0101_0101);
+    assert_eq!(
+        I32x8::ONE.if_else(&I32x8::ZERO, &mask),
+        mask.to_i32x8() * -1,
+        "I32x8::if_else"
+    );
+    assert_eq!(
+        F32x8::ONE.if_else(&F32x8::ZERO, mask),
+        F32x8::from(mask.to_u32x8_ones()),
+        "F32x8::if_else"
+    );
+
+    let set: HashSet<U32x16> = [U32x16::ONE, U32x16::ONE, U32x16::ZERO].into();
+    assert_eq!(set.len(), 2, "Hash and Eq on U32x16");
+    let masks: HashSet<Bx16> = [Bx16::ALL_TRUE, !Bx16::ALL_FALSE].into();
+    assert_eq!(masks.len(), 1, "Hash and Eq on Bx16");
+
+    assert_eq!(
+        format!("{:#x}", I32x8::splat(255)),
+        format!("[{}]", ["0xff"; 8].join(", ")),
+        "LowerHex on I32x8"
+    );
+    assert_eq!(
+        format!("{}", Bx4::new(true, false, true, false)),
+        "[true, false, true, false]",
+        "Display on Bx4"
+    );
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "derive")]
+
+use wasm_bindgen_test::*;
+use wasm_simd::{F32x4, SimdSoa, U32x4};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, SimdSoa)]
+struct Particle {
+    x: f32,
+    y: f32,
+    mass: f32,
+    id: u32,
+    charge: i32,
+}
+
+fn particle(i: u32) -> Particle {
+    Particle {
+        x: i as f32,
+        y: -(i as f32),
+        mass: 0.5 * i as f32,
+        id: i,
+        charge: i as i32 - 3,
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_packed_gather_scatter() {
+    let items: Vec<Particle> = (1..=4).map(particle).collect();
+    let packed = ParticleX4::gather(&items);
+    assert_eq!(ParticleX4::LANES, 4, "Lane count");
+    assert_eq!(packed.x, F32x4::new(1.0, 2.0, 3.0, 4.0), "x lanes");
+    assert_eq!(packed.id, U32x4::new(1, 2, 3, 4), "id lanes");
+    assert_eq!(packed.extract(2), particle(3), "extract");
+
+    let mut out = [Particle::default(); 4];
+    packed.scatter(&mut out);
+    assert_eq!(out.as_slice(), items.as_slice(), "Round trip");
+
+    let partial = ParticleX4::gather(&items[..2]);
+    assert_eq!(
+        partial.mass,
+        F32x4::new(0.5, 1.0, 0.0, 0.0),
+        "Padding is zero"
+    );
+
+    let mut first = [Particle::default(); 1];
+    packed.scatter(&mut first);
+    assert_eq!(first[0], particle(1), "Partial scatter");
+
+    assert_eq!(
+        ParticleX4::splat(&particle(7)).extract(3),
+        particle(7),
+        "splat"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic(expected = "gather takes at most 4 items, got 5")]
+fn test_packed_gather_too_many() {
+    let items: Vec<Particle> = (0..5).map(particle).collect();
+    let _ = ParticleX4::gather(&items);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_soa_gather_scatter() {
+    let items: Vec<Particle> = (0..10).map(particle).collect();
+    let soa = ParticleSoa::gather(&items);
+    assert_eq!(soa.len(), 10, "len");
+    assert_eq!(soa.chunk_count(), 3, "chunk_count");
+    assert_eq!(soa.x().len(), 3, "Stream length");
+    assert_eq!(soa.get(9), Some(particle(9)), "get");
+    assert_eq!(soa.get(10), None, "get past the end");
+    assert_eq!(soa.to_vec(), items, "to_vec");
+
+    let mut out = vec![Particle::default(); 10];
+    soa.scatter(&mut out);
+    assert_eq!(out, items, "Round trip");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_soa_push_and_chunks() {
+    let mut soa = ParticleSoa::new();
+    assert!(soa.is_empty(), "Empty");
+    for i in 0..6 {
+        soa.push(particle(i));
+    }
+    assert_eq!(soa, (0..6).map(particle).collect(), "push matches collect");
+    assert_eq!(
+        soa,
+        ParticleSoa::gather(&(0..6).map(particle).collect::<Vec<_>>()),
+        "push matches gather"
+    );
+
+    let moved: Vec<ParticleX4> = soa
+        .chunks()
+        .map(|mut chunk| {
+            chunk.x += chunk.mass;
+            chunk
+        })
+        .collect();
+    for (index, chunk) in moved.into_iter().enumerate() {
+        soa.set_chunk(index, chunk);
+    }
+    assert_eq!(soa.get(4).map(|p| p.x), Some(6.0), "set_chunk");
+
+    for mass in soa.mass_mut() {
+        *mass *= 2.0;
+    }
+    assert_eq!(soa.get(5).map(|p| p.mass), Some(5.0), "Stream slice");
+    assert_eq!(soa.len(), 6, "Slices keep the length");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_soa_eq_ignores_padding() {
+    let items: Vec<Particle> = (0..6).map(particle).collect();
+    let soa = ParticleSoa::gather(&items);
+
+    let mut padded = soa.clone();
+    padded.x_mut()[1] = F32x4::new(4.0, 5.0, 99.0, -1.0);
+    let mut chunk = padded.chunk(1);
+    chunk.id = U32x4::new(4, 5, 7, 7);
+    padded.set_chunk(1, chunk);
+    assert_eq!(padded, soa, "Padding lanes ignored");
+
+    padded.x_mut()[1] = F32x4::new(4.0, 6.0, 0.0, 0.0);
+    assert_ne!(padded, soa, "Item lanes compared");
+    assert_ne!(ParticleSoa::gather(&items[..5]), soa, "Lengths compared");
+}
+
+mod renamed {
+    use wasm_simd as simd;
+    use wasm_simd::SimdSoa;
+
+    #[derive(SimdSoa)]
+    #[simd_soa(crate = simd)]
+    pub struct Point {
+        pub x: f32,
+        pub y: i32,
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_crate_path_and_visibility() {
+    use renamed::{Point, PointSoa, PointX4};
+
+    let packed = PointX4::splat(&Point { x: 1.5, y: -2 });
+    assert_eq!(packed.x, F32x4::splat(1.5), "Public field");
+    let soa = PointSoa::gather(&[Point { x: 1.5, y: -2 }]);
+    assert_eq!(
+        soa.y(),
+        [wasm_simd::I32x4::new(-2, 0, 0, 0)],
+        "Public stream"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic(expected = "scatter needs a slice of 3 items, got 2")]
+fn test_soa_scatter_length_mismatch() {
+    let soa: ParticleSoa = (0..3).map(particle).collect();
+    let mut out = [Particle::default(); 2];
+    soa.scatter(&mut out);
+}
@@ -0,0 +1,416 @@
+use wasm_bindgen_test::*;
+use wasm_simd::prelude::*;
+
+fn lanes8() -> [i32; 8] {
+    [1, -2, 3, -4, 5, -6, 7, -8]
+}
+
+fn lanes16() -> [i32; 16] {
+    core::array::from_fn(|i| (i as i32 - 5) * 3)
+}
+
+fn assert_f32x8_bits(actual: F32x8, expected: [f32; 8], msg: &str) {
+    let actual: [f32; 8] = actual.into();
+    assert_eq!(
+        actual.map(f32::to_bits),
+        expected.map(f32::to_bits),
+        "{}",
+        msg
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_construction_and_parts() {
+    let v = I32x8::from(lanes8());
+    assert_eq!(v.to_array(), lanes8(), "from/to_array mismatch");
+    assert_eq!(
+        v.to_parts(),
+        [I32x4::new(1, -2, 3, -4), I32x4::new(5, -6, 7, -8)],
+        "Parts should hold four lanes each, in order"
+    );
+    assert_eq!(
+        I32x8::from_parts(v.to_parts()),
+        v,
+        "from_parts/to_parts roundtrip"
+    );
+    assert_eq!(I32x8::splat(3).to_array(), [3; 8], "Splat mismatch");
+    assert_eq!(
+        F32x16::new_from_fn(|i| i as f32).to_array(),
+        core::array::from_fn(|i| i as f32),
+        "new_from_fn mismatch"
+    );
+    assert_eq!(U32x16::default(), U32x16::ZERO, "Default should be zero");
+    assert_eq!(
+        I32x8::from_iter([1, 2, 3]).to_array(),
+        [1, 2, 3, 0, 0, 0, 0, 0],
+        "FromIterator should zero-fill"
+    );
+    assert_eq!(
+        I32x16::from(lanes16()).into_iter().collect::<Vec<_>>(),
+        lanes16().to_vec(),
+        "IntoIterator mismatch"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_lane_access() {
+    let mut v = I32x8::from(lanes8());
+    assert_eq!(v.extract_lane(6), 7, "extract_lane in the high part");
+    assert_eq!(v[5], -6, "Index in the high part");
+    assert_eq!(v.extract::<4>(), 5, "Const extract in the high part");
+    assert_eq!(v.get(8), None, "get out of range");
+
+    v.set_lane(7, 80);
+    v.set::<0>(10);
+    assert_eq!(
+        v.to_array(),
+        [10, -2, 3, -4, 5, -6, 7, 80],
+        "set_lane mismatch"
+    );
+    assert_eq!(
+        v.with_lane::<3>(0).to_array(),
+        [10, -2, 3, 0, 5, -6, 7, 80],
+        "with_lane mismatch"
+    );
+    assert_eq!(
+        v.try_set_lane(8, 0),
+        Err(LaneIndexError { index: 8, lanes: 8 }),
+        "try_set_lane out of range"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic(expected = "Index out of bounds")]
+fn test_extract_lane_panic() {
+    F32x8::ZERO.extract_lane(8);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_arithmetic() {
+    let a = I32x16::from(lanes16());
+    let b = I32x16::new_from_fn(|i| i as i32 + 1);
+    let expected: [i32; 16] = core::array::from_fn(|i| lanes16()[i] * (i as i32 + 1) - 2);
+    assert_eq!((a * b - 2).to_array(), expected, "Vector and scalar ops");
+    assert_eq!(
+        (100 / b).to_array(),
+        core::array::from_fn(|i| 100 / (i as i32 + 1)),
+        "Scalar division"
+    );
+    assert_eq!((a << 2).to_array(), lanes16().map(|x| x << 2), "Shift left");
+    assert_eq!((-a).abs(), a.abs(), "Neg and abs");
+    assert_eq!(
+        a.min(&b).to_array(),
+        core::array::from_fn(|i| lanes16()[i].min(i as i32 + 1)),
+        "min"
+    );
+
+    let mut c = U32x8::splat(10);
+    c += U32x8::ONE;
+    c *= 2;
+    assert_eq!(c, U32x8::splat(22), "Assign ops");
+
+    let f = F32x8::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    assert_f32x8_bits(
+        f.mul_add(F32x8::splat(2.0), F32x8::ONE),
+        [3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0, 17.0],
+        "mul_add",
+    );
+    assert_f32x8_bits(-F32x8::splat(1.5).floor(), [-1.0; 8], "floor and neg");
+    assert_eq!(
+        [F32x8::ONE, F32x8::splat(2.0)].iter().sum::<F32x8>(),
+        F32x8::splat(3.0),
+        "Sum"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_comparisons_and_select() {
+    let a = I32x8::from(lanes8());
+    let mask = a.gt(&I32x8::ZERO);
+    assert_eq!(
+        mask.to_array(),
+        [true, false, true, false, true, false, true, false],
+        "gt mismatch"
+    );
+    assert_eq!(
+        mask.to_bitmask(),
+        0b0101_0101,
+        "to_bitmask spans both parts"
+    );
+    assert_eq!(
+        I32x8::select(&mask, &a, &I32x8::ZERO).to_array(),
+        [1, 0, 3, 0, 5, 0, 7, 0],
+        "select mismatch"
+    );
+    assert_eq!(
+        a.masked_add(&!mask, &I32x8::splat(10)).to_array(),
+        [1, 8, 3, 6, 5, 4, 7, 2],
+        "masked_add mismatch"
+    );
+    assert_eq!(
+        a.if_else(&I32x8::ZERO, &mask).to_array(),
+        [1, 0, 3, 0, 5, 0, 7, 0],
+        "if_else mismatch"
+    );
+    assert!(a.lt(&I32x8::splat(8)).all(), "All lanes below 8");
+
+    let nan = F32x16::splat(1.0).with_lane::<13>(f32::NAN);
+    assert_ne!(nan, nan, "NaN lanes make vectors unequal");
+    assert_eq!(
+        nan.ne(&nan).to_bitmask(),
+        1 << 13,
+        "NaN lane compares unequal"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_shuffles_across_parts() {
+    let v = I32x8::from(lanes8());
+    let w = I32x8::new_from_fn(|i| 10 * i as i32);
+    assert_eq!(
+        v.reverse().to_array(),
+        [-8, 7, -6, 5, -4, 3, -2, 1],
+        "reverse"
+    );
+    for n in 0..8 {
+        let expected: [i32; 8] = core::array::from_fn(|i| lanes8()[(i + n) % 8]);
+        let actual = match n {
+            0 => v.rotate_lanes_left::<0>(),
+            1 => v.rotate_lanes_left::<1>(),
+            2 => v.rotate_lanes_left::<2>(),
+            3 => v.rotate_lanes_left::<3>(),
+            4 => v.rotate_lanes_left::<4>(),
+            5 => v.rotate_lanes_left::<5>(),
+            6 => v.rotate_lanes_left::<6>(),
+            _ => v.rotate_lanes_left::<7>(),
+        };
+        assert_eq!(actual.to_array(), expected, "rotate_lanes_left by {}", n);
+    }
+    assert_eq!(
+        v.rotate_lanes_right::<3>().to_array(),
+        [-6, 7, -8, 1, -2, 3, -4, 5],
+        "rotate_lanes_right"
+    );
+    assert_eq!(v.broadcast_lane::<6>(), I32x8::splat(7), "broadcast_lane");
+    assert_eq!(
+        v.interleave_low(&w).to_array(),
+        [1, 0, -2, 10, 3, 20, -4, 30],
+        "interleave_low"
+    );
+    assert_eq!(
+        v.interleave_high(&w).to_array(),
+        [5, 40, -6, 50, 7, 60, -8, 70],
+        "interleave_high"
+    );
+    assert_eq!(
+        v.concat_low_high(&w).to_array(),
+        [1, -2, 3, -4, 40, 50, 60, 70],
+        "concat_low_high"
+    );
+    assert_eq!(
+        v.shuffle::<15, 0, 9, 6, 4, 3, 12, 8>(&w).to_array(),
+        [70, 1, 10, 7, 5, -4, 40, 0],
+        "shuffle"
+    );
+    assert_eq!(
+        F32x16::new_from_fn(|i| i as f32)
+            .shuffle::<31, 16, 0, 15, 4, 20, 8, 24, 1, 17, 5, 21, 9, 25, 2, 18>(&F32x16::splat(
+                -1.0
+            ))
+            .to_array(),
+        [-1.0, -1.0, 0.0, 15.0, 4.0, -1.0, 8.0, -1.0, 1.0, -1.0, 5.0, -1.0, 9.0, -1.0, 2.0, -1.0],
+        "F32x16 shuffle"
+    );
+    assert_eq!(
+        Bx8::from_bitmask(0b1000_0001)
+            .shuffle::<7, 8, 0, 15, 1, 9, 6, 14>(&Bx8::from_bitmask(0b0000_0010))
+            .to_bitmask(),
+        0b0010_0101,
+        "Mask shuffle"
+    );
+
+    let x = I32x16::from(lanes16());
+    let indices = I32x16::from([15, 0, 4, 9, -1, 16, 3, 12, 7, 7, i32::MIN, 2, 11, 5, 14, 8]);
+    let expected: [i32; 16] = indices.to_array().map(|i| {
+        if (0..16).contains(&i) {
+            lanes16()[i as usize]
+        } else {
+            0
+        }
+    });
+    assert_eq!(
+        x.swizzle(&indices).to_array(),
+        expected,
+        "swizzle across parts"
+    );
+    assert_eq!(
+        Bx8::from_bitmask(0b1000_0001)
+            .swizzle(&I32x8::from([7, 1, 0, 9, 7, 7, 2, 3]))
+            .to_bitmask(),
+        0b0011_0101,
+        "Mask swizzle"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_reductions() {
+    let v = I32x16::from(lanes16());
+    assert_eq!(v.reduce_add(), lanes16().iter().sum::<i32>(), "reduce_add");
+    assert_eq!(v.reduce_min(), -15, "reduce_min");
+    assert_eq!(v.reduce_max(), 30, "reduce_max");
+    assert_eq!(
+        I32x8::splat(i32::MAX).reduce_add(),
+        i32::MAX.wrapping_mul(8),
+        "reduce_add wraps"
+    );
+    assert_eq!(U32x8::from_iter(1..=8).reduce_mul(), 40320, "reduce_mul");
+    assert_eq!(
+        U32x8::from_iter(1..=8).reduce(|a, b| a * 10 + b, 0),
+        12345678,
+        "reduce visits lanes in order"
+    );
+    assert_eq!(I32x8::from(lanes8()).fold(|a, b| a - b, 0), 4, "fold");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_masks() {
+    let mask = Bx16::from_bitmask(0b1010_0000_0001_0000);
+    assert_eq!(mask.count_true(), 3, "count_true");
+    assert_eq!(mask.first_true(), Some(4), "first_true");
+    assert_eq!(mask.last_true(), Some(15), "last_true");
+    assert_eq!(
+        mask.true_lanes().collect::<Vec<_>>(),
+        [4, 13, 15],
+        "true_lanes"
+    );
+    assert_eq!(
+        mask.true_lanes().rev().collect::<Vec<_>>(),
+        [15, 13, 4],
+        "true_lanes reversed"
+    );
+    assert!(mask.any() && !mask.all() && !mask.none(), "any/all/none");
+    assert!(Bx16::ALL_TRUE.all(), "ALL_TRUE");
+    assert_eq!((mask & false), Bx16::ALL_FALSE, "and bool");
+    assert_eq!(
+        (true ^ mask).to_bitmask(),
+        !0b1010_0000_0001_0000,
+        "bool xor"
+    );
+    assert_eq!(
+        mask.to_i32x16().to_array()[13],
+        -1,
+        "to_i32x16 sets lanes to -1"
+    );
+    assert_eq!(
+        mask.to_u32x16_ones().reduce_add(),
+        3,
+        "to_u32x16_ones sets lanes to 1"
+    );
+    assert_eq!(
+        Bx8::from([true, false, false, true, false, false, false, true]).to_bitmask(),
+        0b1000_1001,
+        "From<[bool; 8]>"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_conversions() {
+    let f = F32x8::from_array([-1.5, -0.5, 0.5, 1.5, 2.5, 3e9, -3e9, f32::NAN]);
+    if !cfg!(feature = "relaxed-simd-default") {
+        assert_eq!(
+            f.to_i32_round().to_array(),
+            [-2, 0, 0, 2, 2, i32::MAX, i32::MIN, 0],
+            "to_i32_round"
+        );
+        assert_eq!(
+            U32x8::from(f).to_array(),
+            [0, 0, 0, 1, 2, 3_000_000_000, 0, 0],
+            "U32x8::from"
+        );
+    }
+    assert_eq!(
+        F32x8::from(I32x8::from(lanes8())).to_array(),
+        lanes8().map(|x| x as f32),
+        "F32x8::from(I32x8)"
+    );
+    assert_eq!(
+        F32x8::from_bits(f.to_bits()).to_bits(),
+        f.to_bits(),
+        "to_bits/from_bits roundtrip"
+    );
+
+    let v = U32x16::new_from_fn(|i| 0x0403_0201 + i as u32);
+    assert_eq!(
+        &v.to_le_bytes()[60..],
+        [0x10, 2, 3, 4],
+        "to_le_bytes of the last lane"
+    );
+    assert_eq!(
+        U32x16::from_le_bytes(v.to_le_bytes()),
+        v,
+        "from_le_bytes roundtrip"
+    );
+    assert_eq!(
+        U32x16::from_ne_bytes(v.to_ne_bytes()),
+        v,
+        "from_ne_bytes roundtrip"
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_formatting_and_parsing() {
+    let v = I32x8::from(lanes8());
+    assert_eq!(v.to_string(), "[1, -2, 3, -4, 5, -6, 7, -8]", "Display");
+    assert_eq!(
+        format!("{:?}", v),
+        "I32x8(1, -2, 3, -4, 5, -6, 7, -8)",
+        "Debug"
+    );
+    assert_eq!(
+        format!("{:x}", U32x8::splat(255)),
+        format!("[{}]", ["ff"; 8].join(", ")),
+        "LowerHex"
+    );
+    assert_eq!(v.to_string().parse::<I32x8>(), Ok(v), "FromStr Display");
+    assert_eq!(format!("{:?}", v).parse::<I32x8>(), Ok(v), "FromStr Debug");
+    assert_eq!(
+        "1 2 3 4".parse::<I32x8>(),
+        Err(ParseVectorError::WrongLaneCount {
+            expected: 8,
+            found: 4
+        }),
+        "Too few lanes"
+    );
+    let mask = Bx8::from_bitmask(0b0000_0011);
+    assert_eq!(
+        format!("{:?}", mask),
+        "Bx8(true, true, false, false, false, false, false, false)",
+        "Mask Debug"
+    );
+    assert_eq!(mask.to_string().parse::<Bx8>(), Ok(mask), "Mask FromStr");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_error_messages() {
+    let (mut v, mut mask) = (I32x16::ZERO, Bx8::ALL_TRUE);
+    assert_eq!(
+        "1 2 3 4 5 6 7".parse::<F32x8>().unwrap_err().to_string(),
+        "expected 8 lanes, found 7",
+        "Parse error names the lane count"
+    );
+    assert_eq!(
+        "true false".parse::<Bx16>().unwrap_err().to_string(),
+        "expected 16 lanes, found 2",
+        "Mask parse error names the lane count"
+    );
+    assert_eq!(
+        v.try_set_lane(16, 1).unwrap_err().to_string(),
+        "lane index 16 is out of bounds for 16 lanes",
+        "Lane index error names the lane count"
+    );
+    assert_eq!(
+        mask.try_set_lane(9, false).unwrap_err().to_string(),
+        "lane index 9 is out of bounds for 8 lanes",
+        "Mask lane index error names the lane count"
+    );
+}
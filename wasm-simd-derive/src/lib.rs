@@ -0,0 +1,382 @@
+//! Derive macros for `wasm-simd`, re-exported by its `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Path, Type, Visibility};
+
+/// Generates a structure-of-arrays layout for a struct whose fields are all `f32`, `i32` or
+/// `u32`.
+///
+/// For `struct Particle { x: f32, mass: f32, id: u32 }` this generates, with the visibility of
+/// `Particle`:
+///
+/// - `ParticleX4`, four particles packed lane-wise (`x: F32x4`, `mass: F32x4`, `id: U32x4`),
+///   with `splat`, `gather`, `scatter` and `extract`. Each field keeps its visibility.
+/// - `ParticleSoa`, any number of particles stored as one `Vec` of 4-lane vectors per field,
+///   with `gather` and `scatter` from and to `[Particle]`, `push`, `get`, `to_vec` and access to
+///   each 4-lane chunk as a `ParticleX4`. The streams are private; `x()` and `x_mut()` borrow
+///   the `x` stream as a slice, with the visibility of the field.
+///
+/// Field names that clash with the methods of `ParticleSoa` are rejected.
+///
+/// `#[simd_soa(crate = path)]` sets the path to the `wasm_simd` crate, for when it is renamed
+/// or re-exported.
+#[proc_macro_derive(SimdSoa, attributes(simd_soa))]
+pub fn derive_simd_soa(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_simd_soa(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// Methods of the generated container, which a field accessor must not shadow.
+const SOA_METHODS: &[&str] = &[
+    "new",
+    "with_capacity",
+    "gather",
+    "scatter",
+    "len",
+    "is_empty",
+    "chunk_count",
+    "push",
+    "get",
+    "chunk",
+    "set_chunk",
+    "chunks",
+    "to_vec",
+];
+
+// Reads the path from `#[simd_soa(crate = path)]`, defaulting to `::wasm_simd`.
+fn crate_path(input: &DeriveInput) -> syn::Result<Path> {
+    let mut path = syn::parse_quote!(::wasm_simd);
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("simd_soa")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                path = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported simd_soa attribute, expected `crate = path`"))
+            }
+        })?;
+    }
+    Ok(path)
+}
+
+// Maps a lane type to the 4-lane vector that holds it.
+fn lane_vector(krate: &Path, ty: &Type) -> Option<TokenStream2> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    if path.qself.is_some() {
+        return None;
+    }
+
+    match path.path.get_ident()?.to_string().as_str() {
+        "f32" => Some(quote!(#krate::F32x4)),
+        "i32" => Some(quote!(#krate::I32x4)),
+        "u32" => Some(quote!(#krate::U32x4)),
+        _ => None,
+    }
+}
+
+fn expand_simd_soa(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let krate = crate_path(input)?;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "SimdSoa does not support generic structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "SimdSoa needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "SimdSoa can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut names: Vec<&Ident> = Vec::new();
+    let mut field_vis: Vec<&Visibility> = Vec::new();
+    let mut vectors = Vec::new();
+    for field in fields {
+        let field_name = field.ident.as_ref().expect("named fields have names");
+        let reserved = SOA_METHODS.iter().any(|method| field_name == method)
+            || fields.iter().any(|other| {
+                other
+                    .ident
+                    .as_ref()
+                    .is_some_and(|other| *field_name == format!("{other}_mut"))
+            });
+        if reserved {
+            return Err(syn::Error::new_spanned(
+                field_name,
+                format!("the field name `{field_name}` is reserved by SimdSoa"),
+            ));
+        }
+
+        let vector = lane_vector(&krate, &field.ty).ok_or_else(|| {
+            syn::Error::new_spanned(&field.ty, "SimdSoa fields must be `f32`, `i32` or `u32`")
+        })?;
+        names.push(field_name);
+        field_vis.push(&field.vis);
+        vectors.push(vector);
+    }
+
+    // Locals holding the lanes of each field, named so that they cannot shadow parameters.
+    let lanes: Vec<Ident> = names.iter().map(|n| format_ident!("lanes_{}", n)).collect();
+    let names_mut: Vec<Ident> = names.iter().map(|n| format_ident!("{}_mut", n)).collect();
+    let stream_docs: Vec<String> = names
+        .iter()
+        .map(|n| format!("Returns the `{n}` stream, one vector per chunk."))
+        .collect();
+    let stream_mut_docs: Vec<String> = names
+        .iter()
+        .map(|n| format!("Returns the `{n}` stream mutably, one vector per chunk."))
+        .collect();
+
+    let packed = format_ident!("{}X4", name);
+    let soa = format_ident!("{}Soa", name);
+    let packed_doc = format!("Four `{name}` values packed lane-wise, one vector per field.");
+    let soa_doc = format!(
+        "`{name}` values stored as a structure of arrays, one stream of 4-lane vectors per \
+         field. Lanes past `len()` in the last chunk are padding, which `==` ignores."
+    );
+    let vec = quote!(#krate::__private::Vec);
+
+    Ok(quote! {
+        #[doc = #packed_doc]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #vis struct #packed {
+            #( #field_vis #names: #vectors, )*
+        }
+
+        impl #packed {
+            pub const LANES: usize = 4;
+
+            /// Returns a value with every lane holding `item`.
+            pub fn splat(item: &#name) -> Self {
+                Self { #( #names: #vectors::splat(item.#names), )* }
+            }
+
+            /// Packs up to four items, item `i` going to lane `i`. Lanes past `items.len()`
+            /// are zero.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `items` holds more than four items.
+            pub fn gather(items: &[#name]) -> Self {
+                ::core::assert!(
+                    items.len() <= Self::LANES,
+                    "gather takes at most 4 items, got {}",
+                    items.len()
+                );
+                Self {
+                    #(
+                        #names: #vectors::new_from_fn(|lane| {
+                            items.get(lane).map_or(::core::default::Default::default(), |item| {
+                                item.#names
+                            })
+                        }),
+                    )*
+                }
+            }
+
+            /// Unpacks lane `i` into `out[i]` for every item of `out`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `out` holds more than four items.
+            pub fn scatter(&self, out: &mut [#name]) {
+                ::core::assert!(
+                    out.len() <= Self::LANES,
+                    "scatter takes at most 4 items, got {}",
+                    out.len()
+                );
+                #( let #lanes = self.#names.to_array(); )*
+                for (lane, item) in out.iter_mut().enumerate() {
+                    #( item.#names = #lanes[lane]; )*
+                }
+            }
+
+            /// Returns the item held in `lane`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lane` is 4 or more.
+            pub fn extract(&self, lane: usize) -> #name {
+                #name { #( #names: self.#names.extract_lane(lane), )* }
+            }
+        }
+
+        #[doc = #soa_doc]
+        #[derive(Clone, Debug, Default)]
+        #vis struct #soa {
+            #( #names: #vec<#vectors>, )*
+            len: usize,
+        }
+
+        impl #soa {
+            pub fn new() -> Self {
+                ::core::default::Default::default()
+            }
+
+            /// Returns an empty container with room for `capacity` items.
+            pub fn with_capacity(capacity: usize) -> Self {
+                let chunks = capacity.div_ceil(#packed::LANES);
+                Self {
+                    #( #names: #vec::with_capacity(chunks), )*
+                    len: 0,
+                }
+            }
+
+            /// Packs `items` into the streams, four items per chunk.
+            pub fn gather(items: &[#name]) -> Self {
+                let mut soa = Self::with_capacity(items.len());
+                for chunk in items.chunks(#packed::LANES) {
+                    let packed = #packed::gather(chunk);
+                    #( soa.#names.push(packed.#names); )*
+                }
+                soa.len = items.len();
+                soa
+            }
+
+            /// Unpacks every item into `out`, in order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `out.len()` is not `self.len()`.
+            pub fn scatter(&self, out: &mut [#name]) {
+                ::core::assert_eq!(
+                    out.len(),
+                    self.len,
+                    "scatter needs a slice of {} items, got {}",
+                    self.len,
+                    out.len()
+                );
+                for (chunk, out) in self.chunks().zip(out.chunks_mut(#packed::LANES)) {
+                    chunk.scatter(out);
+                }
+            }
+
+            /// Returns the number of items.
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Returns the number of 4-lane chunks in each stream.
+            pub fn chunk_count(&self) -> usize {
+                self.len.div_ceil(#packed::LANES)
+            }
+
+            /// Appends an item, starting a new chunk of zero lanes when the last one is full.
+            pub fn push(&mut self, item: #name) {
+                let (chunk, lane) = (self.len / #packed::LANES, self.len % #packed::LANES);
+                if lane == 0 {
+                    #( self.#names.push(#vectors::ZERO); )*
+                }
+                #( self.#names[chunk].set_lane(lane, item.#names); )*
+                self.len += 1;
+            }
+
+            pub fn get(&self, index: usize) -> ::core::option::Option<#name> {
+                if index < self.len {
+                    let chunk = self.chunk(index / #packed::LANES);
+                    ::core::option::Option::Some(chunk.extract(index % #packed::LANES))
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+
+            /// Returns items `4 * index` to `4 * index + 3` packed lane-wise.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is not less than `chunk_count()`.
+            pub fn chunk(&self, index: usize) -> #packed {
+                #packed { #( #names: self.#names[index], )* }
+            }
+
+            /// Replaces items `4 * index` to `4 * index + 3`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is not less than `chunk_count()`.
+            pub fn set_chunk(&mut self, index: usize, value: #packed) {
+                #( self.#names[index] = value.#names; )*
+            }
+
+            // The accessors of private fields may go unused.
+            #(
+                #[doc = #stream_docs]
+                #[allow(dead_code)]
+                #field_vis fn #names(&self) -> &[#vectors] {
+                    &self.#names
+                }
+
+                #[doc = #stream_mut_docs]
+                #[allow(dead_code)]
+                #field_vis fn #names_mut(&mut self) -> &mut [#vectors] {
+                    &mut self.#names
+                }
+            )*
+
+            /// Returns an iterator over the 4-lane chunks, in order.
+            pub fn chunks(&self) -> impl ::core::iter::Iterator<Item = #packed> + '_ {
+                (0..self.chunk_count()).map(move |index| self.chunk(index))
+            }
+
+            /// Unpacks every item into a new `Vec`, in order.
+            pub fn to_vec(&self) -> #vec<#name> {
+                let mut items = #vec::with_capacity(self.len);
+                items.extend((0..self.len).filter_map(|index| self.get(index)));
+                items
+            }
+        }
+
+        // Compares the items only: `set_chunk` and the `_mut` accessors can leave any value in
+        // the padding lanes of the last chunk.
+        impl ::core::cmp::PartialEq for #soa {
+            fn eq(&self, other: &Self) -> bool {
+                let full = self.len / #packed::LANES;
+                self.len == other.len
+                    #( && self.#names[..full] == other.#names[..full] )*
+                    && (full * #packed::LANES..self.len).all(|index| {
+                        let lane = index % #packed::LANES;
+                        #(
+                            self.#names[full].extract_lane(lane)
+                                == other.#names[full].extract_lane(lane)
+                        )&&*
+                    })
+            }
+        }
+
+        impl ::core::iter::FromIterator<#name> for #soa {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #name>>(iter: I) -> Self {
+                let mut soa = Self::new();
+                for item in iter {
+                    soa.push(item);
+                }
+                soa
+            }
+        }
+    })
+}